@@ -1,6 +1,6 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 
 /// How the runtime should behave. Dictated by the flags provided to  `Cli`
 #[derive(Debug, PartialEq)]
@@ -15,14 +15,111 @@ pub enum RuntimeBehavior {
     /// will dump the default configuration to stdout instead of running the
     /// application.
     DumpDefaultConfig,
+    /// will dump the theme currently in use to stdout, which can be used as
+    /// a starting point for a new theme.
+    DumpTheme,
+    /// will check the configuration file for problems, printing every one of
+    /// them along with the line they are at.
+    CheckConfig,
     /// will run the application with all disk-synchronization disabled. That
     /// means `HAC` wont't save any files or changes to collection to disk.
     DryRun,
+    /// will render the documentation of a collection instead of running the
+    /// application, see `DocsArgs`.
+    GenerateDocs(DocsArgs),
+    /// will create a collection from a file written by another tool instead
+    /// of running the application, see `ImportArgs`.
+    Import(ImportArgs),
+    /// will write a collection in a format other tools understand instead of
+    /// running the application, see `ExportArgs`.
+    Export(ExportArgs),
     /// the default running behavior of the application, this is the default
     /// behavior for `HAC`.
     Run,
 }
 
+/// formats the documentation of a collection can be rendered as
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocsFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+#[derive(clap::Args, Debug, Clone, PartialEq)]
+pub struct DocsArgs {
+    /// name of a collection on the collections directory, or the path to one
+    pub collection: String,
+    /// format the documentation is rendered as
+    #[arg(long, value_enum, default_value_t)]
+    pub format: DocsFormat,
+    /// writes the documentation to the given file instead of to stdout
+    #[arg(long, short, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug, Clone, PartialEq)]
+pub struct ImportArgs {
+    #[command(flatten)]
+    pub source: ImportSource,
+    /// keeps only the first request of each method and url, as pages tend to
+    /// repeat the same requests, only used by --har
+    #[arg(long)]
+    pub dedupe: bool,
+}
+
+#[derive(clap::Args, Debug, Clone, PartialEq)]
+#[group(required = true, multiple = false)]
+pub struct ImportSource {
+    /// OpenAPI 3.0 or 3.1 spec, written in json or yaml, to create the
+    /// collection from
+    #[arg(long, value_name = "FILE")]
+    pub openapi: Option<PathBuf>,
+    /// HAR file, like the ones saved by the network tab of a browser, to create
+    /// the collection from
+    #[arg(long, value_name = "FILE")]
+    pub har: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug, Clone, PartialEq)]
+pub struct ExportArgs {
+    /// name of a collection on the collections directory, or the path to one
+    pub collection: String,
+    #[command(flatten)]
+    pub format: ExportFormat,
+    /// writes the export to the given file instead of to stdout
+    #[arg(long, short, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug, Clone, PartialEq)]
+#[group(required = true, multiple = false)]
+pub struct ExportFormat {
+    /// exports an OpenAPI 3.0 spec, written as json, describing every request
+    #[arg(long)]
+    pub openapi: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// generates documentation for every request on a collection, including
+    /// their descriptions, headers and example bodies
+    Docs(DocsArgs),
+    /// creates a collection from a file written by another tool, and stores it
+    /// on the collections directory
+    Import(ImportArgs),
+    /// writes a collection in a format other tools understand
+    Export(ExportArgs),
+}
+
+/// everything the application needs to know from the command line to run
+#[derive(Debug, PartialEq)]
+pub struct RuntimeArgs {
+    pub behavior: RuntimeBehavior,
+    /// overrides where collections are loaded from and stored at
+    pub collections_dir: Option<PathBuf>,
+}
+
 #[derive(Parser, Debug)]
 pub struct Cli {
     /// prints the directory in which the config file is being loaded from
@@ -31,6 +128,12 @@ pub struct Cli {
     /// dumps the default configuration to stdout.
     #[arg(long)]
     config_dump: bool,
+    /// dumps the theme in use to stdout, in the format of a theme file.
+    #[arg(long)]
+    theme_dump: bool,
+    /// checks the configuration file for problems and exits.
+    #[arg(long)]
+    config_check: bool,
     /// prints the directory in which the collections are being stored
     #[arg(long)]
     data_dir: bool,
@@ -38,22 +141,48 @@ pub struct Cli {
     /// specified, no collection, request, or anything will be saved to disk.
     #[arg(long)]
     dry_run: bool,
+    /// loads and stores collections on the given directory, instead of the one
+    /// on the configuration file or on a `.hac` workspace
+    #[arg(long, value_name = "DIR", global = true)]
+    collections_dir: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
 impl Cli {
-    pub fn parse_args() -> RuntimeBehavior {
+    pub fn parse_args() -> RuntimeArgs {
         let args = Cli::parse();
 
-        if args.config_dir {
+        RuntimeArgs {
+            behavior: args.runtime_behavior(),
+            collections_dir: args.collections_dir.clone(),
+        }
+    }
+
+    fn runtime_behavior(&self) -> RuntimeBehavior {
+        if let Some(command) = self.command.as_ref() {
+            return match command {
+                Command::Docs(args) => RuntimeBehavior::GenerateDocs(args.clone()),
+                Command::Import(args) => RuntimeBehavior::Import(args.clone()),
+                Command::Export(args) => RuntimeBehavior::Export(args.clone()),
+            };
+        }
+        if self.config_dir {
             return RuntimeBehavior::PrintConfigPath;
         }
-        if args.data_dir {
+        if self.data_dir {
             return RuntimeBehavior::PrintDataPath;
         }
-        if args.config_dump {
+        if self.config_dump {
             return RuntimeBehavior::DumpDefaultConfig;
         }
-        if args.dry_run {
+        if self.theme_dump {
+            return RuntimeBehavior::DumpTheme;
+        }
+        if self.config_check {
+            return RuntimeBehavior::CheckConfig;
+        }
+        if self.dry_run {
             return RuntimeBehavior::DryRun;
         }
 
@@ -68,7 +197,10 @@ impl Cli {
             "collections are being stored at: {}",
             data_path.as_ref().to_string_lossy()
         );
-        println!("you can change this on the configuration file by specifying `collections_dir`");
+        println!("you can change this on the configuration file by specifying `collections_dir`,");
+        println!(
+            "with the `--collections-dir` flag, or by creating a `.hac` directory on your project"
+        );
     }
 
     pub fn print_config_path<P>(maybe_path: Option<P>, usual_path: P)
@@ -93,4 +225,29 @@ impl Cli {
     pub fn print_default_config(config_as_str: &str) {
         println!("{}", config_as_str)
     }
+
+    pub fn print_theme(theme_as_str: &str) {
+        println!("{}", theme_as_str)
+    }
+
+    pub fn print_config_diagnostics<D>(maybe_path: Option<&Path>, diagnostics: &[D])
+    where
+        D: std::fmt::Display,
+    {
+        if !diagnostics.is_empty() {
+            diagnostics
+                .iter()
+                .for_each(|diagnostic| eprintln!("{diagnostic}"));
+            eprintln!(
+                "\nfound {} problem(s) on the configuration",
+                diagnostics.len()
+            );
+            return;
+        }
+
+        match maybe_path {
+            Some(path) => println!("{} has no problems", path.to_string_lossy()),
+            None => println!("no config file was found, the default one is being used"),
+        }
+    }
 }
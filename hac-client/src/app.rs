@@ -1,10 +1,13 @@
+use hac_core::collection::CollectionLoadError;
 use hac_core::{collection::Collection, command::Command};
 
+use crate::clipboard::CopyToClipboard;
 use crate::event_pool::{Event, EventPool};
 use crate::pages::{Eventful, Renderable};
 use crate::screen_manager::ScreenManager;
 
-use std::io::Stdout;
+use std::io::{Stdout, Write};
+use std::path::Path;
 
 use ratatui::{backend::CrosstermBackend, Terminal};
 use tokio::sync::mpsc;
@@ -38,6 +41,18 @@ impl<'app> App<'app> {
         })
     }
 
+    /// shows an error popup on the current screen, used to report problems that
+    /// happen before the app is running
+    pub fn display_error(&mut self, message: String) {
+        self.screen_manager.handle_command(Command::Error(message));
+    }
+
+    /// lists the collections that failed to load on the dashboard
+    pub fn display_broken_collections(&mut self, broken_collections: Vec<CollectionLoadError>) {
+        self.screen_manager
+            .display_broken_collections(broken_collections);
+    }
+
     /// this is the main method which starts the event loop task, listen for events and commands
     /// to pass them down the chain, and render the terminal screen
     pub async fn run(&mut self) -> anyhow::Result<()> {
@@ -54,6 +69,28 @@ impl<'app> App<'app> {
                 while let Ok(command) = command_rx.try_recv() {
                     match command {
                         Command::Quit => self.should_quit = true,
+                        Command::OpenExternalEditor(path) => {
+                            // an editor that fails to start, or that exits with an
+                            // error, like vim's `:cq`, discards the edit
+                            let command = match self.suspend_for_editor(&path)? {
+                                Ok(_) => Command::ExternalEditorClosed(path),
+                                Err(e) => {
+                                    tracing::error!("failed to open external editor: {e:?}");
+                                    Command::Error(format!(
+                                        "the edit was discarded, as the external editor failed: {e}"
+                                    ))
+                                }
+                            };
+                            self.screen_manager.handle_command(command);
+                        }
+                        Command::CopyToClipboard(content) => {
+                            let backend = self.terminal.backend_mut();
+                            if let Err(e) = crossterm::queue!(backend, CopyToClipboard(&content))
+                                .and_then(|_| backend.flush())
+                            {
+                                tracing::error!("failed to write to the clipboard: {e:?}");
+                            }
+                        }
                         _ => self.screen_manager.handle_command(command),
                     }
                 }
@@ -93,13 +130,55 @@ impl<'app> App<'app> {
         shutdown()?;
         Ok(())
     }
+
+    /// hands the terminal over to the user's editor, restoring everything once
+    /// the editor exits. Failing to restore the terminal is an error, while the
+    /// inner result tells whether the editor itself succeeded
+    fn suspend_for_editor(&mut self, path: &Path) -> anyhow::Result<anyhow::Result<()>> {
+        self.event_pool.stop();
+        shutdown()?;
+
+        let edited = open_editor(path);
+
+        startup()?;
+        self.event_pool.start();
+        self.terminal.clear()?;
+        Ok(edited)
+    }
+}
+
+/// opens `path` on `$VISUAL` or `$EDITOR`, falling back to `vi`, waiting for it to
+/// exit. The variables may include arguments, like `code --wait`
+fn open_editor(path: &Path) -> anyhow::Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+
+    let mut args = editor.split_whitespace();
+    let program = args.next().expect("editor should not be empty");
+    let status = std::process::Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()?;
+
+    if !status.success() {
+        anyhow::bail!("{editor} exited with {status}");
+    }
+
+    Ok(())
 }
 
 /// before initializing the app, we must setup the terminal to enable all the features
-/// we need, such as raw mode and entering the alternate screen
+/// we need, such as raw mode, mouse capture and entering the alternate screen
 fn startup() -> anyhow::Result<()> {
     crossterm::terminal::enable_raw_mode()?;
-    crossterm::execute!(std::io::stdout(), crossterm::terminal::EnterAlternateScreen)?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::event::EnableMouseCapture
+    )?;
     Ok(())
 }
 
@@ -107,6 +186,10 @@ fn startup() -> anyhow::Result<()> {
 /// them have a usable terminal
 fn shutdown() -> anyhow::Result<()> {
     crossterm::terminal::disable_raw_mode()?;
-    crossterm::execute!(std::io::stdout(), crossterm::terminal::LeaveAlternateScreen)?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::event::DisableMouseCapture,
+        crossterm::terminal::LeaveAlternateScreen
+    )?;
     Ok(())
 }
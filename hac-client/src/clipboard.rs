@@ -0,0 +1,52 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+/// sends its content to the system clipboard of the host terminal through an
+/// OSC 52 escape sequence. Since the terminal emulator is the one setting the
+/// clipboard, this works even when running over SSH, as long as the terminal
+/// supports it
+pub struct CopyToClipboard<'a>(pub &'a str);
+
+impl crossterm::Command for CopyToClipboard<'_> {
+    fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        let in_tmux = std::env::var("TMUX").is_ok_and(|tmux| !tmux.is_empty());
+        f.write_str(&osc52_sequence(self.0, in_tmux))
+    }
+
+    /// legacy windows consoles have no clipboard sequence to fall back to
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// builds the OSC 52 sequence that sets the clipboard. Tmux doesn't forward
+/// unknown sequences to the outer terminal, so we wrap it in a passthrough
+/// sequence when running inside of it
+fn osc52_sequence(content: &str, in_tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", BASE64.encode(content));
+
+    match in_tmux {
+        true => format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b")),
+        false => sequence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("hac", false), "\x1b]52;c;aGFj\x07");
+        assert_eq!(osc52_sequence("ha", false), "\x1b]52;c;aGE=\x07");
+        assert_eq!(
+            osc52_sequence("{\"a\": 1}", false),
+            "\x1b]52;c;eyJhIjogMX0=\x07"
+        );
+        assert_eq!(
+            osc52_sequence("hac", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGFj\x07\x1b\\"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crossterm::event::{Event as CrosstermEvent, KeyEventKind};
+use crossterm::event::{Event as CrosstermEvent, KeyEventKind, MouseEventKind};
 use futures::{FutureExt, StreamExt};
 use ratatui::layout::Rect;
 use std::ops::Div;
@@ -6,6 +6,7 @@ use std::ops::Div;
 #[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     Key(crossterm::event::KeyEvent),
+    Mouse(crossterm::event::MouseEvent),
     Resize(Rect),
     Tick,
     Render,
@@ -19,6 +20,7 @@ pub struct EventPool {
     event_tx: tokio::sync::mpsc::UnboundedSender<Event>,
     frame_rate: f64,
     tick_rate: f64,
+    task: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl EventPool {
@@ -30,6 +32,7 @@ impl EventPool {
             event_tx,
             frame_rate,
             tick_rate,
+            task: None,
         }
     }
 
@@ -39,7 +42,7 @@ impl EventPool {
         let tick_delay = std::time::Duration::from_secs_f64(1.0.div(self.tick_rate));
 
         let event_tx = self.event_tx.clone();
-        tokio::spawn(async move {
+        self.task = Some(tokio::spawn(async move {
             let mut reader = crossterm::event::EventStream::new();
             let mut render_interval = tokio::time::interval(render_delay);
             let mut tick_interval = tokio::time::interval(tick_delay);
@@ -57,6 +60,13 @@ impl EventPool {
                                     event_tx.send(Event::Key(key_event)).expect("failed to send event through channel");
                                 }
                             }
+                            // we don't have any use for mouse movement, and forwarding it
+                            // would only flood the channel while the mouse moves around
+                            Some(Ok(CrosstermEvent::Mouse(mouse_event))) => {
+                                if mouse_event.kind != MouseEventKind::Moved {
+                                    event_tx.send(Event::Mouse(mouse_event)).expect("failed to send event through channel");
+                                }
+                            }
                             Some(Ok(CrosstermEvent::Resize(width, height))) => event_tx
                                 .send(Event::Resize(Rect::new(0, 0, width, height)))
                                 .expect("failed to send event through channel"),
@@ -71,7 +81,16 @@ impl EventPool {
                     },
                 }
             }
-        });
+        }));
+    }
+
+    /// stops reading events from the terminal, used when handing the terminal
+    /// over to another program, as both would be competing for stdin otherwise
+    #[cfg_attr(test, mutants::skip)]
+    pub fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
     }
 
     #[cfg_attr(test, mutants::skip)]
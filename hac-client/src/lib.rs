@@ -1,5 +1,6 @@
 pub mod app;
 mod ascii;
+mod clipboard;
 pub mod event_pool;
 pub mod pages;
 pub mod screen_manager;
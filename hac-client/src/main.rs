@@ -1,6 +1,10 @@
-use hac_cli::RuntimeBehavior;
+use hac_cli::{DocsArgs, DocsFormat, ExportArgs, ImportArgs, RuntimeBehavior};
 use hac_client::app;
-use hac_core::collection::collection;
+use hac_core::collection::{collection, docs, har, openapi};
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
 
 fn setup_tracing() -> anyhow::Result<tracing_appender::non_blocking::WorkerGuard> {
     let (data_dir, logfile) = hac_config::log_file();
@@ -17,20 +21,154 @@ fn setup_tracing() -> anyhow::Result<tracing_appender::non_blocking::WorkerGuard
     Ok(guard)
 }
 
+/// loads the theme selected on the configuration file, or the default colors when
+/// no theme is selected
+fn load_colors(config: &hac_config::Config) -> anyhow::Result<hac_colors::Colors> {
+    let Some(theme) = config.theme.as_ref() else {
+        return Ok(hac_colors::Colors::default());
+    };
+
+    let theme_path = hac_config::get_theme_path(theme)
+        .ok_or_else(|| anyhow::anyhow!("could not find the themes directory"))?;
+    hac_colors::load_theme(theme_path)
+}
+
+fn config_diagnostics_message(diagnostics: &[hac_config::ConfigDiagnostic]) -> String {
+    let mut message = String::from("problems found on the configuration, they were ignored:\n\n");
+    diagnostics
+        .iter()
+        .for_each(|diagnostic| message.push_str(&format!("{diagnostic}\n")));
+    message
+}
+
+/// renders the documentation of the collection named on `args`, to the output
+/// file when one is given, or to stdout
+fn generate_docs(args: &DocsArgs, collections_dir: Option<PathBuf>) -> anyhow::Result<()> {
+    let config = hac_config::load_config();
+    let collections_dir = hac_config::resolve_collections_dir(&config, collections_dir);
+    let collection = collection::find_collection(collections_dir, &args.collection)?;
+
+    let format = match args.format {
+        DocsFormat::Markdown => docs::DocsFormat::Markdown,
+        DocsFormat::Html => docs::DocsFormat::Html,
+    };
+    let rendered = docs::render_docs(&collection, format);
+
+    match args.output.as_ref() {
+        Some(output) => std::fs::write(output, rendered)
+            .with_context(|| format!("failed to write docs to {}", output.display()))?,
+        None => print!("{rendered}"),
+    }
+
+    Ok(())
+}
+
+fn read_file(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// creates a collection from the file given on `args` and stores it on the
+/// collections directory
+async fn import_collection(
+    args: &ImportArgs,
+    collections_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let collection = match (args.source.openapi.as_ref(), args.source.har.as_ref()) {
+        (Some(path), _) => openapi::import_openapi(&read_file(path)?)
+            .with_context(|| format!("failed to import {}", path.display()))?,
+        (_, Some(path)) => har::import_har(&read_file(path)?, args.dedupe)
+            .with_context(|| format!("failed to import {}", path.display()))?,
+        (None, None) => anyhow::bail!("nothing to import"),
+    };
+
+    let config = hac_config::load_config();
+    let collections_dir = hac_config::resolve_collections_dir(&config, collections_dir);
+    std::fs::create_dir_all(&collections_dir)?;
+    let collection = hac_core::fs::import_collection(
+        collection,
+        collections_dir,
+        config.collection_layout,
+        false,
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+    println!(
+        "imported {} into {}",
+        collection.info.name,
+        collection.path.display()
+    );
+    Ok(())
+}
+
+/// writes the collection named on `args` in the format it asks for, to the
+/// output file when one is given, or to stdout
+fn export_collection(args: &ExportArgs, collections_dir: Option<PathBuf>) -> anyhow::Result<()> {
+    let config = hac_config::load_config();
+    let collections_dir = hac_config::resolve_collections_dir(&config, collections_dir);
+    let collection = collection::find_collection(collections_dir, &args.collection)?;
+
+    let exported = match args.format.openapi {
+        true => serde_json::to_string_pretty(&openapi::export_openapi(&collection))?,
+        false => anyhow::bail!("nothing to export"),
+    };
+
+    match args.output.as_ref() {
+        Some(output) => std::fs::write(output, format!("{exported}\n"))
+            .with_context(|| format!("failed to write export to {}", output.display()))?,
+        None => println!("{exported}"),
+    }
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let runtime_behavior = hac_cli::Cli::parse_args();
+    let runtime_args = hac_cli::Cli::parse_args();
+    let runtime_behavior = runtime_args.behavior;
 
-    match runtime_behavior {
-        RuntimeBehavior::PrintConfigPath => hac_cli::Cli::print_config_path(
-            hac_config::get_config_dir_path(),
-            hac_config::get_usual_path(),
-        ),
+    match &runtime_behavior {
+        RuntimeBehavior::PrintConfigPath => {
+            hac_cli::Cli::print_config_path(
+                hac_config::get_config_dir_path(),
+                hac_config::get_usual_path(),
+            );
+            return Ok(());
+        }
         RuntimeBehavior::PrintDataPath => {
-            hac_cli::Cli::print_data_path(hac_config::get_collections_dir())
+            let config = hac_config::load_config();
+            hac_cli::Cli::print_data_path(hac_config::resolve_collections_dir(
+                &config,
+                runtime_args.collections_dir,
+            ));
+            return Ok(());
         }
         RuntimeBehavior::DumpDefaultConfig => {
-            hac_cli::Cli::print_default_config(hac_config::default_as_str())
+            hac_cli::Cli::print_default_config(hac_config::default_as_str());
+            return Ok(());
+        }
+        RuntimeBehavior::CheckConfig => {
+            let (_, diagnostics) = hac_config::load_config_with_diagnostics();
+            let config_path = hac_config::get_config_dir_path().filter(|path| path.exists());
+            hac_cli::Cli::print_config_diagnostics(config_path.as_deref(), &diagnostics);
+            if !diagnostics.is_empty() {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        RuntimeBehavior::DumpTheme => {
+            let colors = load_colors(&hac_config::load_config())?;
+            hac_cli::Cli::print_theme(&colors.to_theme_string());
+            return Ok(());
+        }
+        RuntimeBehavior::GenerateDocs(args) => {
+            return generate_docs(args, runtime_args.collections_dir);
+        }
+        RuntimeBehavior::Import(args) => {
+            return import_collection(args, runtime_args.collections_dir).await;
+        }
+        RuntimeBehavior::Export(args) => {
+            return export_collection(args, runtime_args.collections_dir);
         }
         _ => {}
     }
@@ -39,12 +177,36 @@ async fn main() -> anyhow::Result<()> {
 
     let _guard = setup_tracing()?;
     hac_config::get_or_create_data_dir();
-    let config = hac_config::load_config();
+    let (mut config, diagnostics) = hac_config::load_config_with_diagnostics();
+    diagnostics
+        .iter()
+        .for_each(|diagnostic| tracing::error!("{diagnostic}"));
 
-    let colors = hac_colors::Colors::default();
-    let mut collections = collection::get_collections_from_config()?;
+    let mut startup_errors = vec![];
+    if !diagnostics.is_empty() {
+        startup_errors.push(config_diagnostics_message(&diagnostics));
+    }
+    let colors = load_colors(&config).unwrap_or_else(|e| {
+        tracing::error!("failed to load theme, using the default one: {e:?}");
+        startup_errors.push(format!(
+            "failed to load theme, using the default one:\n\n{e:#}"
+        ));
+        hac_colors::Colors::default()
+    });
+    config.collections_dir = Some(hac_config::resolve_collections_dir(
+        &config,
+        runtime_args.collections_dir,
+    ));
+    let (mut collections, broken_collections) = collection::get_collections_from_config(&config)?;
     collections.sort_by_key(|key| key.info.name.clone());
+    broken_collections
+        .iter()
+        .for_each(|broken| tracing::error!("failed to load collection: {broken}"));
     let mut app = app::App::new(&colors, collections, &config, dry_run)?;
+    app.display_broken_collections(broken_collections);
+    if !startup_errors.is_empty() {
+        app.display_error(startup_errors.join("\n"));
+    }
     app.run().await?;
 
     Ok(())
@@ -9,7 +9,7 @@ pub mod terminal_too_small;
 mod under_construction;
 
 use crate::event_pool::Event;
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use hac_core::command::Command;
 use ratatui::{layout::Rect, Frame};
 use tokio::sync::mpsc::UnboundedSender;
@@ -37,8 +37,7 @@ pub trait Renderable {
     }
 }
 
-/// An `Eventful` page is a page that can handle key events, and mouse events
-/// when support for them gets added.
+/// An `Eventful` page is a page that can handle key events and mouse events
 pub trait Eventful {
     type Result;
 
@@ -47,6 +46,7 @@ pub trait Eventful {
     fn handle_event(&mut self, event: Option<Event>) -> anyhow::Result<Option<Self::Result>> {
         match event {
             Some(Event::Key(key_event)) => self.handle_key_event(key_event),
+            Some(Event::Mouse(mouse_event)) => self.handle_mouse_event(mouse_event),
             _ => Ok(None),
         }
     }
@@ -56,4 +56,14 @@ pub trait Eventful {
     fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Self::Result>> {
         Ok(None)
     }
+
+    /// when we get a mouse_event, this will be called for the eventful component to handle it,
+    /// mouse events carry absolute positions, so components must check it against their area
+    #[allow(unused_variables)]
+    fn handle_mouse_event(
+        &mut self,
+        mouse_event: MouseEvent,
+    ) -> anyhow::Result<Option<Self::Result>> {
+        Ok(None)
+    }
 }
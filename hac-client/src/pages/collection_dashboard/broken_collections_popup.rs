@@ -0,0 +1,183 @@
+use hac_config::{Keymap, UiAction};
+use hac_core::collection::CollectionLoadError;
+
+use crate::utils::key_for;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph, Widget};
+
+/// every broken collection takes its path, the error and an empty line
+const LINES_PER_ENTRY: u16 = 3;
+
+/// lists every collection that failed to load along with what is wrong with it,
+/// so the user can fix them without digging through the logs
+pub struct BrokenCollectionsPopup<'a> {
+    broken_collections: &'a [CollectionLoadError],
+    selected: usize,
+    /// the dashboard keymap, which is also used on the popup
+    keymap: &'a Keymap,
+    colors: &'a hac_colors::Colors,
+}
+
+impl<'a> BrokenCollectionsPopup<'a> {
+    pub fn new(
+        broken_collections: &'a [CollectionLoadError],
+        selected: usize,
+        keymap: &'a Keymap,
+        colors: &'a hac_colors::Colors,
+    ) -> Self {
+        BrokenCollectionsPopup {
+            broken_collections,
+            selected,
+            keymap,
+            colors,
+        }
+    }
+
+    fn build_entries(&self) -> Vec<Line<'static>> {
+        self.broken_collections
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, broken)| {
+                let path = broken.path.to_string_lossy().to_string();
+                let path = match idx.eq(&self.selected) {
+                    true => Line::from(vec![
+                        "> ".fg(self.colors.normal.magenta),
+                        path.fg(self.colors.normal.magenta).bold(),
+                    ]),
+                    false => Line::from(vec!["  ".into(), path.fg(self.colors.bright.black)]),
+                };
+
+                let message = match broken.location {
+                    Some((line, column)) => format!("  {line}:{column}: {}", broken.message),
+                    None => format!("  {}", broken.message),
+                };
+
+                [
+                    path,
+                    Line::from(message.fg(self.colors.normal.red)),
+                    Line::from(""),
+                ]
+            })
+            .collect()
+    }
+
+    /// hints are built from the keymap, leaving out actions with no key bound
+    fn build_hint(&self) -> Line<'static> {
+        let hints = [
+            (
+                [UiAction::MoveDown, UiAction::MoveUp].as_slice(),
+                "move",
+                self.colors.bright.black,
+            ),
+            (
+                [UiAction::Edit, UiAction::Select].as_slice(),
+                "repair in editor",
+                self.colors.normal.green,
+            ),
+            (
+                [UiAction::Reload].as_slice(),
+                "retry",
+                self.colors.normal.yellow,
+            ),
+        ];
+
+        let mut spans = hints
+            .into_iter()
+            .filter_map(|(actions, label, color)| {
+                let keys = actions
+                    .iter()
+                    .filter_map(|action| key_for(self.keymap, *action))
+                    .collect::<Vec<_>>();
+                (!keys.is_empty()).then(|| format!("[{} -> {label}] ", keys.join("/")).fg(color))
+            })
+            .collect::<Vec<_>>();
+        spans.push("[Esc -> close]".fg(self.colors.bright.black));
+
+        Line::from(spans).centered()
+    }
+
+    fn build_container(&self) -> Block<'_> {
+        Block::default()
+            .title(" Collections that failed to load ".fg(self.colors.normal.red))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.colors.bright.black))
+            .padding(Padding::new(2, 2, 1, 1))
+            .bg(self.colors.normal.black)
+    }
+}
+
+impl Widget for BrokenCollectionsPopup<'_> {
+    fn render(self, size: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(size, buf);
+        let container = self.build_container();
+        let inner = container.inner(size);
+        container.render(size, buf);
+
+        let [entries_pane, _, hint_pane] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .areas(inner);
+
+        // keeps the selected entry on screen when there are more entries than
+        // what fits on the popup
+        let selected_bottom = (self.selected as u16)
+            .saturating_add(1)
+            .saturating_mul(LINES_PER_ENTRY);
+        let scroll = selected_bottom.saturating_sub(entries_pane.height);
+
+        Paragraph::new(self.build_entries())
+            .scroll((scroll, 0))
+            .render(entries_pane, buf);
+        self.build_hint().render(hint_pane, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_selected_entry_is_highlighted() {
+        let colors = hac_colors::Colors::default();
+        let broken = vec![
+            CollectionLoadError {
+                collection_path: PathBuf::from("first.json"),
+                path: PathBuf::from("first.json"),
+                location: Some((3, 1)),
+                message: "EOF while parsing an object".into(),
+            },
+            CollectionLoadError {
+                collection_path: PathBuf::from("second.json"),
+                path: PathBuf::from("second.json"),
+                location: None,
+                message: "missing field `info`".into(),
+            },
+        ];
+
+        let keymap = Keymap::default();
+        let popup = BrokenCollectionsPopup::new(&broken, 1, &keymap, &colors);
+        let entries = popup.build_entries();
+
+        assert_eq!(entries.len(), broken.len() * LINES_PER_ENTRY as usize);
+        assert_eq!(entries[0].spans[0].content, "  ");
+        assert_eq!(
+            entries[1].spans[0].content,
+            "  3:1: EOF while parsing an object"
+        );
+        assert_eq!(entries[3].spans[0].content, "> ");
+        assert_eq!(entries[4].spans[0].content, "  missing field `info`");
+    }
+}
@@ -1,5 +1,8 @@
+use hac_config::UiAction;
+use hac_core::collection::{har, openapi, CollectionLoadError};
 use hac_core::{collection::types::Collection, command::Command};
 
+use crate::pages::collection_dashboard::broken_collections_popup::BrokenCollectionsPopup;
 use crate::pages::collection_dashboard::collection_list::{CollectionList, CollectionListState};
 use crate::pages::collection_dashboard::new_collection_form::{
     FormFocus, FormState, NewCollectionForm,
@@ -8,8 +11,10 @@ use crate::pages::confirm_popup::ConfirmPopup;
 use crate::pages::error_popup::ErrorPopup;
 use crate::pages::overlay::{draw_overlay, make_overlay};
 use crate::pages::{Eventful, Renderable};
+use crate::utils::{key_for, keycode_as_string};
 
-use std::ops::{Add, Div, Not, Sub};
+use std::ops::{Add, Div, Mul, Not, Sub};
+use std::path::{Path, PathBuf};
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::layout::{Alignment, Constraint, Direction, Flex, Layout, Rect};
@@ -29,6 +34,7 @@ struct DashboardLayout {
     confirm_popup: Rect,
     form_popup: Rect,
     error_popup: Rect,
+    broken_collections_popup: Rect,
 }
 
 #[derive(Debug)]
@@ -40,10 +46,16 @@ pub struct CollectionDashboard<'a> {
     list_state: CollectionListState,
     form_state: FormState,
     colors: &'a hac_colors::Colors,
+    config: &'a hac_config::Config,
     filter: String,
+    /// path of the file being imported, typed on the import prompt
+    import_path: String,
     pane_focus: PaneFocus,
     pub command_sender: Option<UnboundedSender<Command>>,
     error_message: String,
+    /// collections that failed to load, which are listed so the user can fix them
+    broken_collections: Vec<CollectionLoadError>,
+    selected_broken_collection: usize,
     dry_run: bool,
 }
 
@@ -55,6 +67,8 @@ enum PaneFocus {
     Prompt,
     Help,
     Filter,
+    Import,
+    BrokenCollections,
 }
 
 impl<'a> CollectionDashboard<'a> {
@@ -62,6 +76,7 @@ impl<'a> CollectionDashboard<'a> {
         size: Rect,
         colors: &'a hac_colors::Colors,
         collections: Vec<Collection>,
+        config: &'a hac_config::Config,
         dry_run: bool,
     ) -> anyhow::Result<Self> {
         let mut list_state = CollectionListState::new(collections.clone());
@@ -74,12 +89,16 @@ impl<'a> CollectionDashboard<'a> {
             list_state,
             form_state: FormState::default(),
             colors,
+            config,
             layout: build_layout(size),
             collections,
             list: CollectionList::new(colors),
             filter: String::new(),
+            import_path: String::new(),
             command_sender: None,
             error_message: String::default(),
+            broken_collections: vec![],
+            selected_broken_collection: 0,
             pane_focus: PaneFocus::List,
             dry_run,
         })
@@ -90,6 +109,93 @@ impl<'a> CollectionDashboard<'a> {
         self.error_message = message;
     }
 
+    /// lists the collections that failed to load, which is shown right away so
+    /// broken collections don't go unnoticed
+    pub fn display_broken_collections(&mut self, broken_collections: Vec<CollectionLoadError>) {
+        self.broken_collections = broken_collections;
+        self.selected_broken_collection = 0;
+        if !self.broken_collections.is_empty() {
+            self.pane_focus = PaneFocus::BrokenCollections;
+        }
+    }
+
+    /// tries to load a broken collection again after one of its files changed,
+    /// moving it into the collections list when it loads
+    pub fn reload_broken_collection(&mut self, path: &Path) {
+        let Some(idx) = self
+            .broken_collections
+            .iter()
+            .position(|broken| broken.path.eq(path) || broken.collection_path.eq(path))
+        else {
+            return;
+        };
+
+        let collection_path = self.broken_collections[idx].collection_path.clone();
+        match hac_core::collection::collection::load_collection(&collection_path) {
+            Ok(collection) => {
+                tracing::debug!("repaired collection: {collection_path:?}");
+                self.broken_collections.remove(idx);
+                self.collections.push(collection);
+                self.collections
+                    .sort_by(|a, b| a.info.name.cmp(&b.info.name));
+                self.list_state.set_items(self.collections.clone());
+                self.list_state.select(Some(0));
+                self.selected_broken_collection = self
+                    .selected_broken_collection
+                    .min(self.broken_collections.len().saturating_sub(1));
+                if self.broken_collections.is_empty() {
+                    self.pane_focus = PaneFocus::List;
+                }
+            }
+            Err(e) => {
+                self.broken_collections[idx] = CollectionLoadError::new(&collection_path, &e);
+            }
+        }
+    }
+
+    fn handle_broken_collections_key_event(
+        &mut self,
+        key_event: KeyEvent,
+    ) -> anyhow::Result<Option<Command>> {
+        let Some(broken) = self.broken_collections.get(self.selected_broken_collection) else {
+            self.pane_focus = PaneFocus::List;
+            return Ok(None);
+        };
+
+        if key_event.code.eq(&KeyCode::Esc) {
+            self.pane_focus = PaneFocus::List;
+            return Ok(None);
+        }
+
+        match self
+            .config
+            .keymaps
+            .dashboard
+            .get(&keycode_as_string(key_event))
+        {
+            Some(UiAction::MoveDown) => {
+                self.selected_broken_collection = usize::min(
+                    self.selected_broken_collection.add(1),
+                    self.broken_collections.len().sub(1),
+                );
+            }
+            Some(UiAction::MoveUp) => {
+                self.selected_broken_collection = self.selected_broken_collection.saturating_sub(1);
+            }
+            Some(UiAction::Edit | UiAction::Select) => {
+                return Ok(Some(Command::OpenExternalEditor(broken.path.clone())));
+            }
+            Some(UiAction::Reload) => {
+                let path = broken.collection_path.clone();
+                self.reload_broken_collection(&path);
+            }
+            Some(UiAction::ShowErrors) => self.pane_focus = PaneFocus::List,
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
     fn filter_list(&mut self) {
         self.list_state.set_items(
             self.collections
@@ -129,9 +235,80 @@ impl<'a> CollectionDashboard<'a> {
         Ok(None)
     }
 
-    fn handle_list_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Command>> {
+    fn handle_import_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Command>> {
         match key_event.code {
+            KeyCode::Esc => {
+                self.pane_focus = PaneFocus::List;
+                self.import_path.clear();
+            }
+            KeyCode::Backspace => _ = self.import_path.pop(),
             KeyCode::Enter => {
+                self.pane_focus = PaneFocus::List;
+                let path = std::mem::take(&mut self.import_path);
+                if !path.trim().is_empty() {
+                    self.import_from_file(path.trim().into());
+                }
+            }
+            KeyCode::Char(c) => self.import_path.push(c),
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    /// imports the OpenAPI spec or HAR file at `path` as a new collection, which
+    /// is opened just like a collection created from the form. HAR files are
+    /// told apart by their extension
+    fn import_from_file(&self, path: PathBuf) {
+        let sender = self
+            .command_sender
+            .clone()
+            .expect("should always have a sender at this point");
+        let dry_run = self.dry_run;
+        let collections_dir = hac_config::get_collections_dir(self.config);
+        let collection_layout = self.config.collection_layout;
+
+        tokio::spawn(async move {
+            let imported = std::fs::read_to_string(&path)
+                .map_err(|e| format!("failed to read {}: {e}", path.display()))
+                .and_then(|content| {
+                    let is_har = path.extension().is_some_and(|ext| ext.eq("har"));
+                    match is_har {
+                        true => har::import_har(&content, false),
+                        false => openapi::import_openapi(&content),
+                    }
+                    .map_err(|e| format!("failed to import {}: {e}", path.display()))
+                });
+            let command = match imported {
+                Ok(collection) => match hac_core::fs::import_collection(
+                    collection,
+                    collections_dir,
+                    collection_layout,
+                    dry_run,
+                )
+                .await
+                {
+                    Ok(collection) => Command::CreateCollection(collection),
+                    Err(e) => Command::Error(e.to_string()),
+                },
+                Err(message) => Command::Error(message),
+            };
+
+            if sender.send(command).is_err() {
+                tracing::error!("failed to send command through channel");
+                std::process::abort();
+            }
+        });
+    }
+
+    fn handle_list_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Command>> {
+        match self
+            .config
+            .keymaps
+            .dashboard
+            .get(&keycode_as_string(key_event))
+        {
+            Some(UiAction::Select) => {
                 return Ok(self
                     .list_state
                     .items
@@ -150,15 +327,16 @@ impl<'a> CollectionDashboard<'a> {
                         Command::SelectCollection(collection.clone())
                     }));
             }
-            KeyCode::Char('d') => {
+            Some(UiAction::Delete) => {
                 if self.list_state.selected().is_some() {
                     self.pane_focus = PaneFocus::Prompt;
                 }
             }
-            KeyCode::Char('n') | KeyCode::Char('c') => {
+            Some(UiAction::Create) => {
                 self.pane_focus = PaneFocus::Form;
             }
-            KeyCode::Char('h') | KeyCode::Left => {
+            Some(UiAction::Import) => self.pane_focus = PaneFocus::Import,
+            Some(UiAction::MoveLeft) => {
                 if !self.list_state.items.is_empty() {
                     self.list_state.select(
                         self.list_state
@@ -168,7 +346,7 @@ impl<'a> CollectionDashboard<'a> {
                     );
                 }
             }
-            KeyCode::Char('j') | KeyCode::Down => {
+            Some(UiAction::MoveDown) => {
                 if !self.list_state.items.is_empty() {
                     self.list_state.select(
                         self.list_state
@@ -183,7 +361,7 @@ impl<'a> CollectionDashboard<'a> {
                     );
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            Some(UiAction::MoveUp) => {
                 if !self.list_state.items.is_empty() {
                     self.list_state.select(
                         self.list_state
@@ -197,7 +375,7 @@ impl<'a> CollectionDashboard<'a> {
                     );
                 }
             }
-            KeyCode::Char('l') | KeyCode::Right => {
+            Some(UiAction::MoveRight) => {
                 if !self.list_state.items.is_empty() {
                     self.list_state.select(
                         self.list_state
@@ -207,8 +385,11 @@ impl<'a> CollectionDashboard<'a> {
                     );
                 }
             }
-            KeyCode::Char('?') => self.pane_focus = PaneFocus::Help,
-            KeyCode::Char('/') => self.pane_focus = PaneFocus::Filter,
+            Some(UiAction::Help) => self.pane_focus = PaneFocus::Help,
+            Some(UiAction::Filter) => self.pane_focus = PaneFocus::Filter,
+            Some(UiAction::ShowErrors) if !self.broken_collections.is_empty() => {
+                self.pane_focus = PaneFocus::BrokenCollections;
+            }
             _ => {}
         };
         Ok(None)
@@ -238,9 +419,19 @@ impl<'a> CollectionDashboard<'a> {
                         .expect("should always have a sender at this point");
 
                     let dry_run = self.dry_run;
+                    let collections_dir = hac_config::get_collections_dir(self.config);
+                    let collection_layout = self.config.collection_layout;
 
                     tokio::spawn(async move {
-                        match hac_core::fs::create_collection(name, description, dry_run).await {
+                        match hac_core::fs::create_collection(
+                            name,
+                            description,
+                            collections_dir,
+                            collection_layout,
+                            dry_run,
+                        )
+                        .await
+                        {
                             Ok(collection) => {
                                 if sender_copy
                                     .send(Command::CreateCollection(collection))
@@ -334,6 +525,25 @@ impl<'a> CollectionDashboard<'a> {
     }
 
     fn draw_hint_text(&self, frame: &mut Frame) {
+        if !self.broken_collections.is_empty() {
+            let keymap = &self.config.keymaps.dashboard;
+            let mut hint = format!(
+                "[{} collection(s) failed to load",
+                self.broken_collections.len()
+            );
+            if let Some(key) = key_for(keymap, UiAction::ShowErrors) {
+                hint.push_str(&format!(", {key} -> review them"));
+            }
+            hint.push(']');
+            if let Some(key) = key_for(keymap, UiAction::Help) {
+                hint.push_str(&format!(" [{key} -> help]"));
+            }
+            let hint = hint.fg(self.colors.normal.red).into_centered_line();
+
+            frame.render_widget(hint, self.layout.hint_pane);
+            return;
+        }
+
         let hint =
             "[h/j/k/l to move] [n -> new] [enter -> select item] [? -> help] [<C-c> -> quit]"
                 .fg(self.colors.normal.magenta)
@@ -366,6 +576,10 @@ impl<'a> CollectionDashboard<'a> {
                 "n/c".fg(self.colors.bright.magenta),
                 "         - creates a new collection".into(),
             ]),
+            Line::from(vec![
+                "i".fg(self.colors.bright.magenta),
+                "           - imports an OpenAPI spec or HAR".into(),
+            ]),
             Line::from(vec![
                 "d".fg(self.colors.bright.magenta),
                 "           - deletes the selected collection".into(),
@@ -382,6 +596,10 @@ impl<'a> CollectionDashboard<'a> {
                 "/".fg(self.colors.bright.magenta),
                 "           - enter filter mode".into(),
             ]),
+            Line::from(vec![
+                "!".fg(self.colors.bright.magenta),
+                "           - list broken collections".into(),
+            ]),
             Line::from(vec![
                 "<C-c>".fg(self.colors.bright.magenta),
                 "       - quits the application".into(),
@@ -403,6 +621,14 @@ impl<'a> CollectionDashboard<'a> {
         frame.render_widget(filter, self.layout.hint_pane);
     }
 
+    fn draw_import_prompt(&self, frame: &mut Frame) {
+        let prompt = Line::from(vec![
+            "OpenAPI spec or HAR file to import: ".fg(self.colors.normal.magenta),
+            self.import_path.clone().into(),
+        ]);
+        frame.render_widget(prompt, self.layout.hint_pane);
+    }
+
     fn draw_collection_list(&mut self, frame: &mut Frame) {
         frame.render_stateful_widget(
             self.list.clone(),
@@ -466,6 +692,17 @@ impl<'a> CollectionDashboard<'a> {
         popup.render(self.layout.error_popup, frame.buffer_mut());
     }
 
+    fn draw_broken_collections_popup(&self, frame: &mut Frame) {
+        make_overlay(self.colors, self.colors.primary.background, 0.2, frame);
+        let popup = BrokenCollectionsPopup::new(
+            &self.broken_collections,
+            self.selected_broken_collection,
+            &self.config.keymaps.dashboard,
+            self.colors,
+        );
+        popup.render(self.layout.broken_collections_popup, frame.buffer_mut());
+    }
+
     fn draw_form_popup(&mut self, size: Rect, frame: &mut Frame) {
         self.draw_background(size, frame);
         draw_overlay(self.colors, size, "新", frame);
@@ -533,8 +770,10 @@ impl Renderable for CollectionDashboard<'_> {
             PaneFocus::Error => self.draw_error_popup(frame),
             PaneFocus::Form => self.draw_form_popup(size, frame),
             PaneFocus::Filter => self.draw_filter_prompt(frame),
+            PaneFocus::Import => self.draw_import_prompt(frame),
             PaneFocus::Help => self.draw_help_popup(frame),
             PaneFocus::Prompt => self.draw_delete_prompt(frame),
+            PaneFocus::BrokenCollections => self.draw_broken_collections_popup(frame),
             PaneFocus::List => self.draw_hint_text(frame),
         }
 
@@ -565,6 +804,8 @@ impl Eventful for CollectionDashboard<'_> {
             PaneFocus::Error => self.handle_error_popup_key_event(key_event),
             PaneFocus::Prompt => self.handle_confirm_popup_key_event(key_event),
             PaneFocus::Filter => self.handle_filter_key_event(key_event),
+            PaneFocus::Import => self.handle_import_key_event(key_event),
+            PaneFocus::BrokenCollections => self.handle_broken_collections_key_event(key_event),
             PaneFocus::Help => {
                 self.pane_focus = PaneFocus::List;
                 Ok(None)
@@ -593,7 +834,7 @@ fn build_layout(size: Rect) -> DashboardLayout {
         size.width.div(2).saturating_sub(25),
         size.height.div(2).saturating_sub(7),
         50,
-        14,
+        16,
     );
     let confirm_popup = Rect::new(
         size.width.div(4),
@@ -614,6 +855,13 @@ fn build_layout(size: Rect) -> DashboardLayout {
         20,
     );
 
+    let broken_collections_popup = Rect::new(
+        size.width.div(8),
+        size.height.div(2).saturating_sub(10),
+        size.width.div(4).mul(3),
+        20,
+    );
+
     DashboardLayout {
         collections_pane,
         hint_pane: help_pane,
@@ -622,6 +870,7 @@ fn build_layout(size: Rect) -> DashboardLayout {
         confirm_popup,
         form_popup,
         error_popup,
+        broken_collections_popup,
     }
 }
 
@@ -672,10 +921,11 @@ mod tests {
             collections_pane: Rect::new(1, 6, 79, 17),
             hint_pane: Rect::new(1, 23, 79, 1),
             title_pane: Rect::new(1, 1, 79, 5),
-            help_popup: Rect::new(14, 5, 50, 14),
+            help_popup: Rect::new(14, 5, 50, 16),
             confirm_popup: Rect::new(19, 8, 39, 8),
             form_popup: Rect::new(19, 5, 39, 14),
             error_popup: Rect::new(19, 2, 39, 20),
+            broken_collections_popup: Rect::new(9, 2, 57, 20),
         };
 
         let layout = build_layout(size);
@@ -687,10 +937,12 @@ mod tests {
     fn test_open_close_help() {
         let size = Rect::new(0, 0, 80, 24);
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let (_guard, path) = setup_temp_collections(1);
         let collection = collection::collection::get_collections(path).unwrap();
 
-        let mut dashboard = CollectionDashboard::new(size, &colors, collection, false).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collection, &config, false).unwrap();
 
         assert_eq!(dashboard.collections.len(), 1);
         assert_eq!(dashboard.list_state.selected(), Some(0));
@@ -716,7 +968,9 @@ mod tests {
     fn test_actions_without_any_collections() {
         let size = Rect::new(0, 0, 80, 24);
         let colors = hac_colors::Colors::default();
-        let mut dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+        let config = hac_config::load_default_config();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
 
         assert!(dashboard.collections.is_empty());
         assert_eq!(dashboard.list_state.selected(), None);
@@ -736,14 +990,43 @@ mod tests {
         assert_eq!(dashboard.list_state.selected(), None);
     }
 
+    #[test]
+    fn test_custom_keymap() {
+        let size = Rect::new(0, 0, 80, 24);
+        let colors = hac_colors::Colors::default();
+        let mut config = hac_config::load_default_config();
+        config.keymaps.dashboard.clear();
+        config
+            .keymaps
+            .dashboard
+            .insert("t".into(), UiAction::MoveRight);
+        let (_guard, path) = setup_temp_collections(10);
+        let collections = collection::collection::get_collections(path).unwrap();
+
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
+
+        feed_keys(
+            &mut dashboard,
+            &[
+                KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE),
+            ],
+        );
+
+        assert_eq!(dashboard.list_state.selected(), Some(1));
+    }
+
     #[test]
     fn test_filtering_list() {
         let size = Rect::new(0, 0, 80, 24);
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let (_guard, path) = setup_temp_collections(10);
         let collections = collection::collection::get_collections(path).unwrap();
 
-        let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
 
         assert_eq!(dashboard.collections.len(), 10);
         assert_eq!(dashboard.list_state.selected(), Some(0));
@@ -808,10 +1091,12 @@ mod tests {
     fn test_moving_out_of_bounds() {
         let size = Rect::new(0, 0, 80, 24);
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let (_guard, path) = setup_temp_collections(3);
         let collections = collection::collection::get_collections(path).unwrap();
 
-        let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
 
         feed_keys(
             &mut dashboard,
@@ -846,10 +1131,12 @@ mod tests {
     fn test_creating_new_collections() {
         let size = Rect::new(0, 0, 80, 24);
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let (_guard, path) = setup_temp_collections(3);
         let collections = collection::collection::get_collections(path).unwrap();
 
-        let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
 
         feed_keys(
             &mut dashboard,
@@ -919,13 +1206,50 @@ mod tests {
         assert_eq!(dashboard.form_state.description, "World");
     }
 
+    #[test]
+    fn test_import_prompt() {
+        let size = Rect::new(0, 0, 80, 24);
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let (_guard, path) = setup_temp_collections(3);
+        let collections = collection::collection::get_collections(path).unwrap();
+
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
+
+        feed_keys(
+            &mut dashboard,
+            &[
+                KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE),
+            ],
+        );
+
+        assert_eq!(dashboard.pane_focus, PaneFocus::Import);
+        assert_eq!(dashboard.import_path, "api");
+
+        feed_keys(
+            &mut dashboard,
+            &[KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)],
+        );
+
+        assert_eq!(dashboard.pane_focus, PaneFocus::List);
+        assert!(dashboard.import_path.is_empty());
+    }
+
     #[test]
     fn test_prompt_delete_collections() {
         let size = Rect::new(0, 0, 80, 24);
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let (_guard, path) = setup_temp_collections(3);
         let collections = collection::collection::get_collections(path).unwrap();
-        let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
 
         feed_keys(
             &mut dashboard,
@@ -946,7 +1270,9 @@ mod tests {
     fn test_display_error() {
         let size = Rect::new(0, 0, 80, 24);
         let colors = hac_colors::Colors::default();
-        let mut dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+        let config = hac_config::load_default_config();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
 
         dashboard.display_error("any error message".into());
 
@@ -957,8 +1283,9 @@ mod tests {
     #[test]
     fn test_draw_background() {
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let size = Rect::new(0, 0, 80, 22);
-        let dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+        let dashboard = CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
 
         let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
         let mut frame = terminal.get_frame();
@@ -977,10 +1304,12 @@ mod tests {
     #[test]
     fn test_close_error_popup() {
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let size = Rect::new(0, 0, 80, 22);
         let (_guard, path) = setup_temp_collections(3);
         let collections = collection::collection::get_collections(path).unwrap();
-        let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
 
         dashboard.display_error("any_error_message".into());
         assert_eq!(dashboard.pane_focus, PaneFocus::Error);
@@ -995,22 +1324,105 @@ mod tests {
     #[test]
     fn test_resizing() {
         let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
         let size = Rect::new(0, 0, 80, 22);
         let new_size = Rect::new(0, 0, 80, 24);
         let (_guard, path) = setup_temp_collections(3);
         let collections = collection::collection::get_collections(path).unwrap();
-        let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
         let expected = DashboardLayout {
             collections_pane: Rect::new(1, 6, 79, 17),
             hint_pane: Rect::new(1, 23, 79, 1),
             title_pane: Rect::new(1, 1, 79, 5),
-            help_popup: Rect::new(14, 5, 50, 14),
+            help_popup: Rect::new(14, 5, 50, 16),
             confirm_popup: Rect::new(19, 8, 39, 8),
             form_popup: Rect::new(19, 5, 39, 14),
             error_popup: Rect::new(19, 2, 39, 20),
+            broken_collections_popup: Rect::new(9, 2, 57, 20),
         };
 
         dashboard.resize(new_size);
         assert_eq!(dashboard.layout, expected);
     }
+
+    #[test]
+    fn test_repairing_broken_collections() {
+        let size = Rect::new(0, 0, 80, 24);
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let (_guard, path) = setup_temp_collections(1);
+        let broken_path = std::path::Path::new(&path).join("broken.json");
+        std::fs::write(&broken_path, r#"{ "info": { "name": "broken" "#).unwrap();
+
+        let (collections, broken) =
+            collection::collection::get_collections_with_errors(&path).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
+        dashboard.display_broken_collections(broken);
+
+        assert_eq!(dashboard.collections.len(), 1);
+        assert_eq!(dashboard.pane_focus, PaneFocus::BrokenCollections);
+
+        let command = dashboard
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+        assert!(
+            matches!(command, Some(Command::OpenExternalEditor(path)) if path.eq(&broken_path))
+        );
+
+        // still broken, so it stays on the list with the new error
+        std::fs::write(&broken_path, r#"{ "info": {} }"#).unwrap();
+        dashboard.reload_broken_collection(&broken_path);
+        assert_eq!(dashboard.broken_collections.len(), 1);
+        assert!(dashboard.broken_collections[0].message.contains("name"));
+
+        std::fs::write(&broken_path, r#"{ "info": { "name": "repaired" } }"#).unwrap();
+        dashboard.reload_broken_collection(&broken_path);
+        assert!(dashboard.broken_collections.is_empty());
+        assert_eq!(dashboard.collections.len(), 2);
+        assert_eq!(dashboard.pane_focus, PaneFocus::List);
+    }
+
+    #[test]
+    fn test_broken_collections_use_the_dashboard_keymap() {
+        let size = Rect::new(0, 0, 80, 24);
+        let colors = hac_colors::Colors::default();
+        let mut config = hac_config::load_default_config();
+        config.keymaps.dashboard.remove("!");
+        config.keymaps.dashboard.remove("e");
+        config
+            .keymaps
+            .dashboard
+            .insert("S-E".into(), UiAction::ShowErrors);
+        config.keymaps.dashboard.insert("o".into(), UiAction::Edit);
+        let (_guard, path) = setup_temp_collections(1);
+        let broken_path = std::path::Path::new(&path).join("broken.json");
+        std::fs::write(&broken_path, r#"{ "info": { "name": "broken" "#).unwrap();
+
+        let (collections, broken) =
+            collection::collection::get_collections_with_errors(&path).unwrap();
+        let mut dashboard =
+            CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
+        dashboard.display_broken_collections(broken);
+
+        let command = dashboard
+            .handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE))
+            .unwrap();
+        assert!(command.is_none());
+        let command = dashboard
+            .handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE))
+            .unwrap();
+        assert!(matches!(command, Some(Command::OpenExternalEditor(_))));
+
+        feed_keys(
+            &mut dashboard,
+            &[KeyEvent::new(KeyCode::Char('E'), KeyModifiers::NONE)],
+        );
+        assert_eq!(dashboard.pane_focus, PaneFocus::List);
+        assert_eq!(
+            key_for(&config.keymaps.dashboard, UiAction::ShowErrors),
+            Some("S-E")
+        );
+    }
 }
@@ -182,11 +182,14 @@ mod tests {
 
     fn sample_collection() -> Collection {
         Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
             info: Info {
                 name: String::from("any_name"),
                 description: None,
             },
+            environment: Default::default(),
             path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
             requests: None,
         }
     }
@@ -222,11 +225,14 @@ mod tests {
         let colors = hac_colors::Colors::default();
         let collection_list = CollectionList::new(&colors);
         let collections = vec![Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
             info: Info {
                 name: String::from("any_name"),
                 description: None,
             },
+            environment: Default::default(),
             path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
             requests: None,
         }];
         let state = CollectionListState::new(collections.clone());
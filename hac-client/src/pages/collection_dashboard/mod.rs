@@ -1,3 +1,4 @@
+mod broken_collections_popup;
 #[allow(clippy::module_inception)]
 mod collection_dashboard;
 mod collection_list;
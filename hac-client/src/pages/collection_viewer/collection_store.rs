@@ -1,11 +1,16 @@
+use hac_core::collection::search;
+use hac_core::collection::tree::{self, find_entry};
 use hac_core::collection::types::{Request, RequestKind};
 use hac_core::collection::Collection;
+use hac_core::net::request_manager::Response;
 
 use crate::pages::collection_viewer::collection_viewer::CollectionViewerOverlay;
 use crate::pages::collection_viewer::collection_viewer::PaneFocus;
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
@@ -14,11 +19,55 @@ pub struct CollectionState {
     collection: Rc<RefCell<Collection>>,
     hovered_request: Option<String>,
     selected_request: Option<Arc<RwLock<Request>>>,
+    /// requests open as tabs, in the order they were opened. The selected
+    /// request is always one of them
+    open_tabs: Vec<Arc<RwLock<Request>>>,
     dirs_expanded: Rc<RefCell<HashMap<String, bool>>>,
     selected_pane: Option<PaneFocus>,
     focused_pane: PaneFocus,
-    has_pending_request: bool,
+    /// requests waiting for their response, every open tab may have one
+    pending_requests: HashSet<String>,
     overlay_stack: Vec<CollectionViewerOverlay>,
+    /// item waiting to be pasted somewhere else on the tree, it is only moved
+    /// when pasted, so cutting and never pasting changes nothing
+    cut_item: Option<String>,
+    /// text narrowing the items shown on the sidebar, while the user is filtering
+    sidebar_filter: Option<String>,
+    /// which directories were expanded before filtering, as filtering expands
+    /// every directory with a match, and clearing the filter should undo that
+    expansion_before_filter: Option<HashMap<String, bool>>,
+    /// last filter applied to the response of each request, keyed by request id
+    response_filters: HashMap<String, String>,
+    /// response the user marked to be diffed against another one later
+    marked_response: Option<MarkedResponse>,
+    /// registers are shared between every request of the collection, so we can
+    /// yank from a request body and paste into another
+    registers: HashMap<char, Register>,
+    /// hash of the collection as it was last synced to disk, requests are edited
+    /// in place from many places, so comparing hashes is how we know if there is
+    /// anything to save
+    synced_hash: u64,
+    /// hash of the collection being written to disk right now, if any
+    syncing_hash: Option<u64>,
+}
+
+/// the register used when no register is specified, just like on vim
+pub const UNNAMED_REGISTER: char = '"';
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Register {
+    pub content: String,
+    /// linewise registers are pasted as whole lines, below or above the cursor
+    pub linewise: bool,
+}
+
+/// a response kept around to be diffed, it is not replaced when its request gets
+/// a new response, so a request can be diffed against an earlier response of it
+#[derive(Debug, Clone)]
+pub struct MarkedResponse {
+    /// what the response is shown as on the diff, like `GET list users (200 OK)`
+    pub label: String,
+    pub response: Rc<RefCell<Response>>,
 }
 
 #[derive(Debug, Default)]
@@ -28,7 +77,11 @@ pub struct CollectionStore {
 
 #[derive(Debug)]
 pub enum CollectionStoreAction {
+    /// selects a request, opening a tab for it when it is not open yet
     SetSelectedRequest(Option<Arc<RwLock<Request>>>),
+    /// closes the tab of a request, selecting one of its neighbours when it was
+    /// the selected one
+    CloseTab(String),
     SetHoveredRequest(Option<String>),
     InsertRequest(RequestKind),
     HoverPrev,
@@ -36,7 +89,39 @@ pub enum CollectionStoreAction {
     ToggleDirectory(String),
     SetFocusedPane(PaneFocus),
     SetSelectedPane(Option<PaneFocus>),
-    SetPendingRequest(bool),
+    /// marks whether the request with the given id is waiting for a response
+    SetPendingRequest(String, bool),
+    SetResponseFilter(String, Option<String>),
+    MarkResponse(Option<MarkedResponse>),
+    SetRegister(char, Register),
+    /// marks the collection with the given hash as being written to disk
+    MarkSyncing(u64),
+    /// marks the collection with the given hash as saved to disk, which only
+    /// happens once it was actually written
+    MarkSynced(u64),
+    /// writing the collection with the given hash failed, so it is still unsaved
+    MarkSyncFailed(u64),
+    /// replaces the collection with a version loaded from disk, keeping the
+    /// selected request, hovered item and expanded directories when they still
+    /// exist on the new version
+    ReloadCollection(Collection),
+    /// marks an item to be moved by the next `PasteItem`, `None` cancels it
+    SetCutItem(Option<String>),
+    /// moves the cut item into the hovered directory, or right after the
+    /// hovered request, pointing its `parent` to wherever it ends up
+    PasteItem,
+    /// swaps an item with the sibling above it
+    MoveItemUp(String),
+    /// swaps an item with the sibling below it
+    MoveItemDown(String),
+    /// copies an item, and everything inside of it, right below the original
+    DuplicateItem(String),
+    /// expands every directory holding an item and hovers it, so it is visible
+    /// on the sidebar no matter how deep it is
+    RevealItem(String),
+    /// narrows the sidebar to requests matching the filter, and the directories
+    /// holding them. `None` clears the filter, restoring the expanded directories
+    SetSidebarFilter(Option<String>),
 }
 
 impl CollectionStore {
@@ -57,14 +142,23 @@ impl CollectionStore {
             .and_then(|items| items.read().unwrap().first().map(|item| item.get_id()));
 
         let state = CollectionState {
+            synced_hash: collection_hash(&collection),
+            syncing_hash: None,
+            open_tabs: selected_request.iter().cloned().collect(),
             selected_request,
             hovered_request,
             dirs_expanded: Rc::new(RefCell::new(HashMap::default())),
             collection: Rc::new(RefCell::new(collection)),
             focused_pane: PaneFocus::Sidebar,
             selected_pane: None,
-            has_pending_request: false,
+            pending_requests: HashSet::default(),
             overlay_stack: vec![],
+            cut_item: None,
+            sidebar_filter: None,
+            expansion_before_filter: None,
+            response_filters: HashMap::default(),
+            marked_response: None,
+            registers: HashMap::default(),
         };
 
         self.state = Some(Rc::new(RefCell::new(state)));
@@ -74,8 +168,20 @@ impl CollectionStore {
         if let Some(ref state) = self.state {
             match action {
                 CollectionStoreAction::SetSelectedRequest(maybe_req) => {
-                    state.borrow_mut().selected_request = maybe_req
+                    let mut state = state.borrow_mut();
+                    if let Some(request) = maybe_req.as_ref() {
+                        let id = request.read().unwrap().id.clone();
+                        if !state
+                            .open_tabs
+                            .iter()
+                            .any(|tab| tab.read().unwrap().id.eq(&id))
+                        {
+                            state.open_tabs.push(request.clone());
+                        }
+                    }
+                    state.selected_request = maybe_req
                 }
+                CollectionStoreAction::CloseTab(request_id) => self.close_tab(&request_id),
                 CollectionStoreAction::SetHoveredRequest(maybe_req_id) => {
                     state.borrow_mut().hovered_request = maybe_req_id
                 }
@@ -104,19 +210,140 @@ impl CollectionStore {
                 CollectionStoreAction::SetSelectedPane(pane) => {
                     state.borrow_mut().selected_pane = pane
                 }
-                CollectionStoreAction::SetPendingRequest(is_pending) => {
-                    state.borrow_mut().has_pending_request = is_pending;
+                CollectionStoreAction::SetPendingRequest(req_id, is_pending) => {
+                    let mut state = state.borrow_mut();
+                    match is_pending {
+                        true => _ = state.pending_requests.insert(req_id),
+                        false => _ = state.pending_requests.remove(&req_id),
+                    }
+                }
+                CollectionStoreAction::SetResponseFilter(req_id, filter) => match filter {
+                    Some(filter) => _ = state.borrow_mut().response_filters.insert(req_id, filter),
+                    None => _ = state.borrow_mut().response_filters.remove(&req_id),
+                },
+                CollectionStoreAction::SetRegister(name, register) => {
+                    state.borrow_mut().registers.insert(name, register);
+                }
+                CollectionStoreAction::MarkSyncing(hash) => {
+                    state.borrow_mut().syncing_hash = Some(hash)
+                }
+                CollectionStoreAction::MarkSynced(hash) => {
+                    let mut state = state.borrow_mut();
+                    state.synced_hash = hash;
+                    if state.syncing_hash.eq(&Some(hash)) {
+                        state.syncing_hash = None;
+                    }
+                }
+                CollectionStoreAction::MarkSyncFailed(hash) => {
+                    let mut state = state.borrow_mut();
+                    if state.syncing_hash.eq(&Some(hash)) {
+                        state.syncing_hash = None;
+                    }
+                }
+                CollectionStoreAction::ReloadCollection(collection) => {
+                    let mut state = state.borrow_mut();
+                    let tree = collection
+                        .requests
+                        .as_ref()
+                        .map(|requests| requests.read().unwrap().clone())
+                        .unwrap_or_default();
+
+                    let selected_id = state
+                        .selected_request
+                        .as_ref()
+                        .map(|request| request.read().unwrap().id.clone());
+                    state.selected_request = selected_id
+                        .and_then(|id| find_entry(&tree, &id))
+                        .and_then(|entry| match entry {
+                            RequestKind::Single(request) => Some(request),
+                            RequestKind::Nested(_) => None,
+                        });
+
+                    state.open_tabs = state
+                        .open_tabs
+                        .iter()
+                        .filter_map(|tab| {
+                            let id = tab.read().unwrap().id.clone();
+                            match find_entry(&tree, &id) {
+                                Some(RequestKind::Single(request)) => Some(request),
+                                _ => None,
+                            }
+                        })
+                        .collect();
+
+                    let hovered_id = state
+                        .hovered_request
+                        .take()
+                        .filter(|id| find_entry(&tree, id).is_some());
+                    state.hovered_request =
+                        hovered_id.or_else(|| tree.first().map(|item| item.get_id()));
+
+                    state.synced_hash = collection_hash(&collection);
+                    *state.collection.borrow_mut() = collection;
+                }
+                CollectionStoreAction::SetCutItem(item_id) => state.borrow_mut().cut_item = item_id,
+                CollectionStoreAction::MarkResponse(marked) => {
+                    state.borrow_mut().marked_response = marked
+                }
+                CollectionStoreAction::PasteItem => self.paste_item(),
+                CollectionStoreAction::MoveItemUp(item_id) => self.move_item(&item_id, -1),
+                CollectionStoreAction::MoveItemDown(item_id) => self.move_item(&item_id, 1),
+                CollectionStoreAction::DuplicateItem(item_id) => self.duplicate_item(&item_id),
+                CollectionStoreAction::RevealItem(item_id) => {
+                    if let Some(requests) = self.get_requests() {
+                        let state = state.borrow();
+                        let mut dirs = state.dirs_expanded.borrow_mut();
+                        for dir_id in tree::ancestors_of(&requests.read().unwrap(), &item_id) {
+                            dirs.insert(dir_id, true);
+                        }
+                    }
+                    self.dispatch(CollectionStoreAction::SetHoveredRequest(Some(item_id)));
                 }
+                CollectionStoreAction::SetSidebarFilter(filter) => self.set_sidebar_filter(filter),
             }
         }
     }
 
+    /// whether the collection changed since it was last synced to disk
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.state.as_ref().is_some_and(|state| {
+            let state = state.borrow();
+            let hash = collection_hash(&state.collection.borrow());
+            hash.ne(&state.synced_hash)
+        })
+    }
+
+    /// whether `collection` is exactly what we last synced to disk, or what we
+    /// are writing right now, which is how we tell our own writes apart from
+    /// changes made by someone else
+    pub fn is_synced_with(&self, collection: &Collection) -> bool {
+        self.state.as_ref().is_some_and(|state| {
+            let state = state.borrow();
+            let hash = collection_hash(collection);
+            state.synced_hash.eq(&hash) || state.syncing_hash.eq(&Some(hash))
+        })
+    }
+
+    /// whether the collection with the given hash is being written to disk
+    pub fn is_syncing(&self, hash: u64) -> bool {
+        self.state
+            .as_ref()
+            .is_some_and(|state| state.borrow().syncing_hash.eq(&Some(hash)))
+    }
+
     pub fn get_selected_request(&self) -> Option<Arc<RwLock<Request>>> {
         self.state
             .as_ref()
             .and_then(|state| state.borrow().selected_request.clone())
     }
 
+    pub fn get_open_tabs(&self) -> Vec<Arc<RwLock<Request>>> {
+        self.state
+            .as_ref()
+            .map(|state| state.borrow().open_tabs.clone())
+            .unwrap_or_default()
+    }
+
     pub fn get_focused_pane(&self) -> PaneFocus {
         self.state
             .as_ref()
@@ -137,6 +364,18 @@ impl CollectionStore {
             .and_then(|state| state.borrow().hovered_request.clone())
     }
 
+    /// the directory new items should go into, which is the hovered directory
+    /// itself, or the directory holding the hovered request
+    pub fn get_hovered_directory(&self) -> Option<String> {
+        let hovered = self.get_hovered_request()?;
+        let requests = self.get_requests()?;
+        let requests = requests.read().unwrap();
+        match find_entry(&requests, &hovered)? {
+            RequestKind::Nested(dir) => Some(dir.id),
+            RequestKind::Single(_) => tree::parent_of(&requests, &hovered),
+        }
+    }
+
     pub fn get_collection(&self) -> Option<Rc<RefCell<Collection>>> {
         self.state
             .as_ref()
@@ -193,13 +432,174 @@ impl CollectionStore {
         })
     }
 
-    pub fn has_pending_request(&self) -> bool {
+    pub fn has_pending_request(&self, req_id: &str) -> bool {
+        self.state
+            .as_ref()
+            .is_some_and(|state| state.borrow().pending_requests.contains(req_id))
+    }
+
+    pub fn get_response_filter(&self, req_id: &str) -> Option<String> {
+        self.state
+            .as_ref()
+            .and_then(|state| state.borrow().response_filters.get(req_id).cloned())
+    }
+
+    pub fn get_marked_response(&self) -> Option<MarkedResponse> {
         self.state
             .as_ref()
-            .is_some_and(|state| state.borrow().has_pending_request)
+            .and_then(|state| state.borrow().marked_response.clone())
+    }
+
+    pub fn get_cut_item(&self) -> Option<String> {
+        self.state
+            .as_ref()
+            .and_then(|state| state.borrow().cut_item.clone())
+    }
+
+    pub fn get_sidebar_filter(&self) -> Option<String> {
+        self.state
+            .as_ref()
+            .and_then(|state| state.borrow().sidebar_filter.clone())
+    }
+
+    /// ids of every item left visible by the sidebar filter, `None` when there
+    /// is nothing to filter by, and every item is visible
+    pub fn get_filtered_items(&self) -> Option<HashSet<String>> {
+        let filter = self
+            .get_sidebar_filter()
+            .filter(|filter| !filter.is_empty())?;
+        let requests = self.get_requests()?;
+        let requests = requests.read().unwrap();
+        Some(search::filter_tree(&requests, &filter))
+    }
+
+    /// every item visible on the sidebar, in the order they are drawn, which
+    /// leaves out children of collapsed directories, and anything filtered out
+    pub fn visible_items(&self) -> Vec<RequestKind> {
+        fn inner(
+            items: &[RequestKind],
+            dirs_expanded: &HashMap<String, bool>,
+            filtered: Option<&HashSet<String>>,
+        ) -> Vec<RequestKind> {
+            items
+                .iter()
+                .filter(|item| filtered.is_none_or(|visible| visible.contains(&item.get_id())))
+                .flat_map(|item| match item {
+                    RequestKind::Nested(dir)
+                        if dirs_expanded.get(&dir.id).copied().unwrap_or(false) =>
+                    {
+                        std::iter::once(item.clone())
+                            .chain(inner(
+                                &dir.requests.read().unwrap(),
+                                dirs_expanded,
+                                filtered,
+                            ))
+                            .collect::<Vec<_>>()
+                    }
+                    _ => vec![item.clone()],
+                })
+                .collect()
+        }
+
+        let (Some(requests), Some(state)) = (self.get_requests(), self.state.as_ref()) else {
+            return vec![];
+        };
+        let filtered = self.get_filtered_items();
+        let dirs_expanded = state.borrow().dirs_expanded.clone();
+        let items = inner(
+            &requests.read().unwrap(),
+            &dirs_expanded.borrow(),
+            filtered.as_ref(),
+        );
+        items
+    }
+
+    pub fn get_register(&self, name: char) -> Option<Register> {
+        self.state
+            .as_ref()
+            .and_then(|state| state.borrow().registers.get(&name).cloned())
+    }
+
+    /// moves the hover `offset` items away on what is visible on the sidebar,
+    /// which is how we skip over whatever the filter left out
+    fn hover_visible(&mut self, offset: isize) {
+        let items = self.visible_items();
+        let hovered = self.get_hovered_request();
+        let position = hovered.and_then(|id| items.iter().position(|item| item.get_id().eq(&id)));
+        let next = match position {
+            Some(position) => position
+                .saturating_add_signed(offset)
+                .min(items.len().saturating_sub(1)),
+            None => 0,
+        };
+        if let Some(item) = items.get(next) {
+            self.dispatch(CollectionStoreAction::SetHoveredRequest(Some(
+                item.get_id(),
+            )));
+        }
+    }
+
+    fn set_sidebar_filter(&mut self, filter: Option<String>) {
+        let Some(state) = self.state.clone() else {
+            return;
+        };
+        let dirs_expanded = state.borrow().dirs_expanded.clone();
+
+        let Some(filter) = filter else {
+            state.borrow_mut().sidebar_filter = None;
+            if let Some(expansion) = state.borrow_mut().expansion_before_filter.take() {
+                *dirs_expanded.borrow_mut() = expansion;
+            }
+
+            // the hovered item may be inside of a directory that was collapsed
+            // back, in which case we hover the outermost one of those
+            if let (Some(hovered), Some(requests)) =
+                (self.get_hovered_request(), self.get_requests())
+            {
+                let ancestors = tree::ancestors_of(&requests.read().unwrap(), &hovered);
+                let collapsed = ancestors
+                    .into_iter()
+                    .rev()
+                    .find(|id| !dirs_expanded.borrow().get(id).copied().unwrap_or(false));
+                if collapsed.is_some() {
+                    self.dispatch(CollectionStoreAction::SetHoveredRequest(collapsed));
+                }
+            }
+            return;
+        };
+
+        // every change to the filter starts from how the tree was before
+        // filtering, otherwise directories expanded by earlier filters would
+        // stay expanded
+        let mut expansion = state
+            .borrow_mut()
+            .expansion_before_filter
+            .get_or_insert_with(|| dirs_expanded.borrow().clone())
+            .clone();
+        state.borrow_mut().sidebar_filter = Some(filter);
+        if let (Some(visible), Some(requests)) = (self.get_filtered_items(), self.get_requests()) {
+            for (dir, _) in tree::directories(&requests.read().unwrap()) {
+                if visible.contains(&dir.id) {
+                    expansion.insert(dir.id, true);
+                }
+            }
+        }
+        *dirs_expanded.borrow_mut() = expansion;
+
+        let hovered = self.get_hovered_request();
+        let items = self.visible_items();
+        if !hovered.is_some_and(|id| items.iter().any(|item| item.get_id().eq(&id))) {
+            self.dispatch(CollectionStoreAction::SetHoveredRequest(
+                items.first().map(|item| item.get_id()),
+            ));
+        }
     }
 
     fn maybe_hover_prev(&mut self) {
+        if self.get_filtered_items().is_some() {
+            return self.hover_visible(-1);
+        }
+
         if self.get_requests().is_some() {
             let requests = self.get_requests().unwrap();
 
@@ -226,6 +626,10 @@ impl CollectionStore {
     }
 
     fn maybe_hover_next(&mut self) {
+        if self.get_filtered_items().is_some() {
+            return self.hover_visible(1);
+        }
+
         if self.get_requests().is_some() {
             let requests = self.get_requests().unwrap();
 
@@ -258,26 +662,143 @@ impl CollectionStore {
     }
 
     pub fn remove_item(&mut self, item_id: String) {
-        if let Some(request) = self.get_selected_request() {
-            if request.read().unwrap().id.eq(&item_id) {
-                self.dispatch(CollectionStoreAction::SetSelectedRequest(None));
-            }
-        }
         let mut requests = self.get_requests();
         let mut requests = requests.as_mut().unwrap().write().unwrap();
-        requests.retain(|req| req.get_id().ne(&item_id));
-        requests.iter_mut().for_each(|req| {
-            if let RequestKind::Nested(dir) = req {
-                dir.requests
-                    .write()
-                    .unwrap()
-                    .retain(|child| child.get_id().ne(&item_id));
-            }
-        });
+        tree::remove_entry(&mut requests, &item_id);
+
+        // removing a directory also removes whatever is inside of it, which may
+        // include the selected request, or any other open request
+        let removed_tabs = self
+            .get_open_tabs()
+            .into_iter()
+            .map(|tab| tab.read().unwrap().id.clone())
+            .filter(|id| find_entry(&requests, id).is_none())
+            .collect::<Vec<_>>();
+        removed_tabs.iter().for_each(|id| self.close_tab(id));
+
+        let selected_id = self
+            .get_selected_request()
+            .map(|request| request.read().unwrap().id.clone());
+        if selected_id.is_some_and(|id| find_entry(&requests, &id).is_none()) {
+            self.dispatch(CollectionStoreAction::SetSelectedRequest(None));
+        }
+
         self.dispatch(CollectionStoreAction::SetHoveredRequest(
             requests.first().map(|req| req.get_id()),
         ));
     }
+
+    fn close_tab(&mut self, request_id: &str) {
+        let Some(state) = self.state.as_ref() else {
+            return;
+        };
+        let mut state = state.borrow_mut();
+        let Some(idx) = state
+            .open_tabs
+            .iter()
+            .position(|tab| tab.read().unwrap().id.eq(request_id))
+        else {
+            return;
+        };
+        state.open_tabs.remove(idx);
+
+        let is_selected = state
+            .selected_request
+            .as_ref()
+            .is_some_and(|request| request.read().unwrap().id.eq(request_id));
+        if is_selected {
+            // the tab that takes the place of the closed one, or the one before
+            // it when it was the last
+            let neighbour = idx.min(state.open_tabs.len().saturating_sub(1));
+            state.selected_request = state.open_tabs.get(neighbour).cloned();
+        }
+    }
+
+    fn paste_item(&mut self) {
+        let (Some(item_id), Some(requests)) = (self.get_cut_item(), self.get_requests()) else {
+            return;
+        };
+        let hovered = self.get_hovered_request();
+        let mut requests = requests.write().unwrap();
+
+        // a directory can't go inside of itself, and pasting an item on top of
+        // itself would leave it where it is anyways
+        let target = hovered.as_ref().and_then(|id| find_entry(&requests, id));
+        if target.is_some_and(|target| tree::is_within(&requests, &item_id, &target.get_id())) {
+            tracing::debug!("refusing to paste {item_id} inside of itself");
+            return;
+        }
+
+        let Some(item) = tree::remove_entry(&mut requests, &item_id) else {
+            self.dispatch(CollectionStoreAction::SetCutItem(None));
+            return;
+        };
+
+        let inserted = match hovered.as_ref().and_then(|id| find_entry(&requests, id)) {
+            Some(RequestKind::Nested(dir)) => {
+                tree::set_parent(&item, Some(&dir.id));
+                self.get_dirs_expanded()
+                    .unwrap()
+                    .borrow_mut()
+                    .insert(dir.id.clone(), true);
+                tree::insert_entry(&mut requests, Some(&dir.id), item)
+            }
+            Some(RequestKind::Single(request)) => {
+                let request_id = request.read().unwrap().id.clone();
+                let parent = tree::parent_of(&requests, &request_id);
+                tree::set_parent(&item, parent.as_deref());
+                tree::insert_after(&mut requests, &request_id, item)
+            }
+            None => {
+                tree::set_parent(&item, None);
+                tree::insert_entry(&mut requests, None, item)
+            }
+        };
+
+        // the target was found right above, so this is never expected to fail,
+        // but an item is never thrown away because of it
+        if let Err(item) = inserted {
+            tree::set_parent(&item, None);
+            requests.push(item);
+        }
+
+        drop(requests);
+        self.dispatch(CollectionStoreAction::SetCutItem(None));
+        self.dispatch(CollectionStoreAction::SetHoveredRequest(Some(item_id)));
+    }
+
+    fn move_item(&mut self, item_id: &str, offset: isize) {
+        if let Some(requests) = self.get_requests() {
+            tree::move_sibling(&mut requests.write().unwrap(), item_id, offset);
+        }
+    }
+
+    fn duplicate_item(&mut self, item_id: &str) {
+        let Some(requests) = self.get_requests() else {
+            return;
+        };
+        let mut requests = requests.write().unwrap();
+        let Some(item) = find_entry(&requests, item_id) else {
+            return;
+        };
+
+        let parent = tree::parent_of(&requests, item_id);
+        let mut copy = tree::deep_copy(&item, parent.as_deref(), &mut || {
+            uuid::Uuid::new_v4().to_string()
+        });
+        match &mut copy {
+            RequestKind::Single(request) => request.write().unwrap().name.push_str(" (copy)"),
+            RequestKind::Nested(dir) => dir.name.push_str(" (copy)"),
+        }
+
+        let copy_id = copy.get_id();
+        if let Err(copy) = tree::insert_after(&mut requests, item_id, copy) {
+            requests.push(copy);
+        }
+
+        drop(requests);
+        self.dispatch(CollectionStoreAction::SetHoveredRequest(Some(copy_id)));
+    }
 }
 
 #[derive(PartialEq)]
@@ -327,8 +848,9 @@ fn traverse(
     path.push(current.clone());
 
     if let RequestKind::Nested(dir) = current {
-        // if we are on a collapsed directory we should not recurse into its children
-        if !dirs_expanded.get(&dir.id).unwrap() {
+        // if we are on a collapsed directory we should not recurse into its children,
+        // directories that were never drawn are not on the map yet, and are collapsed
+        if !dirs_expanded.get(&dir.id).copied().unwrap_or(false) {
             return false;
         }
 
@@ -386,6 +908,17 @@ fn find_next_entry(
     found.then(|| path.pop()).flatten()
 }
 
+/// hashes everything on a collection that is written to disk
+pub fn collection_hash(collection: &Collection) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    match serde_json::to_vec(collection) {
+        Ok(content) => content.hash(&mut hasher),
+        // if we can't serialize it, we can't save it either, so it is never dirty
+        Err(e) => tracing::error!("failed to serialize collection: {e:?}"),
+    }
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -403,6 +936,7 @@ mod tests {
             uri: "/root1".to_string(),
             body_type: None,
             body: None,
+            description: None,
         })))
     }
 
@@ -417,6 +951,7 @@ mod tests {
             headers: None,
             body_type: None,
             body: None,
+            description: None,
         })))
     }
 
@@ -431,6 +966,7 @@ mod tests {
             uri: "/nested1/child2".to_string(),
             body_type: None,
             body: None,
+            description: None,
         })))
     }
 
@@ -445,6 +981,7 @@ mod tests {
             uri: "/not/used".to_string(),
             body_type: None,
             body: None,
+            description: None,
         })))
     }
 
@@ -453,6 +990,7 @@ mod tests {
             id: "dir".to_string(),
             name: "Nested1".to_string(),
             requests: Arc::new(RwLock::new(vec![create_child_one(), create_child_two()])),
+            description: None,
         }
     }
 
@@ -471,6 +1009,7 @@ mod tests {
             uri: "/root2".to_string(),
             body_type: None,
             body: None,
+            description: None,
         })))
     }
 
@@ -478,6 +1017,376 @@ mod tests {
         vec![create_root_one(), create_nested(), create_root_two()]
     }
 
+    #[test]
+    fn test_unsaved_changes_are_tracked() {
+        let collection = Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(create_test_tree()))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        };
+        let mut store = CollectionStore::default();
+        store.set_state(collection);
+
+        assert!(!store.has_unsaved_changes());
+
+        // requests are edited in place, without going through the store
+        let request = store.get_selected_request().unwrap();
+        request.write().unwrap().uri = "/changed".to_string();
+        assert!(store.has_unsaved_changes());
+
+        // a collection is only synced once it was written
+        let hash = collection_hash(&store.get_collection().unwrap().borrow());
+        store.dispatch(CollectionStoreAction::MarkSyncing(hash));
+        assert!(store.has_unsaved_changes());
+        store.dispatch(CollectionStoreAction::MarkSyncFailed(hash));
+        assert!(store.has_unsaved_changes());
+        assert!(!store.is_syncing(hash));
+
+        store.dispatch(CollectionStoreAction::MarkSyncing(hash));
+        store.dispatch(CollectionStoreAction::MarkSynced(hash));
+        assert!(!store.has_unsaved_changes());
+        assert!(!store.is_syncing(hash));
+
+        store.dispatch(CollectionStoreAction::InsertRequest(create_not_used()));
+        assert!(store.has_unsaved_changes());
+    }
+
+    #[test]
+    fn test_pending_requests_are_tracked_per_request() {
+        let mut store = CollectionStore::default();
+        store.set_state(Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(create_test_tree()))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        });
+
+        store.dispatch(CollectionStoreAction::SetPendingRequest(
+            "root".into(),
+            true,
+        ));
+        store.dispatch(CollectionStoreAction::SetPendingRequest(
+            "child_two".into(),
+            true,
+        ));
+        store.dispatch(CollectionStoreAction::SetPendingRequest(
+            "root".into(),
+            false,
+        ));
+
+        assert!(!store.has_pending_request("root"));
+        assert!(store.has_pending_request("child_two"));
+    }
+
+    #[test]
+    fn test_reload_keeps_selection() {
+        let make_collection = |tree| Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(tree))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        };
+        let mut store = CollectionStore::default();
+        store.set_state(make_collection(create_test_tree()));
+        store.dispatch(CollectionStoreAction::ToggleDirectory("dir".into()));
+        store.dispatch(CollectionStoreAction::SetHoveredRequest(Some(
+            "child_two".into(),
+        )));
+        let RequestKind::Single(child) = create_child_two() else {
+            unreachable!()
+        };
+        store.dispatch(CollectionStoreAction::SetSelectedRequest(Some(child)));
+
+        let mut changed_tree = create_test_tree();
+        changed_tree.push(create_not_used());
+        let changed = make_collection(changed_tree);
+        assert!(!store.is_synced_with(&changed));
+        store.dispatch(CollectionStoreAction::ReloadCollection(changed));
+
+        let selected = store.get_selected_request().unwrap();
+        assert_eq!(selected.read().unwrap().id, "child_two");
+        assert_eq!(store.get_hovered_request(), Some("child_two".into()));
+        assert_eq!(
+            store.get_dirs_expanded().unwrap().borrow().get("dir"),
+            Some(&true)
+        );
+        assert_eq!(store.get_requests().unwrap().read().unwrap().len(), 4);
+        assert!(!store.has_unsaved_changes());
+
+        // items that don't exist anymore are not kept around
+        store.dispatch(CollectionStoreAction::ReloadCollection(make_collection(
+            vec![create_root_two()],
+        )));
+        assert!(store.get_selected_request().is_none());
+        assert_eq!(store.get_hovered_request(), Some("root_two".into()));
+    }
+
+    #[test]
+    fn test_hover_through_nested_directories() {
+        let action = create_child_one();
+        let resource = RequestKind::Nested(Directory {
+            id: "resource".to_string(),
+            name: "Resource".to_string(),
+            requests: Arc::new(RwLock::new(vec![action])),
+            description: None,
+        });
+        let service = RequestKind::Nested(Directory {
+            id: "service".to_string(),
+            name: "Service".to_string(),
+            requests: Arc::new(RwLock::new(vec![resource])),
+            description: None,
+        });
+        let mut store = CollectionStore::default();
+        store.set_state(Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(vec![service, create_root_two()]))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        });
+
+        // directories that were never expanded are not on the map at all
+        store.dispatch(CollectionStoreAction::ToggleDirectory("service".into()));
+        store.dispatch(CollectionStoreAction::HoverNext);
+        assert_eq!(store.get_hovered_request(), Some("resource".into()));
+        store.dispatch(CollectionStoreAction::HoverNext);
+        assert_eq!(store.get_hovered_request(), Some("root_two".into()));
+
+        store.dispatch(CollectionStoreAction::ToggleDirectory("resource".into()));
+        store.dispatch(CollectionStoreAction::HoverPrev);
+        assert_eq!(store.get_hovered_request(), Some("child_one".into()));
+        assert_eq!(store.get_hovered_directory(), Some("resource".into()));
+
+        // revealing an item expands every directory holding it
+        store.dispatch(CollectionStoreAction::ToggleDirectory("service".into()));
+        store.dispatch(CollectionStoreAction::ToggleDirectory("resource".into()));
+        store.dispatch(CollectionStoreAction::RevealItem("child_one".into()));
+        let dirs_expanded = store.get_dirs_expanded().unwrap();
+        assert_eq!(dirs_expanded.borrow().get("service"), Some(&true));
+        assert_eq!(dirs_expanded.borrow().get("resource"), Some(&true));
+        assert_eq!(store.get_hovered_request(), Some("child_one".into()));
+
+        // removing a directory removes everything inside of it
+        let RequestKind::Single(child) = store.find_hovered_request() else {
+            unreachable!()
+        };
+        store.dispatch(CollectionStoreAction::SetSelectedRequest(Some(child)));
+        store.remove_item("resource".into());
+        assert!(store.get_selected_request().is_none());
+    }
+
+    #[test]
+    fn test_moving_items_keeps_parents_consistent() {
+        let mut store = CollectionStore::default();
+        store.set_state(Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(create_test_tree()))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        });
+        let parent_of = |store: &CollectionStore, id: &str| {
+            let requests = store.get_requests().unwrap();
+            let requests = requests.read().unwrap();
+            let RequestKind::Single(request) = find_entry(&requests, id).unwrap() else {
+                unreachable!()
+            };
+            let parent = request.read().unwrap().parent.clone();
+            assert_eq!(parent, tree::parent_of(&requests, id));
+            parent
+        };
+
+        // cut a root request and paste it on a directory
+        store.dispatch(CollectionStoreAction::SetCutItem(Some("root".into())));
+        store.dispatch(CollectionStoreAction::SetHoveredRequest(Some("dir".into())));
+        store.dispatch(CollectionStoreAction::PasteItem);
+        assert_eq!(parent_of(&store, "root"), Some("dir".into()));
+        assert_eq!(store.get_cut_item(), None);
+        assert_eq!(store.get_hovered_request(), Some("root".into()));
+
+        // pasting on a request places the item right after it
+        store.dispatch(CollectionStoreAction::SetCutItem(Some("child_one".into())));
+        store.dispatch(CollectionStoreAction::SetHoveredRequest(Some(
+            "root_two".into(),
+        )));
+        store.dispatch(CollectionStoreAction::PasteItem);
+        assert_eq!(parent_of(&store, "child_one"), None);
+        let ids = |store: &CollectionStore| {
+            let requests = store.get_requests().unwrap();
+            let requests = requests.read().unwrap();
+            requests
+                .iter()
+                .map(|item| item.get_id())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&store), vec!["dir", "root_two", "child_one"]);
+
+        // a directory can't be pasted inside of itself
+        store.dispatch(CollectionStoreAction::SetCutItem(Some("dir".into())));
+        store.dispatch(CollectionStoreAction::SetHoveredRequest(Some(
+            "root".into(),
+        )));
+        store.dispatch(CollectionStoreAction::PasteItem);
+        assert_eq!(ids(&store), vec!["dir", "root_two", "child_one"]);
+        store.dispatch(CollectionStoreAction::SetCutItem(None));
+
+        store.dispatch(CollectionStoreAction::MoveItemUp("child_one".into()));
+        assert_eq!(ids(&store), vec!["dir", "child_one", "root_two"]);
+        store.dispatch(CollectionStoreAction::MoveItemDown("root_two".into()));
+        assert_eq!(ids(&store), vec!["dir", "child_one", "root_two"]);
+
+        store.dispatch(CollectionStoreAction::DuplicateItem("dir".into()));
+        let copy_id = store.get_hovered_request().unwrap();
+        assert_eq!(ids(&store)[1], copy_id);
+        let requests = store.get_requests().unwrap();
+        let RequestKind::Nested(copy) = find_entry(&requests.read().unwrap(), &copy_id).unwrap()
+        else {
+            unreachable!()
+        };
+        assert_eq!(copy.name, "Nested1 (copy)");
+        for child in copy.requests.read().unwrap().iter() {
+            assert_ne!(child.get_id(), "child_two");
+            assert_eq!(parent_of(&store, &child.get_id()), Some(copy_id.clone()));
+        }
+    }
+
+    #[test]
+    fn test_selecting_requests_opens_tabs() {
+        let mut store = CollectionStore::default();
+        store.set_state(Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(create_test_tree()))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        });
+        let open_ids = |store: &CollectionStore| {
+            store
+                .get_open_tabs()
+                .iter()
+                .map(|tab| tab.read().unwrap().id.clone())
+                .collect::<Vec<_>>()
+        };
+        let selected_id = |store: &CollectionStore| {
+            store
+                .get_selected_request()
+                .map(|req| req.read().unwrap().id.clone())
+        };
+        let select = |store: &mut CollectionStore, id: &str| {
+            let requests = store.get_requests().unwrap();
+            let Some(RequestKind::Single(request)) = find_entry(&requests.read().unwrap(), id)
+            else {
+                unreachable!()
+            };
+            store.dispatch(CollectionStoreAction::SetSelectedRequest(Some(request)));
+        };
+
+        select(&mut store, "root");
+        select(&mut store, "child_one");
+        select(&mut store, "root_two");
+        // selecting an open request doesn't open it twice
+        select(&mut store, "child_one");
+        assert_eq!(open_ids(&store), vec!["root", "child_one", "root_two"]);
+        assert_eq!(selected_id(&store), Some("child_one".into()));
+
+        // closing the selected tab selects the one taking its place
+        store.dispatch(CollectionStoreAction::CloseTab("child_one".into()));
+        assert_eq!(open_ids(&store), vec!["root", "root_two"]);
+        assert_eq!(selected_id(&store), Some("root_two".into()));
+
+        // closing a tab in the background keeps the selection
+        store.dispatch(CollectionStoreAction::CloseTab("root".into()));
+        assert_eq!(selected_id(&store), Some("root_two".into()));
+
+        // deleting a directory closes the tabs of everything inside of it
+        select(&mut store, "child_two");
+        store.remove_item("dir".into());
+        assert_eq!(open_ids(&store), vec!["root_two"]);
+        assert_eq!(selected_id(&store), Some("root_two".into()));
+
+        store.dispatch(CollectionStoreAction::CloseTab("root_two".into()));
+        assert!(open_ids(&store).is_empty());
+        assert_eq!(selected_id(&store), None);
+    }
+
+    #[test]
+    fn test_filtering_restores_expansion() {
+        let mut store = CollectionStore::default();
+        store.set_state(Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(create_test_tree()))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        });
+        let visible_ids = |store: &CollectionStore| {
+            let items = store.visible_items();
+            items.iter().map(|item| item.get_id()).collect::<Vec<_>>()
+        };
+        assert_eq!(visible_ids(&store), vec!["root", "dir", "root_two"]);
+
+        // an empty filter doesn't hide anything
+        store.dispatch(CollectionStoreAction::SetSidebarFilter(Some("".into())));
+        assert_eq!(visible_ids(&store), vec!["root", "dir", "root_two"]);
+
+        // matches on the method, and expands the directory holding the match
+        store.dispatch(CollectionStoreAction::SetSidebarFilter(Some("put".into())));
+        assert_eq!(visible_ids(&store), vec!["dir", "child_two"]);
+        assert_eq!(store.get_hovered_request(), Some("dir".into()));
+        store.dispatch(CollectionStoreAction::HoverNext);
+        assert_eq!(store.get_hovered_request(), Some("child_two".into()));
+        store.dispatch(CollectionStoreAction::HoverNext);
+        assert_eq!(store.get_hovered_request(), Some("child_two".into()));
+
+        // matches on the uri
+        store.dispatch(CollectionStoreAction::SetSidebarFilter(Some(
+            "/root".into(),
+        )));
+        assert_eq!(visible_ids(&store), vec!["root", "root_two"]);
+
+        // clearing restores the directories, and hovers what holds the hovered
+        // request when it is collapsed back
+        store.dispatch(CollectionStoreAction::SetSidebarFilter(Some(
+            "child2".into(),
+        )));
+        store.dispatch(CollectionStoreAction::SetSidebarFilter(None));
+        assert_eq!(visible_ids(&store), vec!["root", "dir", "root_two"]);
+        assert_eq!(store.get_hovered_request(), Some("dir".into()));
+        assert_eq!(store.get_dirs_expanded().unwrap().borrow().get("dir"), None);
+    }
+
     #[test]
     fn test_visit_next_no_expanded() {
         let tree = create_test_tree();
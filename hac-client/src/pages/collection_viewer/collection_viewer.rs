@@ -1,23 +1,38 @@
+use hac_config::UiAction;
 use hac_core::collection::types::*;
+use hac_core::collection::{har, layout, tree};
 use hac_core::command::Command;
+use hac_core::fs::watcher::CollectionWatcher;
 use hac_core::net::request_manager::Response;
 
-use crate::pages::collection_viewer::collection_store::{CollectionStore, CollectionStoreAction};
+use crate::pages::collection_viewer::collection_store::{
+    collection_hash, CollectionStore, CollectionStoreAction, MarkedResponse,
+};
+use crate::pages::collection_viewer::external_change_prompt::{
+    ExternalChangePrompt, ExternalChangePromptEvent,
+};
 use crate::pages::collection_viewer::request_editor::{RequestEditor, RequestEditorEvent};
+use crate::pages::collection_viewer::request_finder::{RequestFinder, RequestFinderEvent};
 use crate::pages::collection_viewer::request_uri::{RequestUri, RequestUriEvent};
+use crate::pages::collection_viewer::response_diff::{ResponseDiffEvent, ResponseDiffViewer};
 use crate::pages::collection_viewer::response_viewer::{ResponseViewer, ResponseViewerEvent};
 use crate::pages::collection_viewer::sidebar::{self, Sidebar, SidebarEvent};
 use crate::pages::{Eventful, Renderable};
+use crate::utils::{keycode_as_string, tab_at_column};
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::io::Write;
 use std::ops::{Add, Div};
+use std::path::Path;
 use std::rc::Rc;
+use std::sync::{Arc, RwLock};
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::Stylize;
-use ratatui::widgets::{Block, Clear};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Tabs};
 use ratatui::Frame;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 
@@ -25,6 +40,7 @@ use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 pub struct ExplorerLayout {
     pub hint_pane: Rect,
     pub sidebar: Rect,
+    pub tabs_pane: Rect,
     pub req_uri: Rect,
     pub req_editor: Rect,
     pub response_preview: Rect,
@@ -44,6 +60,9 @@ pub enum CollectionViewerOverlay {
     ChangeAuthMethod,
     HeadersForm(usize),
     DeleteSidebarItem(String),
+    ExternalChange,
+    RequestFinder,
+    ResponseDiff,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -74,6 +93,15 @@ impl PaneFocus {
     }
 }
 
+/// editor and response viewer of an open request that is not the selected one,
+/// kept around so switching back to it restores its cursor, scroll and response
+#[derive(Debug)]
+struct RequestTab<'cv> {
+    request: Arc<RwLock<Request>>,
+    request_editor: RequestEditor<'cv>,
+    response_viewer: ResponseViewer<'cv>,
+}
+
 #[derive(Debug)]
 pub struct CollectionViewer<'cv> {
     response_viewer: ResponseViewer<'cv>,
@@ -88,9 +116,31 @@ pub struct CollectionViewer<'cv> {
     collection_sync_timer: std::time::Instant,
     collection_store: Rc<RefCell<CollectionStore>>,
 
+    collection_watcher: CollectionWatcher,
+    collection_watch_timer: std::time::Instant,
+    external_change_prompt: ExternalChangePrompt<'cv>,
+    /// version of the collection found on disk while we had unsaved changes,
+    /// waiting for the user to decide which one to keep
+    pending_external_change: Option<Collection>,
+
+    request_finder: RequestFinder<'cv>,
+    response_diff: Option<ResponseDiffViewer<'cv>>,
+
+    /// request the editor and response viewer belong to. Right after the
+    /// selection changes, this is still the request we are leaving
+    active_request: Option<Arc<RwLock<Request>>>,
+    /// panes of every open tab other than the active one, by request id
+    tabs: HashMap<String, RequestTab<'cv>>,
+    /// tab that was active before the current one, which is what we compare
+    /// against when splitting
+    previous_tab: Option<String>,
+    is_split: bool,
+
     responses_map: HashMap<String, Rc<RefCell<Response>>>,
-    response_rx: UnboundedReceiver<Response>,
-    request_tx: UnboundedSender<Response>,
+    /// responses come along with the id of their request, the user may switch
+    /// tabs, and send other requests, before they arrive
+    response_rx: UnboundedReceiver<(String, Response)>,
+    request_tx: UnboundedSender<(String, Response)>,
 
     dry_run: bool,
 }
@@ -104,15 +154,16 @@ impl<'cv> CollectionViewer<'cv> {
         dry_run: bool,
     ) -> Self {
         let layout = build_layout(size);
-        let (request_tx, response_rx) = unbounded_channel::<Response>();
+        let (request_tx, response_rx) = unbounded_channel::<(String, Response)>();
 
-        let sidebar = sidebar::Sidebar::new(colors, collection_store.clone());
+        let sidebar = sidebar::Sidebar::new(colors, config, collection_store.clone());
 
         let request_editor =
             RequestEditor::new(colors, config, collection_store.clone(), layout.req_editor);
 
         let response_viewer = ResponseViewer::new(
             colors,
+            config,
             collection_store.clone(),
             None,
             layout.response_preview,
@@ -120,6 +171,14 @@ impl<'cv> CollectionViewer<'cv> {
 
         let request_uri = RequestUri::new(colors, collection_store.clone(), layout.req_uri);
 
+        let active_request = collection_store.borrow().get_selected_request();
+
+        let collection_path = collection_store
+            .borrow()
+            .get_collection()
+            .map(|collection| collection.borrow().path.clone())
+            .unwrap_or_default();
+
         CollectionViewer {
             request_editor,
             response_viewer,
@@ -130,6 +189,16 @@ impl<'cv> CollectionViewer<'cv> {
             config,
             global_command_sender: None,
             collection_sync_timer: std::time::Instant::now(),
+            collection_watcher: CollectionWatcher::new(collection_path),
+            collection_watch_timer: std::time::Instant::now(),
+            external_change_prompt: ExternalChangePrompt::new(colors),
+            pending_external_change: None,
+            request_finder: RequestFinder::new(colors, collection_store.clone()),
+            response_diff: None,
+            active_request,
+            tabs: HashMap::default(),
+            previous_tab: None,
+            is_split: false,
             responses_map: HashMap::default(),
             response_rx,
             request_tx,
@@ -139,46 +208,249 @@ impl<'cv> CollectionViewer<'cv> {
     }
 
     fn rebuild_everything(&mut self) {
-        self.sidebar = sidebar::Sidebar::new(self.colors, self.collection_store.clone());
-        self.request_editor = RequestEditor::new(
+        self.sidebar =
+            sidebar::Sidebar::new(self.colors, self.config, self.collection_store.clone());
+        self.request_uri = RequestUri::new(
+            self.colors,
+            self.collection_store.clone(),
+            self.layout.req_uri,
+        );
+        self.show_selected_request();
+    }
+
+    /// builds an editor and a response viewer for the selected request, showing
+    /// the last response we got for it, if any
+    fn build_request_panes(&self) -> (RequestEditor<'cv>, ResponseViewer<'cv>) {
+        let request_editor = RequestEditor::new(
             self.colors,
             self.config,
             self.collection_store.clone(),
             self.layout.req_editor,
         );
-        self.response_viewer = ResponseViewer::new(
+        let mut response_viewer = ResponseViewer::new(
             self.colors,
+            self.config,
             self.collection_store.clone(),
             None,
             self.layout.response_preview,
         );
-        self.request_uri = RequestUri::new(
-            self.colors,
-            self.collection_store.clone(),
-            self.layout.req_uri,
-        );
+        let response = self
+            .collection_store
+            .borrow()
+            .get_selected_request()
+            .and_then(|request| self.responses_map.get(&request.read().unwrap().id).cloned());
+        if response.is_some() {
+            response_viewer.update(response);
+        }
+        (request_editor, response_viewer)
+    }
+
+    /// swaps the editor and response viewer for the ones of the selected request,
+    /// keeping the ones we are leaving around while their tab is open
+    fn show_selected_request(&mut self) {
+        // tabs are also closed when their requests are deleted
+        let open_tabs = self.collection_store.borrow().get_open_tabs();
+        self.tabs
+            .retain(|id, _| open_tabs.iter().any(|tab| tab.read().unwrap().id.eq(id)));
+
+        let selected = self.collection_store.borrow().get_selected_request();
+        let selected_id = selected.as_ref().map(|req| req.read().unwrap().id.clone());
+        let active_id = self
+            .active_request
+            .as_ref()
+            .map(|req| req.read().unwrap().id.clone());
+        if selected_id.eq(&active_id) {
+            return;
+        }
+
+        // the editor body belongs to the request we are leaving
+        self.apply_editor_body();
+        let (request_editor, response_viewer) =
+            match selected_id.as_ref().and_then(|id| self.tabs.remove(id)) {
+                Some(tab) => (tab.request_editor, tab.response_viewer),
+                None => self.build_request_panes(),
+            };
+        let request_editor = std::mem::replace(&mut self.request_editor, request_editor);
+        let response_viewer = std::mem::replace(&mut self.response_viewer, response_viewer);
+
+        if let Some((request, id)) = self.active_request.take().zip(active_id) {
+            if self.is_tab_open(&id) {
+                self.tabs.insert(
+                    id.clone(),
+                    RequestTab {
+                        request,
+                        request_editor,
+                        response_viewer,
+                    },
+                );
+                self.previous_tab = Some(id);
+            }
+        }
+        self.active_request = selected;
+        self.resize_response_viewers();
+    }
+
+    fn is_tab_open(&self, id: &str) -> bool {
+        self.collection_store
+            .borrow()
+            .get_open_tabs()
+            .iter()
+            .any(|tab| tab.read().unwrap().id.eq(id))
+    }
+
+    /// tab whose response is shown next to the active one when splitting, which
+    /// is the previous tab, or any other open tab when that one was closed
+    fn compared_tab_id(&self) -> Option<String> {
+        if !self.is_split {
+            return None;
+        }
+        self.previous_tab
+            .as_ref()
+            .filter(|id| self.tabs.contains_key(*id))
+            .cloned()
+            .or_else(|| self.tabs.keys().min().cloned())
+    }
+
+    /// where the active response viewer is drawn, and where the compared one is,
+    /// when splitting
+    fn response_areas(&self) -> (Rect, Option<Rect>) {
+        if !self.is_split {
+            return (self.layout.response_preview, None);
+        }
+        let [left, right] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(self.layout.response_preview);
+        (left, Some(right))
+    }
+
+    fn resize_response_viewers(&mut self) {
+        let (active_area, compared_area) = self.response_areas();
+        self.response_viewer.resize(active_area);
+        if let Some((id, area)) = self.compared_tab_id().zip(compared_area) {
+            if let Some(tab) = self.tabs.get_mut(&id) {
+                tab.response_viewer.resize(area);
+            }
+        }
+    }
+
+    /// selects the open tab `offset` tabs away from the selected one, wrapping
+    /// around the ends
+    fn switch_tab(&mut self, offset: isize) {
+        let open_tabs = self.collection_store.borrow().get_open_tabs();
+        if open_tabs.len() < 2 {
+            return;
+        }
+        let selected_id = self
+            .collection_store
+            .borrow()
+            .get_selected_request()
+            .map(|req| req.read().unwrap().id.clone());
+        let idx = open_tabs
+            .iter()
+            .position(|tab| Some(&tab.read().unwrap().id).eq(&selected_id.as_ref()))
+            .unwrap_or_default();
+        let next = (idx as isize + offset).rem_euclid(open_tabs.len() as isize) as usize;
+        self.select_tab(open_tabs[next].clone());
+    }
+
+    fn select_tab(&mut self, request: Arc<RwLock<Request>>) {
+        let id = request.read().unwrap().id.clone();
+        let mut store = self.collection_store.borrow_mut();
+        store.dispatch(CollectionStoreAction::SetSelectedRequest(Some(request)));
+        store.dispatch(CollectionStoreAction::RevealItem(id));
+        drop(store);
+        self.rebuild_everything();
     }
 
-    // collect all pending responses from the channel. Here, I don't see a way we
-    // may have more than one response on this channel at any point, but it shouldn't matter
-    // if we have, so we can drain all the responses and update accordingly
+    fn close_selected_tab(&mut self) {
+        let Some(id) = self
+            .collection_store
+            .borrow()
+            .get_selected_request()
+            .map(|req| req.read().unwrap().id.clone())
+        else {
+            return;
+        };
+        // the closed tab may have unsaved changes on its editor
+        self.apply_editor_body();
+        self.collection_store
+            .borrow_mut()
+            .dispatch(CollectionStoreAction::CloseTab(id));
+        self.rebuild_everything();
+    }
+
+    fn toggle_split(&mut self) {
+        self.is_split = !self.is_split;
+        self.resize_response_viewers();
+    }
+
+    fn draw_tabs(&self, frame: &mut Frame) {
+        let open_tabs = self.collection_store.borrow().get_open_tabs();
+        let selected_id = self
+            .collection_store
+            .borrow()
+            .get_selected_request()
+            .map(|req| req.read().unwrap().id.clone());
+        let compared_id = self.compared_tab_id();
+
+        let titles = open_tabs
+            .iter()
+            .map(|tab| {
+                let tab = tab.read().unwrap();
+                let title = format!("{} {}", tab.method, tab.name);
+                match compared_id.as_ref().is_some_and(|id| id.eq(&tab.id)) {
+                    true => Line::from(title.fg(self.colors.normal.blue)),
+                    false => Line::from(title.fg(self.colors.bright.black)),
+                }
+            })
+            .collect::<Vec<_>>();
+        let selected = open_tabs
+            .iter()
+            .position(|tab| Some(&tab.read().unwrap().id).eq(&selected_id.as_ref()));
+
+        let mut tabs = Tabs::new(titles)
+            .highlight_style(Style::default().fg(self.colors.normal.red).bold())
+            .divider("|".fg(self.colors.bright.black));
+        if let Some(selected) = selected {
+            tabs = tabs.select(selected);
+        }
+        frame.render_widget(tabs, self.layout.tabs_pane);
+    }
+
+    fn draw_compare_placeholder(&self, frame: &mut Frame, size: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.colors.bright.black));
+        let hint = Paragraph::new(
+            "open another request to compare their responses".fg(self.colors.bright.black),
+        )
+        .centered()
+        .block(block);
+        frame.render_widget(hint, size);
+    }
+
+    // collect all pending responses from the channel, every open tab may have a
+    // request in flight, so each response is routed by the id of its request
     fn drain_responses_channel(&mut self) {
-        while let Ok(res) = self.response_rx.try_recv() {
+        while let Ok((id, res)) = self.response_rx.try_recv() {
             let res = Rc::new(RefCell::new(res));
-            self.collection_store
-                .borrow()
-                .get_selected_request()
+            self.responses_map.insert(id.clone(), Rc::clone(&res));
+            let active_id = self
+                .active_request
                 .as_ref()
-                .and_then(|req| {
-                    self.responses_map
-                        .insert(req.read().unwrap().id.to_string(), Rc::clone(&res))
-                });
-            self.response_viewer.update(Some(Rc::clone(&res)));
-            self.response_rx.is_empty().then(|| {
-                self.collection_store
-                    .borrow_mut()
-                    .dispatch(CollectionStoreAction::SetPendingRequest(false));
-            });
+                .map(|req| req.read().unwrap().id.clone());
+            // the response may belong to a tab in the background
+            match self.tabs.get_mut(&id) {
+                Some(tab) => tab.response_viewer.update(Some(Rc::clone(&res))),
+                None if active_id.is_some_and(|active| active.eq(&id)) => {
+                    self.response_viewer.update(Some(Rc::clone(&res)))
+                }
+                None => {}
+            }
+            self.collection_store
+                .borrow_mut()
+                .dispatch(CollectionStoreAction::SetPendingRequest(id, false));
         }
     }
 
@@ -197,63 +469,289 @@ impl<'cv> CollectionViewer<'cv> {
             .expect("tried to sync collection to disk without having a collection")
             .borrow()
             .clone();
+        self.apply_editor_body();
         if let Some(request) = self.collection_store.borrow().get_selected_request() {
             let request = request.clone();
-            let body = self.request_editor.body().to_string();
-            // this is not the best idea for when we start implementing other kinds of
-            // body types like GraphQL
-            if !body.is_empty() {
-                request.write().unwrap().body = Some(body);
-                request.write().unwrap().body_type = Some(BodyType::Json)
-            }
 
             // we might later on decide to keep track of the actual dir/request index
             // so we dont have to go over all the possible requests, this might be a
             // problem for huge collections, but I haven't tested
-            collection
+            let requests = collection
                 .requests
                 .as_mut()
-                .expect("no requests on collection, but we have a selected request")
-                .write()
-                .unwrap()
-                .iter_mut()
-                .for_each(|other| match other {
-                    RequestKind::Single(inner) => {
-                        if request.read().unwrap().id.eq(&inner.read().unwrap().id) {
-                            *inner = request.clone();
-                        }
-                    }
-                    RequestKind::Nested(dir) => {
-                        dir.requests.write().unwrap().iter_mut().for_each(|other| {
-                            if let RequestKind::Single(inner) = other {
-                                if request.read().unwrap().id.eq(&inner.read().unwrap().id) {
-                                    *inner = request.clone();
-                                }
-                            }
-                        })
-                    }
-                });
+                .expect("no requests on collection, but we have a selected request");
+            tree::replace_request(&mut requests.write().unwrap(), &request);
         }
 
         self.collection_sync_timer = std::time::Instant::now();
 
+        if !self.collection_store.borrow().has_unsaved_changes() {
+            return;
+        }
+
+        // the collection is only marked as synced once it was written, so a
+        // failed write is tried again on the next sync
+        let hash = collection_hash(&collection);
         if self.dry_run {
+            self.collection_store
+                .borrow_mut()
+                .dispatch(CollectionStoreAction::MarkSynced(hash));
             return;
         }
+        if self.collection_store.borrow().is_syncing(hash) {
+            return;
+        }
+        self.collection_store
+            .borrow_mut()
+            .dispatch(CollectionStoreAction::MarkSyncing(hash));
 
         tokio::spawn(async move {
-            match hac_core::fs::sync_collection(collection).await {
-                Ok(_) => {}
-                Err(e) => {
-                    if sender.send(Command::Error(e.to_string())).is_err() {
-                        tracing::error!("failed to send error command through channel");
-                        std::process::abort();
-                    }
-                }
+            let command = match hac_core::fs::sync_collection(collection).await {
+                Ok(_) => Command::CollectionSynced(hash),
+                Err(e) => Command::CollectionSyncFailed(hash, e.to_string()),
+            };
+            if sender.send(command).is_err() {
+                tracing::error!("failed to send sync command through channel");
+                std::process::abort();
             }
         });
     }
 
+    /// called once writing the collection with the given hash finished, `synced`
+    /// tells whether it was actually written
+    pub fn finish_sync(&mut self, hash: u64, synced: bool) {
+        let action = match synced {
+            true => CollectionStoreAction::MarkSynced(hash),
+            false => CollectionStoreAction::MarkSyncFailed(hash),
+        };
+        self.collection_store.borrow_mut().dispatch(action);
+    }
+
+    /// the body editor keeps its own content, so it has to be put back into its
+    /// request before we can save it, or check it for changes. Every open tab
+    /// has its own editor
+    fn apply_editor_body(&mut self) {
+        if let Some(request) = self.active_request.as_ref() {
+            apply_body(&self.request_editor, request);
+        }
+        for tab in self.tabs.values() {
+            apply_body(&tab.request_editor, &tab.request);
+        }
+    }
+
+    /// checks whether the collection changed on disk, loading it when we have
+    /// nothing to lose, or asking the user what to do otherwise
+    fn check_external_changes(&mut self) {
+        self.collection_watch_timer = std::time::Instant::now();
+        if !self.collection_watcher.poll() {
+            return;
+        }
+
+        let Some(path) = self
+            .collection_store
+            .borrow()
+            .get_collection()
+            .map(|collection| collection.borrow().path.clone())
+        else {
+            return;
+        };
+
+        // the collection may be in the middle of being written, in which case
+        // we will get another change once it is done
+        let collection = match hac_core::collection::collection::load_collection(&path) {
+            Ok(collection) => collection,
+            Err(e) => {
+                tracing::warn!("failed to load changed collection {path:?}: {e:?}");
+                return;
+            }
+        };
+
+        // our own writes also change the files on disk
+        if self.collection_store.borrow().is_synced_with(&collection) {
+            return;
+        }
+
+        self.apply_editor_body();
+        if self.collection_store.borrow().has_unsaved_changes() {
+            self.pending_external_change = Some(collection);
+            let mut store = self.collection_store.borrow_mut();
+            if store
+                .peek_overlay()
+                .ne(&CollectionViewerOverlay::ExternalChange)
+            {
+                store.push_overlay(CollectionViewerOverlay::ExternalChange);
+            }
+            return;
+        }
+
+        self.reload_collection(collection);
+    }
+
+    fn reload_collection(&mut self, collection: Collection) {
+        tracing::debug!(
+            "reloading collection changed on disk: {:?}",
+            collection.path
+        );
+        self.collection_store
+            .borrow_mut()
+            .dispatch(CollectionStoreAction::ReloadCollection(collection));
+
+        // every request was replaced, so the panes of the open tabs hold stale
+        // content and have to be built again
+        self.tabs.clear();
+        let (request_editor, response_viewer) = self.build_request_panes();
+        self.request_editor = request_editor;
+        self.response_viewer = response_viewer;
+        self.active_request = self.collection_store.borrow().get_selected_request();
+        self.rebuild_everything();
+        self.resize_response_viewers();
+    }
+
+    fn open_request_finder(&mut self) {
+        self.request_finder = RequestFinder::new(self.colors, self.collection_store.clone());
+        self.collection_store
+            .borrow_mut()
+            .push_overlay(CollectionViewerOverlay::RequestFinder);
+    }
+
+    fn handle_request_finder_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<()> {
+        let Some(event) = self.request_finder.handle_key_event(key_event)? else {
+            return Ok(());
+        };
+        self.collection_store.borrow_mut().pop_overlay();
+
+        if let RequestFinderEvent::Select(request) = event {
+            self.update_focus(PaneFocus::Sidebar);
+            self.select_tab(request);
+        }
+
+        Ok(())
+    }
+
+    /// the response on view, labeled with the request it belongs to, so it can
+    /// be told apart from other responses on a diff
+    fn response_on_view(&self) -> Option<MarkedResponse> {
+        let response = self.response_viewer.response()?;
+        let label = match self.active_request.as_ref() {
+            Some(request) => {
+                let request = request.read().unwrap();
+                format!("{} {}", request.method, request.name)
+            }
+            None => String::from("response"),
+        };
+        let status = response
+            .borrow()
+            .status
+            .map(|status| status.to_string())
+            .unwrap_or_else(|| String::from("no status"));
+        Some(MarkedResponse {
+            label: format!("{label} ({status})"),
+            response,
+        })
+    }
+
+    fn mark_response(&mut self) {
+        let marked = self.response_on_view();
+        self.collection_store
+            .borrow_mut()
+            .dispatch(CollectionStoreAction::MarkResponse(marked));
+    }
+
+    fn open_response_diff(&mut self) {
+        let Some(after) = self.response_on_view() else {
+            return;
+        };
+        let before = self.collection_store.borrow().get_marked_response();
+        self.response_diff = Some(ResponseDiffViewer::new(
+            self.colors,
+            self.config,
+            before,
+            after,
+        ));
+        self.collection_store
+            .borrow_mut()
+            .push_overlay(CollectionViewerOverlay::ResponseDiff);
+    }
+
+    /// writes the request on view, as it was sent, and its response to a HAR file
+    /// on the current directory, never overwriting an existing file
+    fn export_har(&mut self) {
+        let Some(response) = self.response_viewer.response() else {
+            return;
+        };
+        let response = response.borrow();
+        let Some(sent) = response.sent.as_ref() else {
+            return;
+        };
+
+        let slug = layout::slugify(&sent.request.name);
+        let har = har::export_har(&sent.request, &response, sent.started_at);
+        let exported = serde_json::to_string_pretty(&har)
+            .map_err(anyhow::Error::from)
+            .and_then(|har| {
+                create_unique_file(Path::new(""), &slug, "har", format!("{har}\n").as_bytes())
+            });
+
+        match exported {
+            Ok(file_name) => self.response_viewer.set_exported_to(file_name),
+            Err(e) => {
+                tracing::error!("failed to export {slug}.har: {e:?}");
+                if let Some(sender) = self.global_command_sender.as_ref() {
+                    sender
+                        .send(Command::Error(format!("failed to export {slug}.har: {e}")))
+                        .ok();
+                }
+            }
+        }
+    }
+
+    fn handle_response_diff_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<()> {
+        let Some(response_diff) = self.response_diff.as_mut() else {
+            return Ok(());
+        };
+        if let Some(ResponseDiffEvent::Close) = response_diff.handle_key_event(key_event)? {
+            self.response_diff = None;
+            self.collection_store.borrow_mut().pop_overlay();
+        }
+        Ok(())
+    }
+
+    fn handle_external_change_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<()> {
+        let event = self.external_change_prompt.handle_key_event(key_event)?;
+        if event.is_some() {
+            self.collection_store.borrow_mut().pop_overlay();
+        }
+
+        match (event, self.pending_external_change.take()) {
+            (Some(ExternalChangePromptEvent::Reload), Some(collection)) => {
+                self.reload_collection(collection)
+            }
+            // keeping our changes means they get synced on the next tick,
+            // overwriting what is on disk
+            (Some(ExternalChangePromptEvent::KeepLocal), _) => {}
+            (None, pending) => self.pending_external_change = pending,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// called when the external editor exits, loading whatever the user saved
+    /// back into the body editor
+    pub fn reload_body_from_file(&mut self, path: &Path) {
+        if let Err(e) = self.request_editor.reload_from_file(path) {
+            tracing::error!("failed to reload body from {path:?}: {e:?}");
+            if let Some(sender) = self.global_command_sender.as_ref() {
+                sender
+                    .send(Command::Error(format!(
+                        "failed to reload the body from {}: {e}",
+                        path.display()
+                    )))
+                    .ok();
+            }
+        }
+    }
+
     fn update_selection(&mut self, pane_to_select: Option<PaneFocus>) {
         self.collection_store
             .borrow_mut()
@@ -278,8 +776,16 @@ impl Renderable for CollectionViewer<'_> {
         self.drain_responses_channel();
 
         self.sidebar.draw(frame, self.layout.sidebar)?;
-        self.response_viewer
-            .draw(frame, self.layout.response_preview)?;
+        self.draw_tabs(frame);
+
+        let (active_area, compared_area) = self.response_areas();
+        self.response_viewer.draw(frame, active_area)?;
+        if let Some(area) = compared_area {
+            match self.compared_tab_id().and_then(|id| self.tabs.get_mut(&id)) {
+                Some(tab) => tab.response_viewer.draw(frame, area)?,
+                None => self.draw_compare_placeholder(frame, area),
+            }
+        }
         self.request_editor.draw(frame, self.layout.req_editor)?;
         self.request_uri.draw(frame, self.layout.req_uri)?;
 
@@ -315,6 +821,17 @@ impl Renderable for CollectionViewer<'_> {
             CollectionViewerOverlay::ChangeAuthMethod => {
                 self.request_editor.draw_overlay(frame, overlay)?;
             }
+            CollectionViewerOverlay::ExternalChange => {
+                self.external_change_prompt.draw(frame, size)?;
+            }
+            CollectionViewerOverlay::RequestFinder => {
+                self.request_finder.draw(frame, size)?;
+            }
+            CollectionViewerOverlay::ResponseDiff => {
+                if let Some(response_diff) = self.response_diff.as_mut() {
+                    response_diff.draw(frame, size)?;
+                }
+            }
             CollectionViewerOverlay::None => {}
         }
 
@@ -356,6 +873,14 @@ impl Renderable for CollectionViewer<'_> {
     }
 
     fn handle_tick(&mut self) -> anyhow::Result<()> {
+        if self.collection_watch_timer.elapsed().as_secs().ge(&1) {
+            self.check_external_changes();
+        }
+        // while the user decides what to do about external changes, syncing
+        // would overwrite them
+        if self.pending_external_change.is_some() {
+            return Ok(());
+        }
         if self.collection_sync_timer.elapsed().as_secs().ge(&5) {
             self.sync_collection_changes();
         }
@@ -370,8 +895,12 @@ impl Renderable for CollectionViewer<'_> {
     fn resize(&mut self, new_size: Rect) {
         let new_layout = build_layout(new_size);
         self.request_editor.resize(new_layout.req_editor);
-        self.response_viewer.resize(new_layout.response_preview);
+        for tab in self.tabs.values_mut() {
+            tab.request_editor.resize(new_layout.req_editor);
+            tab.response_viewer.resize(new_layout.response_preview);
+        }
         self.layout = new_layout;
+        self.resize_response_viewers();
     }
 }
 
@@ -379,6 +908,36 @@ impl Eventful for CollectionViewer<'_> {
     type Result = Command;
 
     fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Command>> {
+        if self
+            .collection_store
+            .borrow()
+            .peek_overlay()
+            .eq(&CollectionViewerOverlay::ExternalChange)
+        {
+            self.handle_external_change_key_event(key_event)?;
+            return Ok(None);
+        }
+
+        if self
+            .collection_store
+            .borrow()
+            .peek_overlay()
+            .eq(&CollectionViewerOverlay::RequestFinder)
+        {
+            self.handle_request_finder_key_event(key_event)?;
+            return Ok(None);
+        }
+
+        if self
+            .collection_store
+            .borrow()
+            .peek_overlay()
+            .eq(&CollectionViewerOverlay::ResponseDiff)
+        {
+            self.handle_response_diff_key_event(key_event)?;
+            return Ok(None);
+        }
+
         if let (
             None,
             KeyEvent {
@@ -394,35 +953,41 @@ impl Eventful for CollectionViewer<'_> {
         }
 
         if self.collection_store.borrow().get_selected_pane().is_none() {
-            match key_event.code {
-                KeyCode::Char('r') => {
+            let key_str = keycode_as_string(key_event);
+            match self.config.keymaps.panes.get(&key_str) {
+                Some(UiAction::FocusSidebar) => {
                     self.update_focus(PaneFocus::Sidebar);
                     self.update_selection(Some(PaneFocus::Sidebar));
                 }
-                KeyCode::Char('u') => {
+                Some(UiAction::FocusUri) => {
                     self.update_focus(PaneFocus::ReqUri);
                     self.update_selection(Some(PaneFocus::ReqUri));
                 }
-                KeyCode::Char('p') => {
+                Some(UiAction::FocusPreview) => {
                     self.update_focus(PaneFocus::Preview);
                     self.update_selection(Some(PaneFocus::Preview));
                 }
-                KeyCode::Char('e') => {
+                Some(UiAction::FocusEditor) => {
                     self.update_focus(PaneFocus::Editor);
                     self.update_selection(Some(PaneFocus::Editor));
                 }
-                KeyCode::Tab => {
+                Some(UiAction::FocusNext) => {
                     let next_pane = self.collection_store.borrow().get_focused_pane().next();
                     self.update_focus(next_pane);
                 }
-                KeyCode::BackTab => {
+                Some(UiAction::FocusPrev) => {
                     let prev_pane = self.collection_store.borrow().get_focused_pane().prev();
                     self.update_focus(prev_pane);
                 }
-                KeyCode::Enter => {
+                Some(UiAction::Select) => {
                     let curr_pane = self.collection_store.borrow().get_focused_pane();
                     self.update_selection(Some(curr_pane));
                 }
+                Some(UiAction::FindRequest) => self.open_request_finder(),
+                Some(UiAction::NextTab) => self.switch_tab(1),
+                Some(UiAction::PrevTab) => self.switch_tab(-1),
+                Some(UiAction::CloseTab) => self.close_selected_tab(),
+                Some(UiAction::ToggleSplit) => self.toggle_split(),
                 _ => {}
             }
             return Ok(None);
@@ -452,6 +1017,7 @@ impl Eventful for CollectionViewer<'_> {
                         .collection_store
                         .borrow_mut()
                         .push_overlay(CollectionViewerOverlay::DeleteSidebarItem(item_id)),
+                    Some(SidebarEvent::FindRequest) => self.open_request_finder(),
                     Some(SidebarEvent::RemoveSelection) => self.update_selection(None),
                     Some(SidebarEvent::SyncCollection) => self.sync_collection_changes(),
                     Some(SidebarEvent::Quit) => return Ok(Some(Command::Quit)),
@@ -461,14 +1027,24 @@ impl Eventful for CollectionViewer<'_> {
                 },
                 PaneFocus::ReqUri => match self.request_uri.handle_key_event(key_event)? {
                     Some(RequestUriEvent::Quit) => return Ok(Some(Command::Quit)),
-                    Some(RequestUriEvent::SendRequest) => hac_core::net::handle_request(
-                        self.collection_store
+                    Some(RequestUriEvent::SendRequest) => {
+                        let request = self
+                            .collection_store
                             .borrow()
                             .get_selected_request()
-                            .as_ref()
-                            .unwrap(),
-                        self.request_tx.clone(),
-                    ),
+                            .expect("sent a request without having a selected request");
+                        let environment = self
+                            .collection_store
+                            .borrow()
+                            .get_collection()
+                            .map(|collection| collection.borrow().environment.clone())
+                            .unwrap_or_default();
+                        hac_core::net::handle_request(
+                            &request,
+                            &environment,
+                            self.request_tx.clone(),
+                        )
+                    }
                     Some(RequestUriEvent::RemoveSelection) => self.update_selection(None),
                     // when theres no event we do nothing
                     None => {}
@@ -476,12 +1052,21 @@ impl Eventful for CollectionViewer<'_> {
                 PaneFocus::Preview => match self.response_viewer.handle_key_event(key_event)? {
                     Some(ResponseViewerEvent::RemoveSelection) => self.update_selection(None),
                     Some(ResponseViewerEvent::Quit) => return Ok(Some(Command::Quit)),
+                    Some(ResponseViewerEvent::MarkResponse) => self.mark_response(),
+                    Some(ResponseViewerEvent::DiffResponses) => self.open_response_diff(),
+                    Some(ResponseViewerEvent::ExportHar) => self.export_har(),
                     // when theres no event we do nothing
                     None => {}
                 },
                 PaneFocus::Editor => match self.request_editor.handle_key_event(key_event)? {
                     Some(RequestEditorEvent::RemoveSelection) => self.update_selection(None),
                     Some(RequestEditorEvent::Quit) => return Ok(Some(Command::Quit)),
+                    Some(RequestEditorEvent::EditExternally(path)) => {
+                        return Ok(Some(Command::OpenExternalEditor(path)))
+                    }
+                    Some(RequestEditorEvent::CopyToClipboard(content)) => {
+                        return Ok(Some(Command::CopyToClipboard(content)))
+                    }
                     // when theres no event we do nothing
                     None => {}
                 },
@@ -490,6 +1075,111 @@ impl Eventful for CollectionViewer<'_> {
 
         Ok(None)
     }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> anyhow::Result<Option<Command>> {
+        // overlays are drawn on top of the panes, so any click would land on
+        // whatever is behind them
+        if self.collection_store.borrow().has_overlay() {
+            return Ok(None);
+        }
+
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        if self.layout.tabs_pane.contains(position) {
+            if let MouseEventKind::Down(MouseButton::Left) = mouse_event.kind {
+                let open_tabs = self.collection_store.borrow().get_open_tabs();
+                let titles = open_tabs
+                    .iter()
+                    .map(|tab| {
+                        let tab = tab.read().unwrap();
+                        format!("{} {}", tab.method, tab.name)
+                    })
+                    .collect::<Vec<_>>();
+                let titles = titles.iter().map(String::as_str).collect::<Vec<_>>();
+                if let Some(idx) = tab_at_column(&titles, self.layout.tabs_pane, mouse_event.column)
+                {
+                    self.select_tab(open_tabs[idx].clone());
+                }
+            }
+            return Ok(None);
+        }
+
+        // the compared response is only there to be looked at
+        let (active_area, _) = self.response_areas();
+        let Some(pane) = [
+            (self.layout.sidebar, PaneFocus::Sidebar),
+            (self.layout.req_uri, PaneFocus::ReqUri),
+            (self.layout.req_editor, PaneFocus::Editor),
+            (active_area, PaneFocus::Preview),
+        ]
+        .into_iter()
+        .find_map(|(area, pane)| area.contains(position).then_some(pane)) else {
+            return Ok(None);
+        };
+
+        if let MouseEventKind::Down(MouseButton::Left) = mouse_event.kind {
+            self.update_focus(pane);
+            self.update_selection(Some(pane));
+        }
+
+        match pane {
+            PaneFocus::Sidebar => {
+                if let Some(SidebarEvent::RebuildView) =
+                    self.sidebar.handle_mouse_event(mouse_event)?
+                {
+                    self.rebuild_everything();
+                }
+            }
+            PaneFocus::Editor => {
+                self.request_editor.handle_mouse_event(mouse_event)?;
+            }
+            PaneFocus::Preview => {
+                self.response_viewer.handle_mouse_event(mouse_event)?;
+            }
+            PaneFocus::ReqUri => {}
+        }
+
+        Ok(None)
+    }
+}
+
+fn apply_body(request_editor: &RequestEditor, request: &Arc<RwLock<Request>>) {
+    let body = request_editor.body().to_string();
+    // this is not the best idea for when we start implementing other kinds of
+    // body types like GraphQL
+    if !body.is_empty() {
+        request.write().unwrap().body = Some(body);
+        request.write().unwrap().body_type = Some(BodyType::Json)
+    }
+}
+
+/// writes `content` to `<stem>.<extension>` inside of `dir`, or to
+/// `<stem>-2.<extension>` and so on when the name is taken, returning the path
+/// of the file it was written to
+fn create_unique_file(
+    dir: &Path,
+    stem: &str,
+    extension: &str,
+    content: &[u8],
+) -> anyhow::Result<String> {
+    let mut suffix = 1;
+    loop {
+        let file_name = match suffix {
+            1 => dir.join(format!("{stem}.{extension}")),
+            _ => dir.join(format!("{stem}-{suffix}.{extension}")),
+        };
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&file_name)
+        {
+            Ok(mut file) => {
+                file.write_all(content)?;
+                return Ok(file_name.to_string_lossy().to_string());
+            }
+            Err(e) if e.kind().eq(&std::io::ErrorKind::AlreadyExists) => suffix += 1,
+            Err(e) => return Err(e.into()),
+        }
+    }
 }
 
 pub fn build_layout(size: Rect) -> ExplorerLayout {
@@ -503,9 +1193,13 @@ pub fn build_layout(size: Rect) -> ExplorerLayout {
         .constraints([Constraint::Length(30), Constraint::Fill(1)])
         .areas(top_pane);
 
-    let [req_uri, req_builder] = Layout::default()
+    let [tabs_pane, req_uri, req_builder] = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3), Constraint::Fill(1)])
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Fill(1),
+        ])
         .areas(right_pane);
 
     let [req_editor, response_preview] = if size.width < 120 {
@@ -530,9 +1224,29 @@ pub fn build_layout(size: Rect) -> ExplorerLayout {
     ExplorerLayout {
         hint_pane,
         sidebar,
+        tabs_pane,
         req_uri,
         req_editor,
         response_preview,
         create_req_form,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_existing_files_are_not_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("login.har"), "mine").unwrap();
+
+        let first = create_unique_file(dir.path(), "login", "har", b"first").unwrap();
+        let second = create_unique_file(dir.path(), "login", "har", b"second").unwrap();
+
+        assert_eq!(Path::new(&first), dir.path().join("login-2.har"));
+        assert_eq!(Path::new(&second), dir.path().join("login-3.har"));
+        let original = std::fs::read_to_string(dir.path().join("login.har")).unwrap();
+        assert_eq!(original, "mine");
+    }
+}
@@ -0,0 +1,98 @@
+use crate::ascii::LOGO_ASCII;
+use crate::pages::overlay::make_overlay;
+use crate::pages::{Eventful, Renderable};
+
+use std::ops::{Add, Div, Sub};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use rand::Rng;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalChangePromptEvent {
+    /// discard local changes and load what is on disk
+    Reload,
+    /// keep local changes, which overwrite what is on disk on the next sync
+    KeepLocal,
+}
+
+/// shown when the collection changes on disk while we have changes that were
+/// not synced yet, as loading it would throw away whatever the user did
+#[derive(Debug)]
+pub struct ExternalChangePrompt<'ecp> {
+    colors: &'ecp hac_colors::Colors,
+    logo_idx: usize,
+}
+
+impl<'ecp> ExternalChangePrompt<'ecp> {
+    pub fn new(colors: &'ecp hac_colors::Colors) -> Self {
+        let logo_idx = rand::thread_rng().gen_range(0..LOGO_ASCII.len());
+        ExternalChangePrompt { colors, logo_idx }
+    }
+}
+
+impl Renderable for ExternalChangePrompt<'_> {
+    fn draw(&mut self, frame: &mut Frame, _: Rect) -> anyhow::Result<()> {
+        make_overlay(self.colors, self.colors.normal.black, 0.1, frame);
+
+        let lines = vec![
+            Line::from("The collection was changed outside of hac".fg(self.colors.normal.yellow))
+                .centered(),
+            Line::from(
+                "but you have changes that were not saved yet".fg(self.colors.normal.yellow),
+            )
+            .centered(),
+            Line::from(""),
+            Line::from("[Reload: Enter] [Keep mine: Esc]".fg(self.colors.bright.black)).centered(),
+        ];
+
+        let logo = LOGO_ASCII[self.logo_idx];
+        let logo_size = logo.len() as u16;
+        let size = frame.size();
+
+        let size = Rect::new(
+            size.width.div(2).sub(25),
+            size.height
+                .div(2)
+                .saturating_sub(logo_size.div(2))
+                .saturating_sub(3),
+            50,
+            logo_size.add(7),
+        );
+
+        let logo_size = Rect::new(size.x, size.y, size.width, logo_size);
+        let logo = logo
+            .iter()
+            .map(|line| Line::from(line.fg(self.colors.normal.yellow)).centered())
+            .collect::<Vec<_>>();
+        frame.render_widget(Paragraph::new(logo), logo_size);
+
+        for (idx, line) in lines.into_iter().enumerate() {
+            let y_offset = logo_size.height.add(2).add(idx as u16);
+            let line_size = Rect::new(size.x, size.y.add(y_offset), size.width, 1);
+            frame.render_widget(Paragraph::new(line), line_size);
+        }
+
+        Ok(())
+    }
+}
+
+impl Eventful for ExternalChangePrompt<'_> {
+    type Result = ExternalChangePromptEvent;
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Self::Result>> {
+        if let (KeyCode::Char('c'), KeyModifiers::CONTROL) = (key_event.code, key_event.modifiers) {
+            return Ok(Some(ExternalChangePromptEvent::KeepLocal));
+        }
+
+        match key_event.code {
+            KeyCode::Enter => Ok(Some(ExternalChangePromptEvent::Reload)),
+            KeyCode::Esc => Ok(Some(ExternalChangePromptEvent::KeepLocal)),
+            _ => Ok(None),
+        }
+    }
+}
@@ -1,9 +1,13 @@
 pub mod collection_store;
 #[allow(clippy::module_inception)]
 pub mod collection_viewer;
+mod external_change_prompt;
 mod request_editor;
+mod request_finder;
 mod request_uri;
+mod response_diff;
 mod response_viewer;
 mod sidebar;
+mod text_search;
 
 pub use collection_viewer::CollectionViewer;
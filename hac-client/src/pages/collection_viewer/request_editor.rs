@@ -1,9 +1,11 @@
 mod auth_editor;
 mod body_editor;
+mod description_editor;
 mod headers_editor;
 
 use auth_editor::{AuthEditor, AuthEditorEvent};
 use body_editor::{BodyEditor, BodyEditorEvent};
+use description_editor::{DescriptionEditor, DescriptionEditorEvent};
 use hac_config::EditorMode;
 use hac_core::collection::types::{Request, RequestMethod};
 use hac_core::text_object::{TextObject, Write};
@@ -14,15 +16,17 @@ use crate::pages::collection_viewer::collection_viewer::{CollectionViewerOverlay
 use crate::pages::under_construction::UnderConstruction;
 use crate::pages::Eventful;
 use crate::pages::Renderable;
+use crate::utils::tab_at_column;
 
 use std::cell::RefCell;
 use std::fmt::Display;
 use std::ops::Add;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
-use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
 use ratatui::style::{Style, Stylize};
 use ratatui::widgets::{Block, Borders, Tabs};
 use ratatui::Frame;
@@ -36,8 +40,15 @@ pub enum RequestEditorEvent {
     /// user pressed `Esc` so we bubble a remove selection event for the
     /// parent to handle
     RemoveSelection,
+    /// user asked to edit the body or the description on their external
+    /// editor, it was written to the given file
+    EditExternally(PathBuf),
+    /// user yanked some text, which should be sent to the system clipboard
+    CopyToClipboard(String),
 }
 
+const TAB_TITLES: [&str; 5] = ["Body", "Headers", "Query", "Auth", "Description"];
+
 #[derive(Debug, Default, Clone, PartialEq)]
 pub enum ReqEditorTabs {
     #[default]
@@ -45,15 +56,17 @@ pub enum ReqEditorTabs {
     Headers,
     Query,
     Auth,
+    Description,
 }
 
 impl ReqEditorTabs {
     pub fn prev(&self) -> Self {
         match self {
-            ReqEditorTabs::Body => ReqEditorTabs::Auth,
+            ReqEditorTabs::Body => ReqEditorTabs::Description,
             ReqEditorTabs::Headers => ReqEditorTabs::Body,
             ReqEditorTabs::Query => ReqEditorTabs::Headers,
             ReqEditorTabs::Auth => ReqEditorTabs::Query,
+            ReqEditorTabs::Description => ReqEditorTabs::Auth,
         }
     }
 
@@ -62,7 +75,19 @@ impl ReqEditorTabs {
             ReqEditorTabs::Body => ReqEditorTabs::Headers,
             ReqEditorTabs::Headers => ReqEditorTabs::Query,
             ReqEditorTabs::Query => ReqEditorTabs::Auth,
-            ReqEditorTabs::Auth => ReqEditorTabs::Body,
+            ReqEditorTabs::Auth => ReqEditorTabs::Description,
+            ReqEditorTabs::Description => ReqEditorTabs::Body,
+        }
+    }
+
+    fn from_index(idx: usize) -> Option<Self> {
+        match idx {
+            0 => Some(ReqEditorTabs::Body),
+            1 => Some(ReqEditorTabs::Headers),
+            2 => Some(ReqEditorTabs::Query),
+            3 => Some(ReqEditorTabs::Auth),
+            4 => Some(ReqEditorTabs::Description),
+            _ => None,
         }
     }
 }
@@ -80,6 +105,7 @@ impl Display for ReqEditorTabs {
             ReqEditorTabs::Headers => f.write_str("Headers"),
             ReqEditorTabs::Query => f.write_str("Query"),
             ReqEditorTabs::Auth => f.write_str("Auth"),
+            ReqEditorTabs::Description => f.write_str("Description"),
         }
     }
 }
@@ -97,6 +123,7 @@ pub struct RequestEditor<'re> {
     body_editor: BodyEditor<'re>,
     headers_editor: HeadersEditor<'re>,
     auth_editor: AuthEditor<'re>,
+    description_editor: DescriptionEditor<'re>,
     layout: ReqEditorLayout,
     curr_tab: ReqEditorTabs,
 }
@@ -108,14 +135,17 @@ impl<'re> RequestEditor<'re> {
         collection_store: Rc<RefCell<CollectionStore>>,
         size: Rect,
     ) -> Self {
-        let curr_tab = collection_store
+        let has_no_body = collection_store
             .borrow()
             .get_selected_request()
             .as_ref()
             .map(request_has_no_body)
-            .unwrap_or(false)
-            .then_some(ReqEditorTabs::Headers)
-            .unwrap_or_default();
+            .unwrap_or(false);
+        let curr_tab = if has_no_body {
+            ReqEditorTabs::Headers
+        } else {
+            ReqEditorTabs::default()
+        };
 
         let layout = build_layout(size);
 
@@ -129,10 +159,12 @@ impl<'re> RequestEditor<'re> {
             ),
             headers_editor: HeadersEditor::new(
                 colors,
+                config,
                 collection_store.clone(),
                 layout.content_pane,
             ),
             auth_editor: AuthEditor::new(colors, collection_store.clone()),
+            description_editor: DescriptionEditor::new(colors, collection_store.clone()),
             layout,
             curr_tab,
             collection_store,
@@ -149,6 +181,15 @@ impl<'re> RequestEditor<'re> {
         self.body_editor.body()
     }
 
+    /// loads back whatever was edited externally on the current tab, which is
+    /// either the body or the description
+    pub fn reload_from_file(&mut self, path: &Path) -> anyhow::Result<()> {
+        match self.curr_tab {
+            ReqEditorTabs::Description => self.description_editor.reload_from_file(path),
+            _ => self.body_editor.reload_from_file(path),
+        }
+    }
+
     pub fn resize(&mut self, new_size: Rect) {
         self.layout = build_layout(new_size);
         self.headers_editor.resize(self.layout.content_pane);
@@ -161,18 +202,20 @@ impl<'re> RequestEditor<'re> {
             ReqEditorTabs::Headers => self.headers_editor.draw(frame, size)?,
             ReqEditorTabs::Query => UnderConstruction::new(self.colors).draw(frame, size)?,
             ReqEditorTabs::Auth => self.auth_editor.draw(frame, size)?,
+            ReqEditorTabs::Description => self.description_editor.draw(frame, size)?,
         }
 
         Ok(())
     }
 
     fn draw_tabs(&self, frame: &mut Frame, size: Rect) {
-        let tabs = vec!["Body", "Headers", "Query", "Auth"];
+        let tabs = TAB_TITLES.to_vec();
         let active = match self.curr_tab {
             ReqEditorTabs::Body => 0,
             ReqEditorTabs::Headers => 1,
             ReqEditorTabs::Query => 2,
             ReqEditorTabs::Auth => 3,
+            ReqEditorTabs::Description => 4,
         };
 
         frame.render_widget(
@@ -222,6 +265,7 @@ impl<'re> RequestEditor<'re> {
             ReqEditorTabs::Headers => self.headers_editor.draw_overlay(frame, overlay),
             ReqEditorTabs::Query => todo!(),
             ReqEditorTabs::Auth => self.auth_editor.draw_overlay(frame, overlay),
+            ReqEditorTabs::Description => Ok(()),
         }
     }
 }
@@ -251,7 +295,8 @@ impl Eventful for RequestEditor<'_> {
         if let KeyCode::Tab = key_event.code {
             let store = self.collection_store.borrow_mut();
             if self.curr_tab.eq(&ReqEditorTabs::Body)
-                && self.body_editor.mode().eq(&EditorMode::Insert)
+                && (self.body_editor.mode().eq(&EditorMode::Insert)
+                    || self.body_editor.is_searching())
             {
                 return Ok(None);
             }
@@ -264,7 +309,8 @@ impl Eventful for RequestEditor<'_> {
         if let KeyCode::BackTab = key_event.code {
             let store = self.collection_store.borrow_mut();
             if self.curr_tab.eq(&ReqEditorTabs::Body)
-                && self.body_editor.mode().eq(&EditorMode::Insert)
+                && (self.body_editor.mode().eq(&EditorMode::Insert)
+                    || self.body_editor.is_searching())
             {
                 return Ok(None);
             }
@@ -279,6 +325,12 @@ impl Eventful for RequestEditor<'_> {
                     return Ok(Some(RequestEditorEvent::RemoveSelection))
                 }
                 Some(BodyEditorEvent::Quit) => return Ok(Some(RequestEditorEvent::Quit)),
+                Some(BodyEditorEvent::EditExternally(path)) => {
+                    return Ok(Some(RequestEditorEvent::EditExternally(path)))
+                }
+                Some(BodyEditorEvent::CopyToClipboard(content)) => {
+                    return Ok(Some(RequestEditorEvent::CopyToClipboard(content)))
+                }
                 None => {}
             },
             ReqEditorTabs::Headers => match self.headers_editor.handle_key_event(key_event)? {
@@ -296,6 +348,49 @@ impl Eventful for RequestEditor<'_> {
                 }
                 None => {}
             },
+            ReqEditorTabs::Description => {
+                match self.description_editor.handle_key_event(key_event)? {
+                    Some(DescriptionEditorEvent::Quit) => {
+                        return Ok(Some(RequestEditorEvent::Quit))
+                    }
+                    Some(DescriptionEditorEvent::RemoveSelection) => {
+                        return Ok(Some(RequestEditorEvent::RemoveSelection))
+                    }
+                    Some(DescriptionEditorEvent::EditExternally(path)) => {
+                        return Ok(Some(RequestEditorEvent::EditExternally(path)))
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn handle_mouse_event(
+        &mut self,
+        mouse_event: MouseEvent,
+    ) -> anyhow::Result<Option<Self::Result>> {
+        // while typing or searching the body, the tabs are locked just like they
+        // are when pressing `Tab`
+        let is_body_busy = self.curr_tab.eq(&ReqEditorTabs::Body)
+            && (self.body_editor.mode().eq(&EditorMode::Insert) || self.body_editor.is_searching());
+
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        if let MouseEventKind::Down(MouseButton::Left) = mouse_event.kind {
+            if self.layout.tabs_pane.contains(position) && !is_body_busy {
+                if let Some(tab) =
+                    tab_at_column(&TAB_TITLES, self.layout.tabs_pane, mouse_event.column)
+                        .and_then(ReqEditorTabs::from_index)
+                {
+                    self.curr_tab = tab;
+                }
+                return Ok(None);
+            }
+        }
+
+        if self.curr_tab.eq(&ReqEditorTabs::Body) && self.layout.content_pane.contains(position) {
+            self.body_editor.handle_mouse_event(mouse_event)?;
         }
 
         Ok(None)
@@ -62,11 +62,8 @@ impl<'ae> AuthEditor<'ae> {
         frame: &mut Frame,
         overlay: CollectionViewerOverlay,
     ) -> anyhow::Result<()> {
-        match overlay {
-            CollectionViewerOverlay::ChangeAuthMethod => {
-                self.auth_kind_prompt.draw(frame, frame.size())?;
-            }
-            _ => {}
+        if let CollectionViewerOverlay::ChangeAuthMethod = overlay {
+            self.auth_kind_prompt.draw(frame, frame.size())?;
         }
         Ok(())
     }
@@ -112,9 +109,8 @@ impl Eventful for AuthEditor<'_> {
             return Ok(None);
         }
 
-        match key_event.code {
-            KeyCode::Char('e') => return Ok(Some(AuthEditorEvent::ChangeAuthMethod)),
-            _ => {}
+        if let KeyCode::Char('e') = key_event.code {
+            return Ok(Some(AuthEditorEvent::ChangeAuthMethod));
         }
 
         Ok(None)
@@ -9,6 +9,7 @@ use ratatui::layout::Rect;
 use ratatui::Frame;
 
 pub enum AuthKindPromptEvent {
+    #[allow(dead_code)]
     Placeholder,
     Cancel,
 }
@@ -16,6 +17,7 @@ pub enum AuthKindPromptEvent {
 #[derive(Debug)]
 pub struct AuthKindPrompt<'akp> {
     colors: &'akp hac_colors::Colors,
+    #[allow(dead_code)]
     collection_store: Rc<RefCell<CollectionStore>>,
 }
 
@@ -23,7 +25,7 @@ impl<'akp> AuthKindPrompt<'akp> {
     pub fn new(
         colors: &'akp hac_colors::Colors,
         collection_store: Rc<RefCell<CollectionStore>>,
-    ) -> AuthKindPrompt {
+    ) -> AuthKindPrompt<'akp> {
         AuthKindPrompt {
             colors,
             collection_store,
@@ -1,26 +1,42 @@
 use hac_config::{Action, EditorMode, KeyAction};
+use hac_core::collection::types::BodyType;
 use hac_core::syntax::highlighter::HIGHLIGHTER;
-use hac_core::text_object::{cursor::Cursor, TextObject, Write};
+use hac_core::text_object::{cursor::Cursor, SearchDirection, TextObject, Write};
 
-use crate::pages::{collection_viewer::collection_store::CollectionStore, Eventful, Renderable};
-use crate::utils::build_syntax_highlighted_lines;
+use crate::pages::collection_viewer::collection_store::{
+    CollectionStore, CollectionStoreAction, Register, UNNAMED_REGISTER,
+};
+use crate::pages::collection_viewer::text_search::{highlight_ranges, TextSearch, TextSearchEvent};
+use crate::pages::{Eventful, Renderable};
+use crate::utils::{build_syntax_highlighted_lines, keycode_as_string};
 
 use std::cell::RefCell;
+use std::io::Write as _;
 use std::ops::{Add, Div, Mul, Sub};
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::Stylize;
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
+use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
+use tempfile::NamedTempFile;
 use tree_sitter::Tree;
 
+/// how many lines a single step of the mouse wheel moves
+const MOUSE_SCROLL_LINES: usize = 3;
+
 pub enum BodyEditorEvent {
     RemoveSelection,
     Quit,
+    /// the body was written to the given file, which should be opened on the
+    /// user's external editor
+    EditExternally(PathBuf),
+    /// a yank that should also be sent to the system clipboard
+    CopyToClipboard(String),
 }
 
 #[derive(Debug)]
@@ -43,7 +59,30 @@ pub struct BodyEditor<'be> {
     /// Only KeyAction::Complex are stored here as any other kind of key action can be acted upon
     /// instantly
     keymap_buffer: Option<KeyAction>,
-    _collection_store: Rc<RefCell<CollectionStore>>,
+
+    search: TextSearch,
+    /// where the cursor was when a search prompt was opened, as searching is
+    /// incremental, we move back here when the search is cancelled
+    search_origin: Cursor,
+
+    /// the position where visual mode started, the selection goes from here to
+    /// the cursor
+    visual_anchor: Cursor,
+    /// register chosen with `"` to be used by the next yank, delete or paste
+    selected_register: Option<char>,
+    /// after pressing `"`, the next key is the name of the register to use
+    is_selecting_register: bool,
+    /// file the body was written to by `EditExternally`, actions cannot bubble
+    /// events up, so we hold it until the key event is done being handled
+    pending_external_edit: Option<PathBuf>,
+    /// yanked text to be sent to the system clipboard, held for the same
+    /// reason as `pending_external_edit`
+    pending_clipboard: Option<String>,
+    /// the file being edited externally, it is created with a random name only
+    /// we can open, and is deleted once this handle is dropped, after the
+    /// editor closes
+    external_edit_file: Option<NamedTempFile>,
+    collection_store: Rc<RefCell<CollectionStore>>,
 }
 
 impl<'be> BodyEditor<'be> {
@@ -60,7 +99,7 @@ impl<'be> BodyEditor<'be> {
         Self {
             body,
             tree,
-            _collection_store: collection_store,
+            collection_store,
             styled_display,
             cursor: Cursor::default(),
             editor_mode: EditorMode::Normal,
@@ -70,6 +109,14 @@ impl<'be> BodyEditor<'be> {
             colors,
             config,
             keymap_buffer: None,
+            search: TextSearch::default(),
+            search_origin: Cursor::default(),
+            visual_anchor: Cursor::default(),
+            selected_register: None,
+            is_selecting_register: false,
+            pending_external_edit: None,
+            pending_clipboard: None,
+            external_edit_file: None,
         }
     }
 
@@ -81,7 +128,74 @@ impl<'be> BodyEditor<'be> {
         &self.body
     }
 
+    pub fn is_searching(&self) -> bool {
+        self.search.is_prompting()
+    }
+
+    /// replaces the body with the contents of a file edited externally, the file
+    /// is removed afterwards as it only existed for the editing session
+    pub fn reload_from_file(&mut self, path: &Path) -> anyhow::Result<()> {
+        let mut content = std::fs::read_to_string(path)?;
+        // most editors add a newline at the end of the file when saving, which
+        // would make every external edit add an empty line to the body
+        if !self.body.to_string().ends_with('\n') && content.ends_with('\n') {
+            content.pop();
+        }
+
+        self.body.replace_content(&content, &self.cursor);
+        self.restore_cursor(self.cursor.clone());
+        self.refresh_display();
+        self.external_edit_file = None;
+
+        Ok(())
+    }
+
+    fn refresh_display(&mut self) {
+        self.tree = HIGHLIGHTER.write().unwrap().parse(&self.body.to_string());
+        self.styled_display =
+            build_syntax_highlighted_lines(&self.body.to_string(), self.tree.as_ref(), self.colors);
+        self.search.update_matches(&self.body);
+    }
+
+    /// writes the body to a new temporary file, its extension matches the type
+    /// of the body so editors can pick the right filetype
+    fn write_external_edit_file(&mut self) {
+        let Some(request) = self.collection_store.borrow().get_selected_request() else {
+            return;
+        };
+        let request = request.read().unwrap();
+        let extension = match request.body_type {
+            Some(BodyType::Json) | None => "json",
+        };
+        let file = tempfile::Builder::new()
+            .prefix("hac-")
+            .suffix(&format!(".{extension}"))
+            .tempfile()
+            .and_then(|mut file| {
+                file.write_all(self.body.to_string().as_bytes())?;
+                file.flush()?;
+                Ok(file)
+            });
+
+        match file {
+            Ok(file) => {
+                self.pending_external_edit = Some(file.path().to_path_buf());
+                self.external_edit_file = Some(file);
+            }
+            Err(e) => tracing::error!("failed to write body to a temporary file: {e:?}"),
+        }
+    }
+
     pub fn draw_cursor(&self, frame: &mut Frame) {
+        if self.search.is_prompting() {
+            let [_, statusline_pane] = build_editor_layout(self.size);
+            frame.set_cursor(
+                statusline_pane.x.add(self.search.prompt_len() as u16),
+                statusline_pane.y,
+            );
+            return;
+        }
+
         // the editor status bar occupies 1 row, so we have to subtract it to prevent the
         // cursor from going out of the intended spacing, we also subtract the bottom border.
         let mut editor_position = self.size;
@@ -107,6 +221,11 @@ impl<'be> BodyEditor<'be> {
     }
 
     fn draw_statusline(&self, frame: &mut Frame, size: Rect) {
+        if self.search.is_prompting() {
+            frame.render_widget(Paragraph::new(self.search.prompt_line(self.colors)), size);
+            return;
+        }
+
         let cursor_pos = self.cursor.readable_position();
 
         let mut mode = Span::from(format!(" {} ", self.editor_mode));
@@ -150,6 +269,17 @@ impl<'be> BodyEditor<'be> {
                     .fg(self.colors.bright.blue)
                     .bg(self.colors.normal.blue);
             }
+            EditorMode::Visual | EditorMode::VisualLine => {
+                mode = mode
+                    .fg(self.colors.normal.black)
+                    .bg(self.colors.bright.magenta);
+                cursor = cursor
+                    .fg(self.colors.normal.black)
+                    .bg(self.colors.bright.magenta);
+                percentage = percentage
+                    .fg(self.colors.bright.magenta)
+                    .bg(self.colors.normal.magenta);
+            }
         };
 
         frame.render_widget(
@@ -166,6 +296,10 @@ impl<'be> BodyEditor<'be> {
             Action::InsertTab => self.insert_tab(),
             Action::EnterMode(EditorMode::Normal) => self.enter_normal_mode(),
             Action::EnterMode(EditorMode::Insert) => self.enter_insert_mode(),
+            Action::EnterMode(EditorMode::Visual) => self.enter_visual_mode(EditorMode::Visual),
+            Action::EnterMode(EditorMode::VisualLine) => {
+                self.enter_visual_mode(EditorMode::VisualLine)
+            }
             Action::MoveToLineStart => self.move_to_line_start(),
             Action::MoveToLineEnd => self.move_to_line_end(),
             Action::MoveLeft => self.move_left(),
@@ -195,10 +329,291 @@ impl<'be> BodyEditor<'be> {
             Action::JumpToClosing => self.jump_to_opposing_token(),
             Action::JumpToEmptyLineBelow => self.jump_to_empty_line_below(),
             Action::JumpToEmptyLineAbove => self.jump_to_empty_line_above(),
-            Action::Undo => {}
-            Action::FindNext => {}
-            Action::FindPrevious => {}
-            Action::PasteBelow => {}
+            Action::Undo => self.undo(),
+            Action::Redo => self.redo(),
+            Action::FindNext => self.jump_to_match(false),
+            Action::FindPrevious => self.jump_to_match(true),
+            Action::Search => self.open_search(SearchDirection::Forward),
+            Action::SearchBackwards => self.open_search(SearchDirection::Backward),
+            Action::PasteBelow => self.paste_below(),
+            Action::PasteAbove => self.paste_above(),
+            Action::YankLine => self.yank_line(),
+            Action::YankSelection => self.yank_selection(),
+            Action::DeleteSelection => self.delete_selection(),
+            Action::ChangeSelection => self.change_selection(),
+            Action::SelectRegister => self.is_selecting_register = true,
+            Action::EditExternally => self.write_external_edit_file(),
+        }
+    }
+
+    fn enter_visual_mode(&mut self, mode: EditorMode) {
+        // switching between visual modes keeps the selection we already had
+        if !self.is_visual_mode() {
+            self.visual_anchor = self.cursor.clone();
+        }
+        self.editor_mode = mode;
+    }
+
+    fn is_visual_mode(&self) -> bool {
+        matches!(
+            self.editor_mode,
+            EditorMode::Visual | EditorMode::VisualLine
+        )
+    }
+
+    /// returns the start and end of the selection, in order
+    fn selection(&self) -> (Cursor, Cursor) {
+        let anchor = (self.visual_anchor.row(), self.visual_anchor.col());
+        match anchor.le(&(self.cursor.row(), self.cursor.col())) {
+            true => (self.visual_anchor.clone(), self.cursor.clone()),
+            false => (self.cursor.clone(), self.visual_anchor.clone()),
+        }
+    }
+
+    fn selected_content(&self) -> Register {
+        let (start, end) = self.selection();
+        match self.editor_mode {
+            EditorMode::VisualLine => Register {
+                content: self.body.get_lines_between(start.row(), end.row()),
+                linewise: true,
+            },
+            _ => Register {
+                content: self.body.get_text_between(&start, &end),
+                linewise: false,
+            },
+        }
+    }
+
+    /// `(col, len)` ranges of the selection on the given row, used to highlight it
+    fn selection_ranges(&self, row: usize) -> Vec<(usize, usize)> {
+        let (start, end) = self.selection();
+        if !self.is_visual_mode() || row.lt(&start.row()) || row.gt(&end.row()) {
+            return vec![];
+        }
+
+        match (&self.editor_mode, row.eq(&start.row()), row.eq(&end.row())) {
+            (EditorMode::VisualLine, _, _) => vec![(0, usize::MAX)],
+            (_, true, true) => vec![(start.col(), end.col().sub(start.col()).add(1))],
+            (_, true, false) => vec![(start.col(), usize::MAX)],
+            (_, false, true) => vec![(0, end.col().add(1))],
+            (_, false, false) => vec![(0, usize::MAX)],
+        }
+    }
+
+    /// the event an action left behind while handling a key event, if any
+    fn take_pending_event(&mut self) -> Option<BodyEditorEvent> {
+        let clipboard = self.pending_clipboard.take();
+        self.pending_external_edit
+            .take()
+            .map(BodyEditorEvent::EditExternally)
+            .or(clipboard.map(BodyEditorEvent::CopyToClipboard))
+    }
+
+    /// stores content into the selected register, or the unnamed one when none was
+    /// selected. The unnamed register always holds the last yank or delete, and
+    /// yanks are also sent to the system clipboard
+    fn store_register(&mut self, register: Register, is_yank: bool) {
+        let name = self.selected_register.take().unwrap_or(UNNAMED_REGISTER);
+
+        if is_yank || matches!(name, '+' | '*') {
+            self.pending_clipboard = Some(register.content.clone());
+        }
+
+        let mut store = self.collection_store.borrow_mut();
+        if name.ne(&UNNAMED_REGISTER) {
+            store.dispatch(CollectionStoreAction::SetRegister(name, register.clone()));
+        }
+        store.dispatch(CollectionStoreAction::SetRegister(
+            UNNAMED_REGISTER,
+            register,
+        ));
+    }
+
+    fn yank_line(&mut self) {
+        let register = Register {
+            content: self
+                .body
+                .get_lines_between(self.cursor.row(), self.cursor.row()),
+            linewise: true,
+        };
+        self.store_register(register, true);
+    }
+
+    fn yank_selection(&mut self) {
+        if !self.is_visual_mode() {
+            return;
+        }
+
+        let register = self.selected_content();
+        self.store_register(register, true);
+        let (start, _) = self.selection();
+        self.cursor = start;
+        self.enter_normal_mode();
+        self.maybe_scroll_view();
+    }
+
+    /// deletes the selection, returning the first row of it
+    fn erase_selection(&mut self) -> usize {
+        let register = self.selected_content();
+        self.store_register(register, false);
+
+        let (start, end) = self.selection();
+        match self.editor_mode {
+            EditorMode::VisualLine => {
+                self.body
+                    .delete_lines_between(start.row(), end.row(), &start);
+            }
+            _ => self.body.delete_between(&start, &end),
+        }
+        self.cursor = start.clone();
+        start.row()
+    }
+
+    fn delete_selection(&mut self) {
+        if !self.is_visual_mode() {
+            return;
+        }
+
+        let was_linewise = self.editor_mode.eq(&EditorMode::VisualLine);
+        let row = self.erase_selection();
+        if was_linewise {
+            self.cursor
+                .move_to_row(row.min(self.body.len_lines().saturating_sub(1)));
+            self.cursor.move_to_line_start();
+        }
+        self.enter_normal_mode();
+        self.maybe_scroll_view();
+    }
+
+    fn change_selection(&mut self) {
+        if !self.is_visual_mode() {
+            return;
+        }
+
+        let was_linewise = self.editor_mode.eq(&EditorMode::VisualLine);
+        let row = self.erase_selection();
+        if was_linewise {
+            // changing whole lines leaves an empty line in their place
+            self.body.insert_lines("", row, &self.cursor);
+            self.cursor.move_to_row(row);
+            self.cursor.move_to_line_start();
+        }
+        self.editor_mode = EditorMode::Insert;
+        self.maybe_scroll_view();
+    }
+
+    fn get_register_to_paste(&mut self) -> Option<Register> {
+        let name = self.selected_register.take().unwrap_or(UNNAMED_REGISTER);
+        self.collection_store.borrow().get_register(name)
+    }
+
+    fn paste_below(&mut self) {
+        let Some(register) = self.get_register_to_paste() else {
+            return;
+        };
+
+        if register.linewise {
+            let row = self.cursor.row().add(1);
+            self.body.insert_lines(&register.content, row, &self.cursor);
+            self.cursor.move_to_row(row);
+            self.cursor.move_to_line_start();
+        } else {
+            // pasting after the cursor on empty lines should paste on the first column
+            if self.body.line_len(self.cursor.row()).gt(&0) {
+                self.cursor.move_right(1);
+            }
+            self.paste_charwise(&register.content);
+        }
+
+        self.maybe_scroll_view();
+    }
+
+    fn paste_above(&mut self) {
+        let Some(register) = self.get_register_to_paste() else {
+            return;
+        };
+
+        if register.linewise {
+            self.body
+                .insert_lines(&register.content, self.cursor.row(), &self.cursor);
+            self.cursor.move_to_line_start();
+        } else {
+            self.paste_charwise(&register.content);
+        }
+
+        self.maybe_scroll_view();
+    }
+
+    /// pastes at the cursor position, leaving the cursor on the last pasted char
+    /// when the content fits on a single line
+    fn paste_charwise(&mut self, content: &str) {
+        self.body.insert_text(content, &self.cursor);
+        if !content.contains('\n') {
+            self.cursor
+                .move_right(content.chars().count().saturating_sub(1));
+        }
+    }
+
+    fn maybe_end_undo_group(&mut self) {
+        if self.editor_mode.eq(&EditorMode::Normal) {
+            self.body.end_undo_group();
+        }
+    }
+
+    fn undo(&mut self) {
+        if let Some(cursor) = self.body.undo(&self.cursor) {
+            self.restore_cursor(cursor);
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(cursor) = self.body.redo(&self.cursor) {
+            self.restore_cursor(cursor);
+        }
+    }
+
+    /// moves the cursor back to where it was on a previous version of the body,
+    /// making sure it still lands on a valid position
+    fn restore_cursor(&mut self, cursor: Cursor) {
+        self.cursor = cursor;
+        let len_lines = self.body.len_lines();
+        if self.cursor.row().ge(&len_lines) {
+            self.cursor.move_to_row(len_lines.saturating_sub(1));
+        }
+        let line_len = self.body.line_len(self.cursor.row());
+        if self.cursor.col().ge(&line_len) {
+            self.cursor.move_to_col(line_len.saturating_sub(1));
+        }
+        self.maybe_scroll_view();
+    }
+
+    fn open_search(&mut self, direction: SearchDirection) {
+        self.search_origin = self.cursor.clone();
+        self.search.open(direction);
+    }
+
+    fn jump_to_match(&mut self, reverse: bool) {
+        let position = (self.cursor.col(), self.cursor.row());
+        if let Some(found) = self.search.closest_match(position, reverse).cloned() {
+            self.cursor.move_to_row(found.row);
+            self.cursor.move_to_col(found.col);
+            self.maybe_scroll_view();
+        }
+    }
+
+    fn handle_search_key_event(&mut self, key_event: KeyEvent) {
+        match self.search.handle_key_event(key_event, &self.body) {
+            Some(TextSearchEvent::Changed) => {
+                // searching is incremental, so every change searches again starting
+                // from where the cursor was when the prompt was opened
+                self.cursor = self.search_origin.clone();
+                self.jump_to_match(false);
+            }
+            Some(TextSearchEvent::Cancelled) => {
+                self.cursor = self.search_origin.clone();
+                self.maybe_scroll_view();
+            }
+            Some(TextSearchEvent::Confirmed) | None => {}
         }
     }
 
@@ -512,11 +927,26 @@ impl Renderable for BodyEditor<'_> {
 
         self.draw_statusline(frame, statusline_pane);
 
+        let match_style = TextSearch::match_style(self.colors);
+        let selection_style = Style::default().bg(self.colors.primary.hover);
         let lines_in_view = self
             .styled_display
             .clone()
             .into_iter()
+            .enumerate()
             .skip(self.row_scroll)
+            .take(size.height.into())
+            .map(|(row, line)| {
+                let line = highlight_ranges(line, &self.selection_ranges(row), selection_style);
+                let ranges = self
+                    .search
+                    .matches()
+                    .iter()
+                    .filter(|m| m.row.eq(&row))
+                    .map(|m| (m.col, m.len))
+                    .collect::<Vec<_>>();
+                highlight_ranges(line, &ranges, match_style)
+            })
             .chain(std::iter::repeat(Line::from(
                 "~".fg(self.colors.bright.black),
             )))
@@ -537,8 +967,25 @@ impl Eventful for BodyEditor<'_> {
     type Result = BodyEditorEvent;
 
     fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Self::Result>> {
+        if self.search.is_prompting() {
+            self.handle_search_key_event(key_event);
+            return Ok(None);
+        }
+
+        if self.is_selecting_register {
+            self.is_selecting_register = false;
+            if let KeyCode::Char(name) = key_event.code {
+                self.selected_register = Some(name);
+            }
+            return Ok(None);
+        }
+
         let key_str = keycode_as_string(key_event);
 
+        // every edit made by a single keymap is undone at once, and when the keymap
+        // enters insert mode, the group goes on until we are back to normal mode
+        self.body.begin_undo_group();
+
         if let Some(buffered_keymap) = self.keymap_buffer.to_owned() {
             match buffered_keymap {
                 KeyAction::Complex(key_action) => match key_action.get(&key_str) {
@@ -556,13 +1003,9 @@ impl Eventful for BodyEditor<'_> {
                 _ => self.keymap_buffer = None,
             }
 
-            self.tree = HIGHLIGHTER.write().unwrap().parse(&self.body.to_string());
-            self.styled_display = build_syntax_highlighted_lines(
-                &self.body.to_string(),
-                self.tree.as_ref(),
-                self.colors,
-            );
-            return Ok(None);
+            self.refresh_display();
+            self.maybe_end_undo_group();
+            return Ok(self.take_pending_event());
         }
 
         if let (KeyCode::Esc, EditorMode::Normal) = (key_event.code, &self.editor_mode) {
@@ -596,32 +1039,52 @@ impl Eventful for BodyEditor<'_> {
                     }
                 }
             },
+            EditorMode::Visual | EditorMode::VisualLine => {
+                match self.config.editor_keys.visual.get(&key_str) {
+                    Some(KeyAction::Simple(action)) => self.handle_action(action),
+                    Some(KeyAction::Multiple(actions)) => {
+                        actions.iter().for_each(|a| self.handle_action(a))
+                    }
+                    Some(key_action) => self.keymap_buffer = Some(key_action.clone()),
+                    None => {}
+                }
+            }
         }
 
-        self.tree = HIGHLIGHTER.write().unwrap().parse(&self.body.to_string());
-        self.styled_display =
-            build_syntax_highlighted_lines(&self.body.to_string(), self.tree.as_ref(), self.colors);
+        self.refresh_display();
+        self.maybe_end_undo_group();
 
-        Ok(None)
+        Ok(self.take_pending_event())
     }
-}
 
-fn keycode_as_string(key_event: KeyEvent) -> String {
-    match (key_event.code, key_event.modifiers) {
-        (KeyCode::Char(c), KeyModifiers::NONE) => c.into(),
-        (KeyCode::Char(c), KeyModifiers::SHIFT) => format!("S-{}", c),
-        (KeyCode::Char(c), KeyModifiers::CONTROL) => format!("C-{}", c),
-        (KeyCode::Backspace, _) => "Backspace".into(),
-        (KeyCode::Left, _) => "Left".into(),
-        (KeyCode::Down, _) => "Down".into(),
-        (KeyCode::Up, _) => "Up".into(),
-        (KeyCode::Right, _) => "Right".into(),
-        (KeyCode::Home, _) => "Home".into(),
-        (KeyCode::End, _) => "End".into(),
-        (KeyCode::Enter, _) => "Enter".into(),
-        (KeyCode::Tab, _) => "Tab".into(),
-        (KeyCode::Esc, _) => "Esc".into(),
-        _ => String::default(),
+    fn handle_mouse_event(
+        &mut self,
+        mouse_event: MouseEvent,
+    ) -> anyhow::Result<Option<Self::Result>> {
+        if self.search.is_prompting() || self.is_selecting_register {
+            return Ok(None);
+        }
+
+        match mouse_event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let [request_pane, _] = build_editor_layout(self.size);
+                if !request_pane.contains(Position::new(mouse_event.column, mouse_event.row)) {
+                    return Ok(None);
+                }
+
+                let row = mouse_event.row.sub(request_pane.y) as usize;
+                let col = mouse_event.column.sub(request_pane.x) as usize;
+                let mut cursor = self.cursor.clone();
+                cursor.move_to_row(row.add(self.row_scroll));
+                cursor.move_to_col(col.add(self.col_scroll));
+                self.restore_cursor(cursor);
+            }
+            MouseEventKind::ScrollDown => (0..MOUSE_SCROLL_LINES).for_each(|_| self.move_down()),
+            MouseEventKind::ScrollUp => (0..MOUSE_SCROLL_LINES).for_each(|_| self.move_up()),
+            _ => {}
+        }
+
+        Ok(None)
     }
 }
 
@@ -669,3 +1132,180 @@ fn make_body(collection_store: &Rc<RefCell<CollectionStore>>) -> (TextObject<Wri
 
     (body, tree)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hac_core::collection::types::{Info, Request, RequestKind, RequestMethod};
+    use hac_core::collection::Collection;
+    use std::sync::{Arc, RwLock};
+
+    fn make_store_with_body(body: &str) -> Rc<RefCell<CollectionStore>> {
+        let request = RequestKind::Single(Arc::new(RwLock::new(Request {
+            id: "req".to_string(),
+            method: RequestMethod::Post,
+            name: "any_name".to_string(),
+            uri: "/any_uri".to_string(),
+            headers: None,
+            auth_method: None,
+            parent: None,
+            body: Some(body.to_string()),
+            body_type: None,
+            description: None,
+        })));
+        let collection = Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: Info {
+                name: "any_name".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(vec![request]))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        };
+        let mut store = CollectionStore::default();
+        store.set_state(collection);
+        Rc::new(RefCell::new(store))
+    }
+
+    fn feed_keys(editor: &mut BodyEditor, keys: &[(KeyCode, KeyModifiers)]) {
+        for (code, modifiers) in keys {
+            editor
+                .handle_key_event(KeyEvent::new(*code, *modifiers))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_visual_line_delete_and_paste() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_body("first\nsecond\nthird");
+        let mut editor = BodyEditor::new(&colors, &config, store.clone(), Rect::new(0, 0, 80, 22));
+
+        feed_keys(
+            &mut editor,
+            &[
+                (KeyCode::Char('j'), KeyModifiers::NONE),
+                (KeyCode::Char('V'), KeyModifiers::SHIFT),
+                (KeyCode::Char('d'), KeyModifiers::NONE),
+            ],
+        );
+        assert_eq!(editor.body().to_string(), "first\nthird");
+        assert_eq!(editor.mode(), &EditorMode::Normal);
+
+        feed_keys(&mut editor, &[(KeyCode::Char('p'), KeyModifiers::NONE)]);
+        assert_eq!(editor.body().to_string(), "first\nthird\nsecond");
+
+        feed_keys(&mut editor, &[(KeyCode::Char('u'), KeyModifiers::NONE)]);
+        assert_eq!(editor.body().to_string(), "first\nthird");
+    }
+
+    #[test]
+    fn test_named_registers_are_shared_between_editors() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_body("{\"name\": \"hac\"}");
+        let mut editor = BodyEditor::new(&colors, &config, store.clone(), Rect::new(0, 0, 80, 22));
+
+        // selects `{"name"` into register `a` and deletes it
+        feed_keys(
+            &mut editor,
+            &[
+                (KeyCode::Char('"'), KeyModifiers::NONE),
+                (KeyCode::Char('a'), KeyModifiers::NONE),
+                (KeyCode::Char('v'), KeyModifiers::NONE),
+                (KeyCode::Char('l'), KeyModifiers::NONE),
+                (KeyCode::Char('l'), KeyModifiers::NONE),
+                (KeyCode::Char('l'), KeyModifiers::NONE),
+                (KeyCode::Char('l'), KeyModifiers::NONE),
+                (KeyCode::Char('l'), KeyModifiers::NONE),
+                (KeyCode::Char('l'), KeyModifiers::NONE),
+                (KeyCode::Char('d'), KeyModifiers::NONE),
+            ],
+        );
+        assert_eq!(editor.body().to_string(), ": \"hac\"}");
+        assert_eq!(
+            store.borrow().get_register('a').unwrap().content,
+            "{\"name\""
+        );
+
+        let mut other = BodyEditor::new(&colors, &config, store.clone(), Rect::new(0, 0, 80, 22));
+        feed_keys(
+            &mut other,
+            &[
+                (KeyCode::Char('"'), KeyModifiers::NONE),
+                (KeyCode::Char('a'), KeyModifiers::NONE),
+                (KeyCode::Char('P'), KeyModifiers::SHIFT),
+            ],
+        );
+        assert_eq!(other.body().to_string(), "{\"name\"{\"name\": \"hac\"}");
+    }
+
+    #[test]
+    fn test_yanks_are_sent_to_the_clipboard() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_body("first\nsecond");
+        let mut editor = BodyEditor::new(&colors, &config, store.clone(), Rect::new(0, 0, 80, 22));
+
+        let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        assert!(editor.handle_key_event(press('y')).unwrap().is_none());
+        let event = editor.handle_key_event(press('y')).unwrap();
+        assert!(
+            matches!(event, Some(BodyEditorEvent::CopyToClipboard(content)) if content == "first\n")
+        );
+        // deletes only go to the registers
+        editor.handle_key_event(press('d')).unwrap();
+        assert!(editor.handle_key_event(press('d')).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_click_places_cursor() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_body("first\nsecond\nthird");
+        let mut editor = BodyEditor::new(&colors, &config, store.clone(), Rect::new(0, 0, 80, 22));
+
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        editor.handle_mouse_event(click(3, 1)).unwrap();
+        assert_eq!((editor.cursor.row(), editor.cursor.col()), (1, 3));
+
+        // clicking past the end of a line lands on its last character
+        editor.handle_mouse_event(click(40, 1)).unwrap();
+        assert_eq!((editor.cursor.row(), editor.cursor.col()), (1, 5));
+    }
+
+    #[test]
+    fn test_edit_externally_roundtrip() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_body("{\"a\": 1}");
+        let mut editor = BodyEditor::new(&colors, &config, store.clone(), Rect::new(0, 0, 80, 22));
+
+        let event = editor
+            .handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL))
+            .unwrap();
+        let Some(BodyEditorEvent::EditExternally(path)) = event else {
+            panic!("expected an external edit event");
+        };
+        assert_eq!(path.extension().unwrap(), "json");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"a\": 1}");
+
+        std::fs::write(&path, "{\n  \"a\": 2\n}\n").unwrap();
+        editor.reload_from_file(&path).unwrap();
+
+        assert_eq!(editor.body().to_string(), "{\n  \"a\": 2\n}");
+        assert!(!path.exists());
+
+        feed_keys(&mut editor, &[(KeyCode::Char('u'), KeyModifiers::NONE)]);
+        assert_eq!(editor.body().to_string(), "{\"a\": 1}");
+    }
+}
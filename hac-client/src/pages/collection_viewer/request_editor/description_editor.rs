@@ -0,0 +1,269 @@
+use crate::pages::collection_viewer::collection_store::CollectionStore;
+use crate::pages::{Eventful, Renderable};
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::ops::Sub;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::{Paragraph, Wrap};
+use ratatui::Frame;
+use tempfile::NamedTempFile;
+
+#[derive(Debug)]
+pub enum DescriptionEditorEvent {
+    Quit,
+    RemoveSelection,
+    /// the description was written to the given file, so the user can edit it
+    /// on their external editor
+    EditExternally(PathBuf),
+}
+
+/// shows the markdown description of the selected request. Descriptions are
+/// written on the external editor, as they are usually long prose
+#[derive(Debug)]
+pub struct DescriptionEditor<'de> {
+    colors: &'de hac_colors::Colors,
+    collection_store: Rc<RefCell<CollectionStore>>,
+    scroll: u16,
+    /// the file being edited externally, deleted once the editor closes
+    external_edit_file: Option<NamedTempFile>,
+}
+
+impl<'de> DescriptionEditor<'de> {
+    pub fn new(
+        colors: &'de hac_colors::Colors,
+        collection_store: Rc<RefCell<CollectionStore>>,
+    ) -> Self {
+        DescriptionEditor {
+            colors,
+            collection_store,
+            scroll: 0,
+            external_edit_file: None,
+        }
+    }
+
+    /// loads what the user saved on the external editor as the description of
+    /// the selected request, a blank file removes the description
+    pub fn reload_from_file(&mut self, path: &Path) -> anyhow::Result<()> {
+        let content = std::fs::read_to_string(path)?;
+        self.external_edit_file = None;
+
+        let Some(request) = self.collection_store.borrow().get_selected_request() else {
+            return Ok(());
+        };
+        let description = content.trim_end();
+        request.write().unwrap().description =
+            (!description.trim().is_empty()).then(|| description.to_string());
+
+        Ok(())
+    }
+
+    fn write_external_edit_file(&mut self) -> Option<PathBuf> {
+        let request = self.collection_store.borrow().get_selected_request()?;
+        let description = request.read().unwrap().description.clone();
+        let file = tempfile::Builder::new()
+            .prefix("hac-")
+            .suffix(".md")
+            .tempfile()
+            .and_then(|mut file| {
+                file.write_all(description.unwrap_or_default().as_bytes())?;
+                file.flush()?;
+                Ok(file)
+            });
+
+        match file {
+            Ok(file) => {
+                let path = file.path().to_path_buf();
+                self.external_edit_file = Some(file);
+                Some(path)
+            }
+            Err(e) => {
+                tracing::error!("failed to write description to a temporary file: {e:?}");
+                None
+            }
+        }
+    }
+
+    fn draw_hint(&self, frame: &mut Frame) {
+        let size = frame.size();
+        let hint_size = Rect::new(0, size.height.sub(1), size.width, 1);
+        frame.render_widget(
+            Paragraph::new("[e: Edit description] [j/k: Scroll] [Tab: Change focus] [?: Help]")
+                .fg(self.colors.bright.black)
+                .centered(),
+            hint_size,
+        );
+    }
+
+    /// styles the parts of markdown that matter when reading it on a terminal,
+    /// headings, list markers and code blocks, everything else is kept as is
+    fn build_lines(&self, description: &str) -> Vec<Line<'static>> {
+        let mut in_code = false;
+        description
+            .lines()
+            .map(|line| {
+                let trimmed = line.trim_start();
+                if trimmed.starts_with("```") {
+                    in_code = !in_code;
+                    return Line::from(line.to_string().fg(self.colors.bright.black));
+                }
+                if in_code {
+                    return Line::from(line.to_string().fg(self.colors.normal.green));
+                }
+                if trimmed.starts_with('#') {
+                    return Line::from(line.to_string().fg(self.colors.normal.blue).bold());
+                }
+                if let Some(item) = trimmed
+                    .strip_prefix("- ")
+                    .or_else(|| trimmed.strip_prefix("* "))
+                {
+                    let indent = line.len() - trimmed.len();
+                    return Line::from(vec![
+                        format!("{}• ", " ".repeat(indent)).fg(self.colors.normal.yellow),
+                        item.to_string().fg(self.colors.normal.white),
+                    ]);
+                }
+                Line::from(line.to_string().fg(self.colors.normal.white))
+            })
+            .collect()
+    }
+}
+
+impl Renderable for DescriptionEditor<'_> {
+    fn draw(&mut self, frame: &mut Frame, size: Rect) -> anyhow::Result<()> {
+        let Some(request) = self.collection_store.borrow().get_selected_request() else {
+            return Ok(());
+        };
+        self.draw_hint(frame);
+
+        let request = request.read().unwrap();
+        let lines = match request.description.as_ref() {
+            Some(description) => self.build_lines(description),
+            None => vec![Line::from(
+                "No description, press `e` to write one in markdown".fg(self.colors.bright.black),
+            )],
+        };
+
+        let max_scroll = lines.len().saturating_sub(1) as u16;
+        self.scroll = self.scroll.min(max_scroll);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            size,
+        );
+
+        Ok(())
+    }
+}
+
+impl Eventful for DescriptionEditor<'_> {
+    type Result = DescriptionEditorEvent;
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Self::Result>> {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+                return Ok(Some(DescriptionEditorEvent::Quit))
+            }
+            (KeyCode::Esc, _) => return Ok(Some(DescriptionEditorEvent::RemoveSelection)),
+            (KeyCode::Char('e'), _) | (KeyCode::Enter, _) => {
+                return Ok(self
+                    .write_external_edit_file()
+                    .map(DescriptionEditorEvent::EditExternally))
+            }
+            (KeyCode::Char('j'), _) | (KeyCode::Down, _) => {
+                self.scroll = self.scroll.saturating_add(1)
+            }
+            (KeyCode::Char('k'), _) | (KeyCode::Up, _) => {
+                self.scroll = self.scroll.saturating_sub(1)
+            }
+            (KeyCode::Char('g'), _) => self.scroll = 0,
+            _ => {}
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::collection_viewer::collection_store::CollectionStoreAction;
+    use hac_core::collection::types::{Collection, Info, Request, RequestKind, RequestMethod};
+
+    use std::sync::{Arc, RwLock};
+
+    fn make_store() -> Rc<RefCell<CollectionStore>> {
+        let request = Arc::new(RwLock::new(Request {
+            id: "description-test".into(),
+            method: RequestMethod::Get,
+            name: "list".into(),
+            uri: "/users".into(),
+            headers: None,
+            auth_method: None,
+            parent: None,
+            body: None,
+            body_type: None,
+            description: None,
+        }));
+        let collection = Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: Info {
+                name: "any".into(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(vec![RequestKind::Single(
+                request.clone(),
+            )]))),
+            environment: Default::default(),
+            path: "any".into(),
+            layout: hac_config::CollectionLayout::File,
+        };
+
+        let mut store = CollectionStore::default();
+        store.set_state(collection);
+        store.dispatch(CollectionStoreAction::SetSelectedRequest(Some(request)));
+        Rc::new(RefCell::new(store))
+    }
+
+    #[test]
+    fn test_editing_the_description_externally() {
+        let colors = hac_colors::Colors::default();
+        let store = make_store();
+        let mut editor = DescriptionEditor::new(&colors, store.clone());
+
+        let event = editor
+            .handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE))
+            .unwrap();
+        let Some(DescriptionEditorEvent::EditExternally(path)) = event else {
+            panic!("expected an external edit");
+        };
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+
+        std::fs::write(&path, "# Users\nlists every user\n").unwrap();
+        editor.reload_from_file(&path).unwrap();
+        let request = store.borrow().get_selected_request().unwrap();
+        assert_eq!(
+            request.read().unwrap().description.as_deref(),
+            Some("# Users\nlists every user")
+        );
+        assert!(!path.exists());
+
+        // saving an empty file removes the description
+        let Some(DescriptionEditorEvent::EditExternally(path)) = editor
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap()
+        else {
+            panic!("expected an external edit");
+        };
+        std::fs::write(&path, "\n  \n").unwrap();
+        editor.reload_from_file(&path).unwrap();
+        assert!(request.read().unwrap().description.is_none());
+    }
+}
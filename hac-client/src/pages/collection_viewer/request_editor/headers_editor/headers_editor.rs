@@ -2,11 +2,13 @@ use crate::ascii::LOGO_ASCII;
 use crate::pages::collection_viewer::collection_viewer::CollectionViewerOverlay;
 use crate::pages::overlay::make_overlay;
 use crate::pages::{collection_viewer::collection_store::CollectionStore, Eventful, Renderable};
+use crate::utils::keycode_as_string;
 
 use std::ops::{Div, Mul, Sub};
 use std::{cell::RefCell, ops::Add, rc::Rc};
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hac_config::UiAction;
 use hac_core::collection::types::HeaderMap;
 use rand::Rng;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
@@ -38,6 +40,7 @@ struct HeadersEditorLayout {
 #[derive(Debug)]
 pub struct HeadersEditor<'he> {
     colors: &'he hac_colors::colors::Colors,
+    config: &'he hac_config::Config,
     collection_store: Rc<RefCell<CollectionStore>>,
     scroll: usize,
     selected_row: usize,
@@ -53,6 +56,7 @@ pub struct HeadersEditor<'he> {
 impl<'he> HeadersEditor<'he> {
     pub fn new(
         colors: &'he hac_colors::colors::Colors,
+        config: &'he hac_config::Config,
         collection_store: Rc<RefCell<CollectionStore>>,
         size: Rect,
     ) -> Self {
@@ -65,6 +69,7 @@ impl<'he> HeadersEditor<'he> {
             header_form: HeadersEditorForm::new(colors, collection_store.clone()),
 
             colors,
+            config,
             collection_store,
             scroll: 0,
             selected_row: 0,
@@ -181,7 +186,7 @@ impl<'he> HeadersEditor<'he> {
         let components = logo
             .iter()
             .map(|line| Line::from(line.fg(self.colors.normal.red)))
-            .chain(std::iter::repeat(Line::from("")).take(2))
+            .chain(std::iter::repeat_n(Line::from(""), 2))
             .chain(lines)
             .collect::<Vec<_>>();
 
@@ -366,6 +371,10 @@ impl Eventful for HeadersEditor<'_> {
             return Ok(Some(HeadersEditorEvent::Quit));
         }
 
+        if let KeyCode::Esc = key_event.code {
+            return Ok(Some(HeadersEditorEvent::RemoveSelection));
+        }
+
         let Some(request) = self.collection_store.borrow_mut().get_selected_request() else {
             return Ok(None);
         };
@@ -378,14 +387,19 @@ impl Eventful for HeadersEditor<'_> {
             .map(|h| h.len())
             .unwrap_or_default();
 
-        match key_event.code {
-            KeyCode::Char('j') | KeyCode::Down => {
+        match self
+            .config
+            .keymaps
+            .headers_editor
+            .get(&keycode_as_string(key_event))
+        {
+            Some(UiAction::MoveDown) => {
                 self.selected_row = usize::min(self.selected_row.add(1), total_headers.sub(1))
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            Some(UiAction::MoveUp) => {
                 self.selected_row = self.selected_row.saturating_sub(1);
             }
-            KeyCode::Char('?') => {
+            Some(UiAction::Help) => {
                 drop(request);
                 let mut store = self.collection_store.borrow_mut();
                 let overlay = store.peek_overlay();
@@ -395,7 +409,7 @@ impl Eventful for HeadersEditor<'_> {
                     store.push_overlay(CollectionViewerOverlay::HeadersHelp);
                 };
             }
-            KeyCode::Char(' ') => {
+            Some(UiAction::Toggle) => {
                 if total_headers.eq(&0) {
                     return Ok(None);
                 }
@@ -412,7 +426,7 @@ impl Eventful for HeadersEditor<'_> {
                     header.enabled = !header.enabled;
                 }
             }
-            KeyCode::Char('d') => {
+            Some(UiAction::Delete) => {
                 if total_headers.eq(&0) {
                     return Ok(None);
                 }
@@ -429,7 +443,7 @@ impl Eventful for HeadersEditor<'_> {
                         .push_overlay(CollectionViewerOverlay::HeadersDelete);
                 }
             }
-            KeyCode::Enter => {
+            Some(UiAction::Edit) => {
                 if total_headers.eq(&0) {
                     return Ok(None);
                 }
@@ -446,8 +460,7 @@ impl Eventful for HeadersEditor<'_> {
                         .push_overlay(CollectionViewerOverlay::HeadersForm(self.selected_row));
                 }
             }
-            KeyCode::Esc => return Ok(Some(HeadersEditorEvent::RemoveSelection)),
-            KeyCode::Char('n') => {
+            Some(UiAction::Create) => {
                 let headers = request.headers.get_or_insert_with(Vec::new);
                 headers.push(HeaderMap {
                     pair: Default::default(),
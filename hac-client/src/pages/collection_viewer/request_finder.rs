@@ -0,0 +1,361 @@
+use hac_core::collection::search::{self, RequestMatch};
+use hac_core::collection::types::{Request, RequestMethod};
+
+use crate::pages::collection_viewer::collection_store::CollectionStore;
+use crate::pages::overlay::make_overlay;
+use crate::pages::{Eventful, Renderable};
+
+use std::cell::RefCell;
+use std::ops::{Add, Div, Mul};
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap};
+use ratatui::Frame;
+
+#[derive(Debug, Clone)]
+pub enum RequestFinderEvent {
+    /// user picked a request, which should be selected and revealed on the
+    /// sidebar
+    Select(Arc<RwLock<Request>>),
+    /// user closed the finder without picking anything
+    Cancel,
+}
+
+/// popup that fuzzy finds requests by their method, name and uri, no matter
+/// how deep they are on the collection, previewing the request under the cursor
+#[derive(Debug)]
+pub struct RequestFinder<'rf> {
+    colors: &'rf hac_colors::Colors,
+    collection_store: Rc<RefCell<CollectionStore>>,
+    query: String,
+    matches: Vec<RequestMatch>,
+    selected: usize,
+    scroll: usize,
+    /// how many requests the collection has, which is how many matches an
+    /// empty query has
+    total: usize,
+}
+
+impl<'rf> RequestFinder<'rf> {
+    pub fn new(
+        colors: &'rf hac_colors::Colors,
+        collection_store: Rc<RefCell<CollectionStore>>,
+    ) -> Self {
+        let mut finder = RequestFinder {
+            colors,
+            collection_store,
+            query: String::default(),
+            matches: vec![],
+            selected: 0,
+            scroll: 0,
+            total: 0,
+        };
+        finder.update_matches();
+        finder.total = finder.matches.len();
+        finder
+    }
+
+    fn update_matches(&mut self) {
+        self.matches = self
+            .collection_store
+            .borrow()
+            .get_requests()
+            .map(|requests| search::find_requests(&requests.read().unwrap(), &self.query))
+            .unwrap_or_default();
+        self.selected = 0;
+        self.scroll = 0;
+    }
+
+    fn method_color(&self, method: &RequestMethod) -> Color {
+        match method {
+            RequestMethod::Get => self.colors.normal.green,
+            RequestMethod::Post => self.colors.normal.magenta,
+            RequestMethod::Put => self.colors.normal.yellow,
+            RequestMethod::Patch => self.colors.normal.orange,
+            RequestMethod::Delete => self.colors.normal.red,
+        }
+    }
+
+    /// the haystack of a match, with the method colored, the uri dimmed, and
+    /// every matched character highlighted
+    fn build_match_line(&self, entry: &RequestMatch, is_selected: bool) -> Line<'static> {
+        let request = entry.request.read().unwrap();
+        let method_len = request.method.to_string().chars().count();
+        let name_end = method_len + 1 + request.name.chars().count();
+
+        let mut spans = vec![match is_selected {
+            true => "> ".fg(self.colors.normal.red),
+            false => Span::from("  "),
+        }];
+        spans.extend(entry.haystack.chars().enumerate().map(|(idx, char)| {
+            let style = match idx {
+                _ if idx.lt(&method_len) => Style::default()
+                    .fg(self.method_color(&request.method))
+                    .bold(),
+                _ if idx.le(&name_end) => Style::default().fg(self.colors.normal.white),
+                _ => Style::default().fg(self.colors.bright.black),
+            };
+            let style = match entry.positions.contains(&idx) {
+                true => style.fg(self.colors.normal.yellow).bold().underlined(),
+                false => style,
+            };
+            Span::styled(char.to_string(), style)
+        }));
+
+        let line = Line::from(spans);
+        match is_selected {
+            true => line.bg(self.colors.primary.hover),
+            false => line,
+        }
+    }
+
+    fn build_preview(&self, entry: &RequestMatch) -> Vec<Line<'static>> {
+        let request = entry.request.read().unwrap();
+        let location = match entry.path.as_ref() {
+            Some(path) => format!("in {path}"),
+            None => "in the root of the collection".to_string(),
+        };
+
+        let mut lines = vec![
+            Line::from(request.name.clone().fg(self.colors.normal.white).bold()),
+            Line::from(location.fg(self.colors.bright.black)),
+            Line::from(""),
+            Line::from(vec![
+                request
+                    .method
+                    .to_string()
+                    .fg(self.method_color(&request.method))
+                    .bold(),
+                format!(" {}", request.uri).fg(self.colors.normal.white),
+            ]),
+        ];
+
+        if let Some(headers) = request.headers.as_ref().filter(|h| !h.is_empty()) {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Headers".fg(self.colors.normal.yellow)));
+            lines.extend(headers.iter().map(|header| {
+                let (name, value) = &header.pair;
+                let color = match header.enabled {
+                    true => self.colors.normal.white,
+                    false => self.colors.bright.black,
+                };
+                Line::from(format!("{name}: {value}").fg(color))
+            }));
+        }
+
+        if let Some(body) = request.body.as_ref().filter(|b| !b.is_empty()) {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Body".fg(self.colors.normal.yellow)));
+            lines.extend(
+                body.lines()
+                    .map(|line| Line::from(line.to_string().fg(self.colors.normal.white))),
+            );
+        }
+
+        lines
+    }
+}
+
+impl Renderable for RequestFinder<'_> {
+    fn draw(&mut self, frame: &mut Frame, _: Rect) -> anyhow::Result<()> {
+        make_overlay(self.colors, self.colors.normal.black, 0.1, frame);
+
+        let size = frame.size();
+        let size = Rect::new(
+            size.width.div(10),
+            size.height.div(8),
+            size.width.mul(8).div(10),
+            size.height.mul(6).div(8),
+        );
+
+        let container = Block::default()
+            .title(" Find request ".fg(self.colors.normal.white))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.colors.bright.black))
+            .padding(Padding::horizontal(1))
+            .bg(self.colors.primary.background);
+        let inner = container.inner(size);
+        frame.render_widget(Clear, size);
+        frame.render_widget(container, size);
+
+        let [prompt_pane, _, results_pane, hint_pane] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+                Constraint::Length(1),
+            ])
+            .areas(inner);
+
+        let [list_pane, preview_pane] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .areas(results_pane);
+
+        let counter = format!(" {}/{}", self.matches.len(), self.total);
+        let prompt = Line::from(vec![
+            "> ".fg(self.colors.normal.red),
+            self.query.clone().fg(self.colors.normal.white),
+            counter.fg(self.colors.bright.black),
+        ]);
+        frame.render_widget(Paragraph::new(prompt), prompt_pane);
+        frame.set_cursor(
+            prompt_pane.x.add(2).add(self.query.chars().count() as u16),
+            prompt_pane.y,
+        );
+
+        // keeps the selected match on view when there are more matches than what
+        // fits on the list
+        let amount_on_view = usize::max(list_pane.height.into(), 1);
+        if self.selected.lt(&self.scroll) {
+            self.scroll = self.selected;
+        }
+        if self.selected.ge(&self.scroll.add(amount_on_view)) {
+            self.scroll = self.selected.add(1).saturating_sub(amount_on_view);
+        }
+
+        let lines = self
+            .matches
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(amount_on_view)
+            .map(|(idx, entry)| self.build_match_line(entry, idx.eq(&self.selected)))
+            .collect::<Vec<_>>();
+        frame.render_widget(Paragraph::new(lines), list_pane);
+
+        if let Some(entry) = self.matches.get(self.selected) {
+            let preview = Paragraph::new(self.build_preview(entry))
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::default()
+                        .borders(Borders::LEFT)
+                        .border_style(Style::default().fg(self.colors.bright.black))
+                        .padding(Padding::horizontal(1)),
+                );
+            frame.render_widget(preview, preview_pane);
+        }
+
+        let hint = Line::from(vec![
+            "[C-n/C-p -> move] ".fg(self.colors.bright.black),
+            "[enter -> open] ".fg(self.colors.normal.green),
+            "[esc -> close]".fg(self.colors.bright.black),
+        ])
+        .centered();
+        frame.render_widget(Paragraph::new(hint), hint_pane);
+
+        Ok(())
+    }
+}
+
+impl Eventful for RequestFinder<'_> {
+    type Result = RequestFinderEvent;
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Self::Result>> {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) | (KeyCode::Esc, _) => {
+                return Ok(Some(RequestFinderEvent::Cancel));
+            }
+            (KeyCode::Enter, _) => {
+                return Ok(self
+                    .matches
+                    .get(self.selected)
+                    .map(|entry| RequestFinderEvent::Select(entry.request.clone())));
+            }
+            (KeyCode::Down, _)
+            | (KeyCode::Tab, _)
+            | (KeyCode::Char('n') | KeyCode::Char('j'), KeyModifiers::CONTROL) => {
+                self.selected =
+                    usize::min(self.selected.add(1), self.matches.len().saturating_sub(1));
+            }
+            (KeyCode::Up, _)
+            | (KeyCode::BackTab, _)
+            | (KeyCode::Char('p') | KeyCode::Char('k'), KeyModifiers::CONTROL) => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            (KeyCode::Backspace, _) => {
+                self.query.pop();
+                self.update_matches();
+            }
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                self.query.push(c);
+                self.update_matches();
+            }
+            _ => {}
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hac_core::collection::types::{Directory, RequestKind};
+    use hac_core::collection::Collection;
+
+    fn make_request(id: &str, method: RequestMethod) -> RequestKind {
+        RequestKind::Single(Arc::new(RwLock::new(Request {
+            id: id.to_string(),
+            method,
+            name: id.to_string(),
+            uri: format!("/{id}"),
+            headers: None,
+            auth_method: None,
+            parent: None,
+            body: None,
+            body_type: None,
+            description: None,
+        })))
+    }
+
+    #[test]
+    fn test_typing_narrows_the_matches() {
+        let colors = hac_colors::Colors::default();
+        let nested = RequestKind::Nested(Directory {
+            id: "users".to_string(),
+            name: "users".to_string(),
+            requests: Arc::new(RwLock::new(vec![make_request(
+                "create_user",
+                RequestMethod::Post,
+            )])),
+            description: None,
+        });
+        let mut store = CollectionStore::default();
+        store.set_state(Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: hac_core::collection::types::Info {
+                name: "any".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(vec![
+                make_request("health", RequestMethod::Get),
+                nested,
+            ]))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        });
+        let mut finder = RequestFinder::new(&colors, Rc::new(RefCell::new(store)));
+        assert_eq!(finder.matches.len(), 2);
+
+        for c in "post".chars() {
+            let event = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+            assert!(finder.handle_key_event(event).unwrap().is_none());
+        }
+        assert_eq!(finder.matches.len(), 1);
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        let Some(RequestFinderEvent::Select(request)) = finder.handle_key_event(enter).unwrap()
+        else {
+            panic!("expected a request to be selected");
+        };
+        assert_eq!(request.read().unwrap().id, "create_user");
+    }
+}
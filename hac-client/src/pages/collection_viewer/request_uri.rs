@@ -137,12 +137,11 @@ impl Eventful for RequestUri<'_> {
             }
             KeyCode::Enter => {
                 let mut store = self.collection_store.borrow_mut();
-                if store
+                let selected_id = store
                     .get_selected_request()
-                    .as_ref()
-                    .is_some_and(|_| !store.has_pending_request())
-                {
-                    store.dispatch(CollectionStoreAction::SetPendingRequest(true));
+                    .map(|req| req.read().unwrap().id.clone());
+                if let Some(id) = selected_id.filter(|id| !store.has_pending_request(id)) {
+                    store.dispatch(CollectionStoreAction::SetPendingRequest(id, true));
                     return Ok(Some(RequestUriEvent::SendRequest));
                 }
             }
@@ -0,0 +1,425 @@
+use hac_config::UiAction;
+use hac_core::net::response_diff::{self, BodyDiff, Change, KeyedChange, LineChange};
+use hac_core::syntax::highlighter::HIGHLIGHTER;
+
+use crate::pages::collection_viewer::collection_store::MarkedResponse;
+use crate::pages::overlay::make_overlay;
+use crate::pages::{Eventful, Renderable};
+use crate::utils::{
+    blend_colors_multiply, build_syntax_highlighted_lines, key_for, keycode_as_string,
+};
+
+use std::ops::{Div, Mul};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph};
+use ratatui::Frame;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseDiffEvent {
+    Close,
+}
+
+/// popup listing every difference between the marked response and the one on
+/// view, status and headers first, then the body
+#[derive(Debug)]
+pub struct ResponseDiffViewer<'rd> {
+    colors: &'rd hac_colors::Colors,
+    config: &'rd hac_config::Config,
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+    /// how many lines fit on the popup, updated when drawing, used to scroll
+    /// by half a page
+    page_height: usize,
+}
+
+impl<'rd> ResponseDiffViewer<'rd> {
+    /// `before` is the marked response, when the user hasn't marked any, the
+    /// popup explains how to do it
+    pub fn new(
+        colors: &'rd hac_colors::Colors,
+        config: &'rd hac_config::Config,
+        before: Option<MarkedResponse>,
+        after: MarkedResponse,
+    ) -> Self {
+        let (title, lines) = match before {
+            Some(before) => (
+                format!(" {} -> {} ", before.label, after.label),
+                build_diff_lines(colors, &before, &after),
+            ),
+            None => {
+                let keymap = &config.keymaps.response_viewer;
+                let hint = match key_for(keymap, UiAction::MarkResponse) {
+                    Some(key) => {
+                        format!("mark a response with `{key}` to diff it against another one")
+                    }
+                    None => String::from("mark a response to diff it against another one"),
+                };
+                (
+                    String::from(" Diff "),
+                    vec![Line::from(hint.fg(colors.bright.black))],
+                )
+            }
+        };
+
+        ResponseDiffViewer {
+            colors,
+            config,
+            title,
+            lines,
+            scroll: 0,
+            page_height: 0,
+        }
+    }
+
+    fn build_hint(&self) -> Line<'static> {
+        let keymap = &self.config.keymaps.response_viewer;
+        let hints = [
+            ([UiAction::MoveDown, UiAction::MoveUp], "scroll"),
+            ([UiAction::HalfPageDown, UiAction::HalfPageUp], "half page"),
+        ];
+
+        let mut spans = hints
+            .into_iter()
+            .filter_map(|(actions, label)| {
+                let keys = actions
+                    .iter()
+                    .filter_map(|action| key_for(keymap, *action))
+                    .collect::<Vec<_>>();
+                (!keys.is_empty()).then(|| format!("[{} -> {label}] ", keys.join("/")))
+            })
+            .map(|hint| hint.fg(self.colors.bright.black))
+            .collect::<Vec<_>>();
+        spans.push("[Esc -> close]".fg(self.colors.bright.black));
+
+        Line::from(spans).centered()
+    }
+
+    fn scroll_by(&mut self, offset: isize) {
+        let max_scroll = self.lines.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(offset).min(max_scroll);
+    }
+}
+
+fn highlight_value(colors: &hac_colors::Colors, value: &str) -> Vec<Span<'static>> {
+    let tree = HIGHLIGHTER.write().unwrap().parse(value);
+    build_syntax_highlighted_lines(value, tree.as_ref(), colors)
+        .into_iter()
+        .flat_map(|line| line.spans)
+        .collect()
+}
+
+fn section_title(colors: &hac_colors::Colors, title: &str) -> Line<'static> {
+    Line::from(title.to_string().fg(colors.normal.yellow).bold())
+}
+
+fn keyed_change_line(
+    colors: &hac_colors::Colors,
+    change: &KeyedChange,
+    is_json: bool,
+) -> Line<'static> {
+    let value = |value: &str| match is_json {
+        true => highlight_value(colors, value),
+        false => vec![value.to_string().fg(colors.normal.white)],
+    };
+
+    let mut spans = match &change.change {
+        Change::Added(_) => vec!["+ ".fg(colors.normal.green)],
+        Change::Removed(_) => vec!["- ".fg(colors.normal.red)],
+        Change::Changed { .. } => vec!["~ ".fg(colors.normal.yellow)],
+    };
+    spans.push(change.key.clone().fg(colors.normal.blue));
+    spans.push(": ".fg(colors.bright.black));
+    match &change.change {
+        Change::Added(after) => spans.extend(value(after)),
+        Change::Removed(before) => spans.extend(value(before)),
+        Change::Changed { before, after } => {
+            spans.extend(value(before));
+            spans.push(" -> ".fg(colors.bright.black));
+            spans.extend(value(after));
+        }
+    }
+    Line::from(spans)
+}
+
+/// line diffs show every line of the body, highlighted just like the preview,
+/// with added and removed lines marked on the gutter
+fn body_lines_diff(colors: &hac_colors::Colors, changes: &[LineChange]) -> Vec<Line<'static>> {
+    let text = |keep: fn(&LineChange) -> Option<&String>| {
+        changes
+            .iter()
+            .filter_map(keep)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let before = text(|change| match change {
+        LineChange::Same(line) | LineChange::Removed(line) => Some(line),
+        LineChange::Added(_) => None,
+    });
+    let after = text(|change| match change {
+        LineChange::Same(line) | LineChange::Added(line) => Some(line),
+        LineChange::Removed(_) => None,
+    });
+    let highlight = |text: &str| {
+        let tree = HIGHLIGHTER.write().unwrap().parse(text);
+        build_syntax_highlighted_lines(text, tree.as_ref(), colors)
+    };
+    let before = highlight(&before);
+    let after = highlight(&after);
+
+    let (mut before_idx, mut after_idx) = (0, 0);
+    let mut lines = vec![];
+    for change in changes {
+        let (gutter, highlighted, raw) = match change {
+            LineChange::Same(line) => {
+                before_idx += 1;
+                after_idx += 1;
+                ("  ".into(), after.get(after_idx - 1), line)
+            }
+            LineChange::Removed(line) => {
+                before_idx += 1;
+                ("- ".fg(colors.normal.red), before.get(before_idx - 1), line)
+            }
+            LineChange::Added(line) => {
+                after_idx += 1;
+                ("+ ".fg(colors.normal.green), after.get(after_idx - 1), line)
+            }
+        };
+        let mut spans = vec![gutter];
+        match highlighted {
+            Some(highlighted) => spans.extend(highlighted.spans.clone()),
+            None => spans.push(raw.clone().fg(colors.normal.white)),
+        }
+        // tinting keeps the syntax highlight readable on changed lines
+        let tint = |color| blend_colors_multiply(color, colors.primary.background, 0.2);
+        let line = Line::from(spans);
+        lines.push(match change {
+            LineChange::Same(_) => line,
+            LineChange::Removed(_) => line.bg(tint(colors.normal.red)),
+            LineChange::Added(_) => line.bg(tint(colors.normal.green)),
+        });
+    }
+    lines
+}
+
+fn build_diff_lines(
+    colors: &hac_colors::Colors,
+    before: &MarkedResponse,
+    after: &MarkedResponse,
+) -> Vec<Line<'static>> {
+    let diff = response_diff::diff_responses(&before.response.borrow(), &after.response.borrow());
+    if diff.is_empty() {
+        return vec![Line::from(
+            "both responses are identical".fg(colors.normal.green),
+        )];
+    }
+
+    let mut lines = vec![];
+    if let Some(Change::Changed { before, after }) = diff.status.as_ref() {
+        lines.push(section_title(colors, "Status"));
+        lines.push(Line::from(vec![
+            "~ ".fg(colors.normal.yellow),
+            before.clone().fg(colors.normal.white),
+            " -> ".fg(colors.bright.black),
+            after.clone().fg(colors.normal.white),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    if !diff.headers.is_empty() {
+        lines.push(section_title(colors, "Headers"));
+        lines.extend(
+            diff.headers
+                .iter()
+                .map(|change| keyed_change_line(colors, change, false)),
+        );
+        lines.push(Line::from(""));
+    }
+
+    lines.push(section_title(colors, "Body"));
+    match &diff.body {
+        BodyDiff::Json(changes) if changes.is_empty() => {
+            lines.push(Line::from("no changes".fg(colors.bright.black)))
+        }
+        BodyDiff::Json(changes) => lines.extend(
+            changes
+                .iter()
+                .map(|change| keyed_change_line(colors, change, true)),
+        ),
+        BodyDiff::Lines(changes) => lines.extend(body_lines_diff(colors, changes)),
+    }
+
+    lines
+}
+
+impl Renderable for ResponseDiffViewer<'_> {
+    fn draw(&mut self, frame: &mut Frame, _: Rect) -> anyhow::Result<()> {
+        make_overlay(self.colors, self.colors.normal.black, 0.1, frame);
+
+        let size = frame.size();
+        let size = Rect::new(
+            size.width.div(10),
+            size.height.div(8),
+            size.width.mul(8).div(10),
+            size.height.mul(6).div(8),
+        );
+
+        let container = Block::default()
+            .title(self.title.clone().fg(self.colors.normal.white))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.colors.bright.black))
+            .padding(Padding::horizontal(1))
+            .bg(self.colors.primary.background);
+        let inner = container.inner(size);
+        frame.render_widget(Clear, size);
+        frame.render_widget(container, size);
+
+        let [lines_pane, _, hint_pane] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .areas(inner);
+        self.page_height = lines_pane.height.into();
+
+        let lines = self
+            .lines
+            .iter()
+            .skip(self.scroll)
+            .take(lines_pane.height.into())
+            .cloned()
+            .collect::<Vec<_>>();
+        frame.render_widget(Paragraph::new(lines), lines_pane);
+
+        frame.render_widget(Paragraph::new(self.build_hint()), hint_pane);
+
+        Ok(())
+    }
+}
+
+impl Eventful for ResponseDiffViewer<'_> {
+    type Result = ResponseDiffEvent;
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Self::Result>> {
+        if let (KeyCode::Char('c'), KeyModifiers::CONTROL) | (KeyCode::Esc, _) =
+            (key_event.code, key_event.modifiers)
+        {
+            return Ok(Some(ResponseDiffEvent::Close));
+        }
+
+        let half_page = self.page_height.div(2).max(1) as isize;
+        match self
+            .config
+            .keymaps
+            .response_viewer
+            .get(&keycode_as_string(key_event))
+        {
+            Some(UiAction::Close) => return Ok(Some(ResponseDiffEvent::Close)),
+            Some(UiAction::HalfPageDown) => self.scroll_by(half_page),
+            Some(UiAction::HalfPageUp) => self.scroll_by(half_page.mul(-1)),
+            Some(UiAction::MoveDown) => self.scroll_by(1),
+            Some(UiAction::MoveUp) => self.scroll_by(-1),
+            Some(UiAction::ScrollToStart) => self.scroll = 0,
+            Some(UiAction::ScrollToEnd) => self.scroll_by(isize::MAX),
+            _ => {}
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hac_core::net::request_manager::Response;
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    fn make_marked(label: &str, status: u16, body: &str) -> MarkedResponse {
+        MarkedResponse {
+            label: label.to_string(),
+            response: Rc::new(RefCell::new(Response {
+                body: Some(body.to_string()),
+                pretty_body: None,
+                headers: None,
+                duration: Duration::from_millis(10),
+                status: Some(reqwest::StatusCode::from_u16(status).unwrap()),
+                headers_size: None,
+                body_size: None,
+                size: None,
+                is_error: false,
+                cause: None,
+                sent: None,
+            })),
+        }
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_status_is_listed_before_the_body() {
+        let colors = hac_colors::Colors::default();
+        let before = make_marked("GET users (200 OK)", 200, r#"{"name":"john","id":1}"#);
+        let after = make_marked("GET users (201 Created)", 201, r#"{"name":"jane","id":1}"#);
+
+        let config = hac_config::load_default_config();
+        let viewer = ResponseDiffViewer::new(&colors, &config, Some(before.clone()), after);
+        let lines = viewer.lines.iter().map(line_text).collect::<Vec<_>>();
+        assert_eq!(lines[0], "Status");
+        assert_eq!(lines[1], "~ 200 OK -> 201 Created");
+        assert_eq!(lines[3], "Body");
+        assert_eq!(lines[4], "~ .name: \"john\" -> \"jane\"");
+        assert_eq!(lines.len(), 5);
+
+        let viewer = ResponseDiffViewer::new(&colors, &config, Some(before.clone()), before);
+        assert_eq!(line_text(&viewer.lines[0]), "both responses are identical");
+    }
+
+    #[test]
+    fn test_scrolling_with_remapped_keys() {
+        let colors = hac_colors::Colors::default();
+        let mut config = hac_config::load_default_config();
+        let keymap = &mut config.keymaps.response_viewer;
+        keymap.retain(|_, action| !matches!(action, UiAction::MoveDown | UiAction::Close));
+        keymap.insert("n".into(), UiAction::MoveDown);
+        keymap.insert("x".into(), UiAction::Close);
+
+        let before = make_marked("GET users", 200, "a\nb\nc");
+        let after = make_marked("GET users", 200, "x\ny\nz");
+        let mut viewer = ResponseDiffViewer::new(&colors, &config, Some(before), after);
+
+        let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        viewer.handle_key_event(press('j')).unwrap();
+        assert_eq!(viewer.scroll, 0);
+        viewer.handle_key_event(press('n')).unwrap();
+        assert_eq!(viewer.scroll, 1);
+        assert!(viewer.handle_key_event(press('q')).unwrap().is_none());
+        assert_eq!(
+            viewer.handle_key_event(press('x')).unwrap(),
+            Some(ResponseDiffEvent::Close)
+        );
+
+        let hint = line_text(&viewer.build_hint());
+        assert!(hint.starts_with("[n/k -> scroll] [C-d/C-u -> half page]"));
+
+        let viewer = ResponseDiffViewer::new(&colors, &config, None, make_marked("", 200, ""));
+        assert_eq!(
+            line_text(&viewer.lines[0]),
+            "mark a response with `m` to diff it against another one"
+        );
+    }
+}
@@ -1,20 +1,24 @@
+use hac_config::UiAction;
 use hac_core::net::request_manager::Response;
+use hac_core::net::response_filter::apply_filter;
 use hac_core::syntax::highlighter::HIGHLIGHTER;
+use hac_core::text_object::{Readonly, SearchDirection, SearchMatch, TextObject};
 
 use crate::ascii::{BIG_ERROR_ARTS, LOGO_ASCII, SMALL_ERROR_ARTS};
 use crate::pages::collection_viewer::collection_viewer::PaneFocus;
+use crate::pages::collection_viewer::text_search::{highlight_ranges, TextSearch, TextSearchEvent};
 use crate::pages::under_construction::UnderConstruction;
 use crate::pages::{spinner::Spinner, Eventful, Renderable};
-use crate::utils::build_syntax_highlighted_lines;
+use crate::utils::{build_syntax_highlighted_lines, keycode_as_string, tab_at_column};
 
 use std::cell::RefCell;
 use std::iter;
 use std::ops::{Add, Sub};
 use std::rc::Rc;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use rand::Rng;
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
 use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph, Scrollbar};
@@ -22,12 +26,15 @@ use ratatui::widgets::{ScrollbarOrientation, ScrollbarState, Tabs};
 use ratatui::Frame;
 use tree_sitter::Tree;
 
-use super::collection_store::CollectionStore;
+use super::collection_store::{CollectionStore, CollectionStoreAction};
 
 #[derive(Debug)]
 pub enum ResponseViewerEvent {
     RemoveSelection,
     Quit,
+    MarkResponse,
+    DiffResponses,
+    ExportHar,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -58,6 +65,11 @@ impl ResViewerTabs {
     }
 }
 
+const TAB_TITLES: [&str; 4] = ["Pretty", "Raw", "Headers", "Cookies"];
+
+/// how many lines a single step of the mouse wheel scrolls
+const MOUSE_SCROLL_LINES: usize = 3;
+
 impl From<ResViewerTabs> for usize {
     fn from(value: ResViewerTabs) -> Self {
         match value {
@@ -69,6 +81,18 @@ impl From<ResViewerTabs> for usize {
     }
 }
 
+impl ResViewerTabs {
+    fn from_index(idx: usize) -> Option<Self> {
+        match idx {
+            0 => Some(ResViewerTabs::Preview),
+            1 => Some(ResViewerTabs::Raw),
+            2 => Some(ResViewerTabs::Headers),
+            3 => Some(ResViewerTabs::Cookies),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ResViewerLayout {
     tabs_pane: Rect,
@@ -85,25 +109,54 @@ struct PreviewLayout {
 #[derive(Debug, Clone)]
 pub struct ResponseViewer<'a> {
     colors: &'a hac_colors::Colors,
+    config: &'a hac_config::Config,
     response: Option<Rc<RefCell<Response>>>,
     tree: Option<Tree>,
+    /// pretty printed body of the current response, we keep it around so we can
+    /// re-apply filters without having to decode the response again
+    body: String,
     lines: Vec<Line<'static>>,
     error_lines: Option<Vec<Line<'static>>>,
     empty_lines: Vec<Line<'static>>,
     preview_layout: PreviewLayout,
     layout: ResViewerLayout,
     collection_store: Rc<RefCell<CollectionStore>>,
+    /// request this viewer shows responses of, every open tab has its own
+    /// viewer, so this is not always the selected request
+    request_id: Option<String>,
 
     active_tab: ResViewerTabs,
     raw_scroll: usize,
     headers_scroll_y: usize,
     headers_scroll_x: usize,
     pretty_scroll: usize,
+
+    /// filter applied to the preview tab, this is stored per request on the
+    /// collection store so we can restore it when switching requests
+    active_filter: Option<String>,
+    /// filter being typed on the filter prompt, only used while `is_filtering`
+    filter_input: String,
+    filter_error: Option<String>,
+    is_filtering: bool,
+
+    /// searchable contents of the preview and raw tabs, rows here map exactly to
+    /// the rows displayed on each tab, so matches can be used to scroll
+    preview_text: TextObject<Readonly>,
+    raw_text: TextObject<Readonly>,
+    search: TextSearch,
+    /// scroll of the active tab when a search prompt was opened, as searching
+    /// is incremental, we scroll back here when the search is cancelled
+    search_origin: usize,
+    last_match: Option<SearchMatch>,
+    /// name of the HAR file the response on view was last exported to, shown
+    /// on the title until another response arrives
+    exported_to: Option<String>,
 }
 
 impl<'a> ResponseViewer<'a> {
     pub fn new(
         colors: &'a hac_colors::Colors,
+        config: &'a hac_config::Config,
         collection_store: Rc<RefCell<CollectionStore>>,
         response: Option<Rc<RefCell<Response>>>,
         size: Rect,
@@ -122,11 +175,18 @@ impl<'a> ResponseViewer<'a> {
         let preview_layout = build_preview_layout(layout.content_pane);
 
         let empty_lines = make_empty_ascii_art(colors);
+        let request_id = collection_store
+            .borrow()
+            .get_selected_request()
+            .map(|req| req.read().unwrap().id.clone());
+        let active_filter = get_stored_filter(&collection_store.borrow(), request_id.as_deref());
 
         ResponseViewer {
             colors,
+            config,
             response,
             tree,
+            body: String::default(),
             lines: vec![],
             error_lines: None,
             empty_lines,
@@ -138,12 +198,32 @@ impl<'a> ResponseViewer<'a> {
             headers_scroll_x: 0,
             pretty_scroll: 0,
             collection_store,
+            request_id,
+            active_filter,
+            filter_input: String::default(),
+            filter_error: None,
+            is_filtering: false,
+            preview_text: TextObject::default(),
+            raw_text: TextObject::default(),
+            search: TextSearch::default(),
+            search_origin: 0,
+            last_match: None,
+            exported_to: None,
         }
     }
 
+    pub fn response(&self) -> Option<Rc<RefCell<Response>>> {
+        self.response.clone()
+    }
+
+    pub fn set_exported_to(&mut self, file_name: String) {
+        self.exported_to = Some(file_name);
+    }
+
     pub fn resize(&mut self, new_size: Rect) {
         self.layout = build_layout(new_size);
         self.preview_layout = build_preview_layout(self.layout.content_pane);
+        self.build_raw_text();
     }
 
     pub fn update(&mut self, response: Option<Rc<RefCell<Response>>>) {
@@ -157,13 +237,11 @@ impl<'a> ResponseViewer<'a> {
             })
             .unwrap_or_default();
 
-        if body_str.len().gt(&0) {
-            self.tree = HIGHLIGHTER.write().unwrap().parse(&body_str);
-            self.lines = build_syntax_highlighted_lines(&body_str, self.tree.as_ref(), self.colors);
-        } else {
-            self.tree = None;
-            self.lines = vec![];
-        }
+        self.body = body_str;
+        self.exported_to = None;
+        self.active_filter =
+            get_stored_filter(&self.collection_store.borrow(), self.request_id.as_deref());
+        self.build_pretty_lines();
 
         if let Some(res) = response.as_ref() {
             let cause: String = res
@@ -197,14 +275,200 @@ impl<'a> ResponseViewer<'a> {
 
         self.empty_lines = make_empty_ascii_art(self.colors);
         self.response = response;
+        self.build_raw_text();
+    }
+
+    /// the raw tab displays the body split into chunks of the width of the pane,
+    /// so we build a text with the same chunks as lines to be able to search it
+    fn build_raw_text(&mut self) {
+        let width = self.layout.content_pane.width.saturating_sub(2).max(1);
+        let content = self
+            .response
+            .as_ref()
+            .and_then(|res| res.borrow().body.clone())
+            .unwrap_or_default()
+            .chars()
+            .map(|c| if matches!(c, '\n' | '\r') { ' ' } else { c })
+            .collect::<Vec<_>>()
+            .chunks(width.into())
+            .map(|chunk| chunk.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        self.raw_text = TextObject::from(&content);
+        self.update_search_matches();
+    }
+
+    fn update_search_matches(&mut self) {
+        self.last_match = None;
+        match self.active_tab {
+            ResViewerTabs::Preview => self.search.update_matches(&self.preview_text),
+            ResViewerTabs::Raw => self.search.update_matches(&self.raw_text),
+            _ => {}
+        }
+    }
+
+    fn active_scroll(&mut self) -> Option<&mut usize> {
+        match self.active_tab {
+            ResViewerTabs::Preview => Some(&mut self.pretty_scroll),
+            ResViewerTabs::Raw => Some(&mut self.raw_scroll),
+            _ => None,
+        }
+    }
+
+    fn jump_to_match(&mut self, reverse: bool) {
+        let Some(scroll) = self.active_scroll().copied() else {
+            return;
+        };
+
+        let position = match self.last_match.as_ref() {
+            Some(last_match) if last_match.row.eq(&scroll) => (last_match.col, last_match.row),
+            _ => (0, scroll),
+        };
+
+        if let Some(found) = self.search.closest_match(position, reverse).cloned() {
+            if let Some(scroll) = self.active_scroll() {
+                *scroll = found.row;
+            }
+            self.last_match = Some(found);
+        }
+    }
+
+    fn handle_search_key_event(&mut self, key_event: KeyEvent) {
+        let text = match self.active_tab {
+            ResViewerTabs::Raw => &self.raw_text,
+            _ => &self.preview_text,
+        };
+
+        match self.search.handle_key_event(key_event, text) {
+            Some(TextSearchEvent::Changed) => {
+                let origin = self.search_origin;
+                if let Some(scroll) = self.active_scroll() {
+                    *scroll = origin;
+                }
+                self.last_match = None;
+                self.jump_to_match(false);
+            }
+            Some(TextSearchEvent::Cancelled) => {
+                let origin = self.search_origin;
+                if let Some(scroll) = self.active_scroll() {
+                    *scroll = origin;
+                }
+                self.last_match = None;
+            }
+            Some(TextSearchEvent::Confirmed) | None => {}
+        }
+    }
+
+    fn highlight_matches(&self, row: usize, line: Line<'static>) -> Line<'static> {
+        let ranges = self
+            .search
+            .matches()
+            .iter()
+            .filter(|m| m.row.eq(&row))
+            .map(|m| (m.col, m.len))
+            .collect::<Vec<_>>();
+        highlight_ranges(line, &ranges, TextSearch::match_style(self.colors))
+    }
+
+    fn draw_search_prompt(&self, frame: &mut Frame, size: Rect) {
+        frame.render_widget(self.search.prompt_line(self.colors), size);
+        frame.set_cursor(size.x.add(self.search.prompt_len() as u16), size.y);
+    }
+
+    /// builds the highlighted lines for the preview tab, applying the active filter
+    /// to the body when there is one
+    fn build_pretty_lines(&mut self) {
+        self.filter_error = None;
+
+        let content = match self.active_filter.as_ref() {
+            Some(filter) if !self.body.is_empty() => match apply_filter(&self.body, filter) {
+                Ok(filtered) => filtered,
+                Err(e) => {
+                    self.filter_error = Some(e.to_string());
+                    self.body.clone()
+                }
+            },
+            _ => self.body.clone(),
+        };
+
+        if content.len().gt(&0) {
+            self.tree = HIGHLIGHTER.write().unwrap().parse(&content);
+            self.lines = build_syntax_highlighted_lines(&content, self.tree.as_ref(), self.colors);
+        } else {
+            self.tree = None;
+            self.lines = vec![];
+        }
+
+        self.preview_text = TextObject::from(&content);
+        self.update_search_matches();
+    }
+
+    fn set_filter(&mut self, filter: Option<String>) {
+        if let Some(req_id) = self.request_id.clone() {
+            self.collection_store
+                .borrow_mut()
+                .dispatch(CollectionStoreAction::SetResponseFilter(
+                    req_id,
+                    filter.clone(),
+                ));
+        }
+
+        self.active_filter = filter;
+        self.pretty_scroll = 0;
+        self.build_pretty_lines();
+    }
+
+    fn handle_filter_key_event(&mut self, key_event: KeyEvent) {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) | (KeyCode::Esc, _) => {
+                self.is_filtering = false;
+                self.filter_input.clear();
+            }
+            (KeyCode::Backspace, _) => {
+                self.filter_input.pop();
+            }
+            (KeyCode::Enter, _) => {
+                self.is_filtering = false;
+                let filter = std::mem::take(&mut self.filter_input);
+                // submitting an empty filter clears the current one
+                let filter = (!filter.trim().is_empty()).then(|| filter.trim().to_string());
+                self.set_filter(filter);
+            }
+            (KeyCode::Char(c), _) => self.filter_input.push(c),
+            _ => {}
+        }
+    }
+
+    fn draw_filter_prompt(&self, frame: &mut Frame, size: Rect) {
+        let prompt = Line::from(vec![
+            "Filter: ".fg(self.colors.bright.black),
+            self.filter_input.clone().fg(self.colors.normal.white),
+        ]);
+        frame.render_widget(prompt, size);
+        frame.set_cursor(
+            size.x
+                .add("Filter: ".len() as u16)
+                .add(self.filter_input.chars().count() as u16),
+            size.y,
+        );
     }
 
     fn draw_container(&self, size: Rect, frame: &mut Frame) {
-        let is_focused = self
+        // when comparing responses, only the viewer of the selected request can
+        // be focused
+        let is_active = self
             .collection_store
             .borrow()
-            .get_focused_pane()
-            .eq(&PaneFocus::Preview);
+            .get_selected_request()
+            .map(|req| req.read().unwrap().id.clone())
+            .eq(&self.request_id);
+        let is_focused = is_active
+            && self
+                .collection_store
+                .borrow()
+                .get_focused_pane()
+                .eq(&PaneFocus::Preview);
         let is_selected = self
             .collection_store
             .borrow()
@@ -217,19 +481,33 @@ impl<'a> ResponseViewer<'a> {
             (_, _) => Style::default().fg(self.colors.bright.black),
         };
 
+        let is_marked = self
+            .collection_store
+            .borrow()
+            .get_marked_response()
+            .zip(self.response.as_ref())
+            .is_some_and(|(marked, response)| Rc::ptr_eq(&marked.response, response));
+        let mut title = vec![
+            "P".fg(self.colors.normal.red).bold(),
+            "review".fg(self.colors.bright.black),
+        ];
+        if is_marked {
+            title.push(" (marked)".fg(self.colors.normal.yellow));
+        }
+        if let Some(file_name) = self.exported_to.as_ref() {
+            title.push(format!(" (saved to {file_name})").fg(self.colors.normal.green));
+        }
+
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(vec![
-                "P".fg(self.colors.normal.red).bold(),
-                "review".fg(self.colors.bright.black),
-            ])
+            .title(title)
             .border_style(block_border);
 
         frame.render_widget(block, size);
     }
 
     fn draw_tabs(&self, frame: &mut Frame, size: Rect) {
-        let tabs = Tabs::new(["Pretty", "Raw", "Headers", "Cookies"])
+        let tabs = Tabs::new(TAB_TITLES)
             .style(Style::default().fg(self.colors.bright.black))
             .select(self.active_tab.clone().into())
             .highlight_style(
@@ -340,7 +618,11 @@ impl<'a> ResponseViewer<'a> {
             }
         }
 
-        if self.collection_store.borrow().has_pending_request() {
+        if self
+            .request_id
+            .as_ref()
+            .is_some_and(|id| self.collection_store.borrow().has_pending_request(id))
+        {
             self.draw_spinner(frame);
         }
 
@@ -465,7 +747,10 @@ impl<'a> ResponseViewer<'a> {
 
             let lines_in_view = lines
                 .into_iter()
+                .enumerate()
                 .skip(self.raw_scroll)
+                .take(size.height.into())
+                .map(|(row, line)| self.highlight_matches(row, line))
                 .chain(iter::repeat(Line::from("~".fg(self.colors.bright.black))))
                 .take(size.height.into())
                 .collect::<Vec<_>>();
@@ -517,7 +802,7 @@ impl<'a> ResponseViewer<'a> {
 
             self.draw_scrollbar(
                 self.lines.len(),
-                self.raw_scroll,
+                self.pretty_scroll,
                 frame,
                 self.preview_layout.scrollbar,
             );
@@ -530,7 +815,10 @@ impl<'a> ResponseViewer<'a> {
 
             let lines_in_view = lines
                 .into_iter()
+                .enumerate()
                 .skip(self.pretty_scroll)
+                .take(size.height.into())
+                .map(|(row, line)| self.highlight_matches(row, line))
                 .chain(iter::repeat(Line::from("~".fg(self.colors.bright.black))))
                 .take(size.height.into())
                 .collect::<Vec<_>>();
@@ -580,6 +868,15 @@ impl<'a> ResponseViewer<'a> {
                 pieces.push(format!("{} B", size).fg(self.colors.normal.green))
             };
 
+            if let Some(filter) = self.active_filter.as_ref() {
+                pieces.push(" ".into());
+                pieces.push("Filter: ".fg(self.colors.bright.black));
+                match self.filter_error.as_ref() {
+                    Some(error) => pieces.push(error.clone().fg(self.colors.normal.red)),
+                    None => pieces.push(filter.clone().fg(self.colors.normal.yellow)),
+                }
+            }
+
             frame.render_widget(Line::from(pieces), size);
         }
     }
@@ -589,7 +886,11 @@ impl<'a> Renderable for ResponseViewer<'a> {
     fn draw(&mut self, frame: &mut Frame, size: Rect) -> anyhow::Result<()> {
         self.draw_tabs(frame, self.layout.tabs_pane);
         self.draw_current_tab(frame, self.layout.content_pane)?;
-        self.draw_summary(frame, self.layout.summary_pane);
+        match (self.is_filtering, self.search.is_prompting()) {
+            (true, _) => self.draw_filter_prompt(frame, self.layout.summary_pane),
+            (_, true) => self.draw_search_prompt(frame, self.layout.summary_pane),
+            _ => self.draw_summary(frame, self.layout.summary_pane),
+        }
         self.draw_container(size, frame);
 
         Ok(())
@@ -602,6 +903,16 @@ impl<'a> Eventful for ResponseViewer<'a> {
     type Result = ResponseViewerEvent;
 
     fn handle_key_event(&mut self, key_event: KeyEvent) -> anyhow::Result<Option<Self::Result>> {
+        if self.is_filtering {
+            self.handle_filter_key_event(key_event);
+            return Ok(None);
+        }
+
+        if self.search.is_prompting() {
+            self.handle_search_key_event(key_event);
+            return Ok(None);
+        }
+
         if let (KeyCode::Char('c'), KeyModifiers::CONTROL) = (key_event.code, key_event.modifiers) {
             return Ok(Some(ResponseViewerEvent::Quit));
         }
@@ -610,33 +921,48 @@ impl<'a> Eventful for ResponseViewer<'a> {
             return Ok(Some(ResponseViewerEvent::RemoveSelection));
         }
 
-        if let KeyCode::Tab = key_event.code {
-            self.active_tab = ResViewerTabs::next(&self.active_tab);
-        }
-
-        if let KeyCode::BackTab = key_event.code {
-            self.active_tab = ResViewerTabs::prev(&self.active_tab);
-        }
+        let is_searchable = matches!(self.active_tab, ResViewerTabs::Preview | ResViewerTabs::Raw);
+        let is_headers = self.active_tab.eq(&ResViewerTabs::Headers);
 
-        match key_event.code {
-            KeyCode::Char('0') if self.active_tab.eq(&ResViewerTabs::Headers) => {
-                self.headers_scroll_x = 0;
+        match self
+            .config
+            .keymaps
+            .response_viewer
+            .get(&keycode_as_string(key_event))
+        {
+            Some(UiAction::NextTab) => {
+                self.active_tab = ResViewerTabs::next(&self.active_tab);
+                self.update_search_matches();
             }
-            KeyCode::Char('$') if self.active_tab.eq(&ResViewerTabs::Headers) => {
-                self.headers_scroll_x = usize::MAX;
+            Some(UiAction::PrevTab) => {
+                self.active_tab = ResViewerTabs::prev(&self.active_tab);
+                self.update_search_matches();
             }
-            KeyCode::Char('h') => {
-                if let ResViewerTabs::Headers = self.active_tab {
-                    self.headers_scroll_x = self.headers_scroll_x.saturating_sub(1)
-                }
+            Some(UiAction::Filter) if self.active_tab.eq(&ResViewerTabs::Preview) => {
+                self.filter_input = self.active_filter.clone().unwrap_or_default();
+                self.is_filtering = true;
+            }
+            Some(action @ (UiAction::Search | UiAction::SearchBackwards)) if is_searchable => {
+                self.search_origin = self.active_scroll().copied().unwrap_or_default();
+                self.search.open(match action {
+                    UiAction::Search => SearchDirection::Forward,
+                    _ => SearchDirection::Backward,
+                });
             }
-            KeyCode::Char('j') => match self.active_tab {
+            Some(UiAction::FindNext) if is_searchable => self.jump_to_match(false),
+            Some(UiAction::FindPrevious) if is_searchable => self.jump_to_match(true),
+            Some(UiAction::ScrollToStart) if is_headers => self.headers_scroll_x = 0,
+            Some(UiAction::ScrollToEnd) if is_headers => self.headers_scroll_x = usize::MAX,
+            Some(UiAction::MoveLeft) if is_headers => {
+                self.headers_scroll_x = self.headers_scroll_x.saturating_sub(1)
+            }
+            Some(UiAction::MoveDown) => match self.active_tab {
                 ResViewerTabs::Preview => self.pretty_scroll = self.pretty_scroll.add(1),
                 ResViewerTabs::Raw => self.raw_scroll = self.raw_scroll.add(1),
                 ResViewerTabs::Headers => self.headers_scroll_y = self.headers_scroll_y.add(1),
                 ResViewerTabs::Cookies => {}
             },
-            KeyCode::Char('k') => match self.active_tab {
+            Some(UiAction::MoveUp) => match self.active_tab {
                 ResViewerTabs::Preview => self.pretty_scroll = self.pretty_scroll.saturating_sub(1),
                 ResViewerTabs::Raw => self.raw_scroll = self.raw_scroll.saturating_sub(1),
                 ResViewerTabs::Headers => {
@@ -644,11 +970,71 @@ impl<'a> Eventful for ResponseViewer<'a> {
                 }
                 ResViewerTabs::Cookies => {}
             },
-            KeyCode::Char('l') => {
-                if let ResViewerTabs::Headers = self.active_tab {
-                    self.headers_scroll_x = self.headers_scroll_x.add(1)
+            Some(UiAction::MoveRight) if is_headers => {
+                self.headers_scroll_x = self.headers_scroll_x.add(1)
+            }
+            Some(UiAction::MarkResponse) if self.response.is_some() => {
+                return Ok(Some(ResponseViewerEvent::MarkResponse));
+            }
+            Some(UiAction::DiffResponses) if self.response.is_some() => {
+                return Ok(Some(ResponseViewerEvent::DiffResponses));
+            }
+            Some(UiAction::ExportHar) if self.response.is_some() => {
+                return Ok(Some(ResponseViewerEvent::ExportHar));
+            }
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn handle_mouse_event(
+        &mut self,
+        mouse_event: MouseEvent,
+    ) -> anyhow::Result<Option<Self::Result>> {
+        if self.is_filtering || self.search.is_prompting() {
+            return Ok(None);
+        }
+
+        match mouse_event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let position = Position::new(mouse_event.column, mouse_event.row);
+                if !self.layout.tabs_pane.contains(position) {
+                    return Ok(None);
+                }
+                if let Some(tab) =
+                    tab_at_column(&TAB_TITLES, self.layout.tabs_pane, mouse_event.column)
+                        .and_then(ResViewerTabs::from_index)
+                {
+                    self.active_tab = tab;
+                    self.update_search_matches();
                 }
             }
+            // scrolling past the end is fine, as each tab clamps its scroll when drawing
+            MouseEventKind::ScrollDown => match self.active_tab {
+                ResViewerTabs::Preview => self.pretty_scroll += MOUSE_SCROLL_LINES,
+                ResViewerTabs::Raw => self.raw_scroll += MOUSE_SCROLL_LINES,
+                ResViewerTabs::Headers => self.headers_scroll_y += MOUSE_SCROLL_LINES,
+                ResViewerTabs::Cookies => {}
+            },
+            MouseEventKind::ScrollUp => match self.active_tab {
+                ResViewerTabs::Preview => {
+                    self.pretty_scroll = self.pretty_scroll.saturating_sub(MOUSE_SCROLL_LINES)
+                }
+                ResViewerTabs::Raw => {
+                    self.raw_scroll = self.raw_scroll.saturating_sub(MOUSE_SCROLL_LINES)
+                }
+                ResViewerTabs::Headers => {
+                    self.headers_scroll_y = self.headers_scroll_y.saturating_sub(MOUSE_SCROLL_LINES)
+                }
+                ResViewerTabs::Cookies => {}
+            },
+            MouseEventKind::ScrollRight if self.active_tab.eq(&ResViewerTabs::Headers) => {
+                self.headers_scroll_x += MOUSE_SCROLL_LINES;
+            }
+            MouseEventKind::ScrollLeft if self.active_tab.eq(&ResViewerTabs::Headers) => {
+                self.headers_scroll_x = self.headers_scroll_x.saturating_sub(MOUSE_SCROLL_LINES);
+            }
             _ => {}
         }
 
@@ -656,6 +1042,13 @@ impl<'a> Eventful for ResponseViewer<'a> {
     }
 }
 
+fn get_stored_filter(
+    collection_store: &CollectionStore,
+    request_id: Option<&str>,
+) -> Option<String> {
+    request_id.and_then(|id| collection_store.get_response_filter(id))
+}
+
 fn build_layout(size: Rect) -> ResViewerLayout {
     let size = Rect::new(
         size.x.add(1),
@@ -748,9 +1141,197 @@ fn make_empty_ascii_art(colors: &hac_colors::Colors) -> Vec<Line<'static>> {
 
 #[cfg(test)]
 mod tests {
+    use hac_core::collection::types::{Info, Request, RequestKind, RequestMethod};
+    use hac_core::collection::Collection;
+    use hac_core::text_object::TextObject;
     use rand::{rngs::StdRng, SeedableRng};
+    use std::sync::{Arc, RwLock};
+    use std::time::Duration;
 
     use super::*;
+
+    fn make_store_with_request(req_id: &str) -> Rc<RefCell<CollectionStore>> {
+        let request = RequestKind::Single(Arc::new(RwLock::new(Request {
+            id: req_id.to_string(),
+            method: RequestMethod::Get,
+            name: "any_name".to_string(),
+            uri: "/any_uri".to_string(),
+            headers: None,
+            auth_method: None,
+            parent: None,
+            body: None,
+            body_type: None,
+            description: None,
+        })));
+        let collection = Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
+            info: Info {
+                name: "any_name".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(vec![request]))),
+            environment: Default::default(),
+            path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
+        };
+        let mut store = CollectionStore::default();
+        store.set_state(collection);
+        Rc::new(RefCell::new(store))
+    }
+
+    fn make_response(body: &str) -> Rc<RefCell<Response>> {
+        Rc::new(RefCell::new(Response {
+            body: Some(body.to_string()),
+            pretty_body: Some(TextObject::from(body)),
+            headers: None,
+            duration: Duration::from_millis(1),
+            status: None,
+            headers_size: None,
+            body_size: None,
+            size: None,
+            is_error: false,
+            cause: None,
+            sent: None,
+        }))
+    }
+
+    fn type_keys(viewer: &mut ResponseViewer, keys: &str) {
+        for c in keys.chars() {
+            let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+            viewer.handle_key_event(key).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_filter_is_applied_and_remembered() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_request("req");
+        let response = make_response(r#"{"user": {"name": "hac"}}"#);
+        let mut viewer = ResponseViewer::new(
+            &colors,
+            &config,
+            store.clone(),
+            None,
+            Rect::new(0, 0, 80, 22),
+        );
+        viewer.update(Some(response.clone()));
+
+        type_keys(&mut viewer, "f.user.name");
+        viewer
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+
+        assert_eq!(viewer.lines.len(), 1);
+        assert_eq!(
+            store.borrow().get_response_filter("req"),
+            Some(".user.name".to_string())
+        );
+
+        let mut viewer = ResponseViewer::new(
+            &colors,
+            &config,
+            store.clone(),
+            None,
+            Rect::new(0, 0, 80, 22),
+        );
+        viewer.update(Some(response));
+        assert_eq!(viewer.active_filter, Some(".user.name".to_string()));
+        assert_eq!(viewer.lines.len(), 1);
+    }
+
+    #[test]
+    fn test_search_scrolls_to_matches() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_request("req");
+        let mut viewer = ResponseViewer::new(
+            &colors,
+            &config,
+            store.clone(),
+            None,
+            Rect::new(0, 0, 80, 22),
+        );
+        viewer.update(Some(make_response(
+            "{\n  \"a\": 1,\n  \"b\": 2,\n  \"a\": 3\n}",
+        )));
+
+        type_keys(&mut viewer, "/\"a");
+        assert_eq!(viewer.pretty_scroll, 1);
+        viewer
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+
+        type_keys(&mut viewer, "n");
+        assert_eq!(viewer.pretty_scroll, 3);
+        type_keys(&mut viewer, "n");
+        assert_eq!(viewer.pretty_scroll, 1);
+        viewer
+            .handle_key_event(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT))
+            .unwrap();
+        assert_eq!(viewer.pretty_scroll, 3);
+    }
+
+    #[test]
+    fn test_mouse_switches_tabs_and_scrolls() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_request("req");
+        let mut viewer = ResponseViewer::new(
+            &colors,
+            &config,
+            store.clone(),
+            None,
+            Rect::new(0, 0, 80, 22),
+        );
+        viewer.update(Some(make_response("{\n  \"a\": 1\n}")));
+
+        let mouse_event = |kind, column, row| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        // tabs are drawn inside of the border, as ` Pretty │ Raw │ ...`
+        viewer
+            .handle_mouse_event(mouse_event(MouseEventKind::Down(MouseButton::Left), 12, 1))
+            .unwrap();
+        assert_eq!(viewer.active_tab, ResViewerTabs::Raw);
+
+        viewer
+            .handle_mouse_event(mouse_event(MouseEventKind::ScrollDown, 20, 10))
+            .unwrap();
+        assert_eq!(viewer.raw_scroll, MOUSE_SCROLL_LINES);
+        viewer
+            .handle_mouse_event(mouse_event(MouseEventKind::ScrollUp, 20, 10))
+            .unwrap();
+        assert_eq!(viewer.raw_scroll, 0);
+        assert_eq!(viewer.pretty_scroll, 0);
+    }
+
+    #[test]
+    fn test_invalid_filter_keeps_body() {
+        let colors = hac_colors::Colors::default();
+        let config = hac_config::load_default_config();
+        let store = make_store_with_request("req");
+        let mut viewer = ResponseViewer::new(
+            &colors,
+            &config,
+            store.clone(),
+            None,
+            Rect::new(0, 0, 80, 22),
+        );
+        viewer.update(Some(make_response("{\n  \"a\": 1\n}")));
+
+        type_keys(&mut viewer, "fnope");
+        viewer
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+
+        assert!(viewer.filter_error.is_some());
+        assert_eq!(viewer.lines.len(), 3);
+    }
     #[test]
     fn test_ascii_with_size() {
         let seed = [0u8; 32];
@@ -7,7 +7,9 @@ mod edit_request_form;
 mod request_form;
 mod select_request_parent;
 
+use hac_config::UiAction;
 use hac_core::collection::types::{Request, RequestKind, RequestMethod};
+use hac_core::collection::{search, tree};
 
 use super::sidebar::delete_item_prompt::{DeleteItemPrompt, DeleteItemPromptEvent};
 use super::sidebar::directory_form::{DirectoryForm, DirectoryFormEvent};
@@ -17,13 +19,15 @@ use super::sidebar::request_form::{RequestFormCreate, RequestFormEdit};
 use crate::pages::collection_viewer::collection_store::{CollectionStore, CollectionStoreAction};
 use crate::pages::collection_viewer::collection_viewer::{CollectionViewerOverlay, PaneFocus};
 use crate::pages::{Eventful, Renderable};
+use crate::utils::keycode_as_string;
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::{Add, Sub};
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::Rect;
 use ratatui::style::{Style, Styled, Stylize};
 use ratatui::text::{Line, Span};
@@ -56,6 +60,9 @@ pub enum SidebarEvent {
     /// user pressed `DeleteItem (D)` hotkey, which should notify the caller to open the
     /// delete_item_prompt to ask the user for confirmation
     DeleteItem(String),
+    /// user pressed `FindRequest (C-p)` hotkey, which should notify the caller to
+    /// open the request finder
+    FindRequest,
     /// user pressed a hotkey to quit the application, so we bubble up so the caller
     /// can do a few things before bubbling the quit request further up
     Quit,
@@ -113,20 +120,28 @@ impl RequestFormVariant<'_> {
 #[derive(Debug)]
 pub struct Sidebar<'sbar> {
     colors: &'sbar hac_colors::Colors,
+    config: &'sbar hac_config::Config,
     lines: Vec<Paragraph<'static>>,
     collection_store: Rc<RefCell<CollectionStore>>,
     request_form: RequestFormVariant<'sbar>,
     directory_form: DirectoryFormVariant<'sbar>,
     delete_item_prompt: DeleteItemPrompt<'sbar>,
+    /// whether the user is typing on the filter prompt, the filter itself lives
+    /// on the store, so it stays applied when the sidebar is rebuilt
+    is_filtering: bool,
+    /// area the sidebar was last drawn on, used to find which item was clicked
+    size: Rect,
 }
 
 impl<'sbar> Sidebar<'sbar> {
     pub fn new(
         colors: &'sbar hac_colors::Colors,
+        config: &'sbar hac_config::Config,
         collection_store: Rc<RefCell<CollectionStore>>,
     ) -> Self {
         let mut sidebar = Self {
             colors,
+            config,
             request_form: RequestFormVariant::Create(RequestForm::<RequestFormCreate>::new(
                 colors,
                 collection_store.clone(),
@@ -136,6 +151,8 @@ impl<'sbar> Sidebar<'sbar> {
             ),
             delete_item_prompt: DeleteItemPrompt::new(colors, collection_store.clone()),
             lines: vec![],
+            is_filtering: false,
+            size: Rect::default(),
             collection_store,
         };
 
@@ -146,14 +163,61 @@ impl<'sbar> Sidebar<'sbar> {
 
     pub fn rebuild_tree_view(&mut self) {
         let mut collection_store = self.collection_store.borrow_mut();
-        self.lines = build_lines(
-            collection_store.get_requests(),
-            0,
-            collection_store.get_selected_request(),
-            collection_store.get_hovered_request(),
-            collection_store.get_dirs_expanded().unwrap().clone(),
-            self.colors,
-        );
+        let filter = collection_store
+            .get_sidebar_filter()
+            .zip(collection_store.get_filtered_items());
+        let context = TreeContext {
+            selected_request: collection_store.get_selected_request(),
+            hovered_request: collection_store.get_hovered_request(),
+            cut_item: collection_store.get_cut_item(),
+            filter,
+            dirs_expanded: collection_store.get_dirs_expanded().unwrap().clone(),
+            colors: self.colors,
+        };
+        self.lines = build_lines(collection_store.get_requests(), 0, &context);
+    }
+
+    fn set_filter(&mut self, filter: Option<String>) {
+        self.collection_store
+            .borrow_mut()
+            .dispatch(CollectionStoreAction::SetSidebarFilter(filter));
+        self.rebuild_tree_view();
+    }
+
+    fn handle_filter_key_event(&mut self, key_event: KeyEvent) -> Option<SidebarEvent> {
+        let mut filter = self
+            .collection_store
+            .borrow()
+            .get_sidebar_filter()
+            .unwrap_or_default();
+
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) | (KeyCode::Esc, _) => {
+                self.is_filtering = false;
+                self.set_filter(None);
+            }
+            (KeyCode::Enter, _) => {
+                self.is_filtering = false;
+                if filter.is_empty() {
+                    self.set_filter(None);
+                }
+            }
+            (KeyCode::Backspace, _) if filter.is_empty() => {
+                self.is_filtering = false;
+                self.set_filter(None);
+            }
+            (KeyCode::Backspace, _) => {
+                filter.pop();
+                self.set_filter(Some(filter));
+            }
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                filter.push(c);
+                self.set_filter(Some(filter));
+            }
+            _ => {}
+        }
+
+        None
     }
 
     pub fn draw_overlay(
@@ -189,6 +253,7 @@ impl<'sbar> Sidebar<'sbar> {
 
 impl<'sbar> Renderable for Sidebar<'sbar> {
     fn draw(&mut self, frame: &mut Frame, size: Rect) -> anyhow::Result<()> {
+        self.size = size;
         let is_focused = self
             .collection_store
             .borrow()
@@ -218,11 +283,35 @@ impl<'sbar> Renderable for Sidebar<'sbar> {
 
         frame.render_widget(block, size);
 
+        // the filter takes the last row of the sidebar, while there is one
+        let filter = self.collection_store.borrow().get_sidebar_filter();
+        let last_row = match filter.is_some() {
+            true => size.bottom().saturating_sub(2),
+            false => size.bottom().saturating_sub(1),
+        };
+
         self.lines.clone().into_iter().for_each(|req| {
             requests_size.y += 1;
-            frame.render_widget(req, requests_size);
+            if requests_size.y.lt(&last_row) {
+                frame.render_widget(req, requests_size);
+            }
         });
 
+        if let Some(filter) = filter {
+            let filter_size = Rect::new(requests_size.x, last_row, requests_size.width, 1);
+            let color = match self.is_filtering {
+                true => self.colors.normal.yellow,
+                false => self.colors.bright.black,
+            };
+            let line = Line::from(vec!["/".fg(color), filter.clone().fg(color)]);
+            frame.render_widget(Paragraph::new(line), filter_size);
+
+            if self.is_filtering {
+                let column = filter_size.x.add(1).add(filter.chars().count() as u16);
+                frame.set_cursor(column, filter_size.y);
+            }
+        }
+
         Ok(())
     }
 
@@ -335,11 +424,16 @@ impl<'a> Eventful for Sidebar<'a> {
                 match self.delete_item_prompt.handle_key_event(key_event)? {
                     Some(DeleteItemPromptEvent::Confirm) => {
                         let mut store = self.collection_store.borrow_mut();
-                        let changed_selection = store
-                            .get_selected_request()
-                            .is_some_and(|req| req.read().unwrap().id.eq(&item_id));
+                        let selected_id = |store: &CollectionStore| {
+                            store
+                                .get_selected_request()
+                                .map(|req| req.read().unwrap().id.clone())
+                        };
+                        // removing a directory may also remove the selected request
+                        let previous_selection = selected_id(&store);
                         store.remove_item(item_id);
                         store.pop_overlay();
+                        let changed_selection = selected_id(&store).ne(&previous_selection);
                         drop(store);
                         self.rebuild_tree_view();
 
@@ -362,14 +456,38 @@ impl<'a> Eventful for Sidebar<'a> {
             _ => {}
         };
 
+        if self.is_filtering {
+            return Ok(self.handle_filter_key_event(key_event));
+        }
+
         if let (KeyCode::Char('c'), KeyModifiers::CONTROL) = (key_event.code, key_event.modifiers) {
             return Ok(Some(SidebarEvent::Quit));
         }
 
+        // with a filter applied, leaving clears the filter before anything else
+        let has_filter = self
+            .collection_store
+            .borrow()
+            .get_sidebar_filter()
+            .is_some();
+        if let (KeyCode::Esc, true) = (key_event.code, has_filter) {
+            self.set_filter(None);
+            return Ok(None);
+        }
+
+        if let KeyCode::Esc = key_event.code {
+            return Ok(Some(SidebarEvent::RemoveSelection));
+        }
+
         let mut store = self.collection_store.borrow_mut();
 
-        match key_event.code {
-            KeyCode::Enter => {
+        match self
+            .config
+            .keymaps
+            .sidebar
+            .get(&keycode_as_string(key_event))
+        {
+            Some(UiAction::Select) => {
                 if store.get_requests().is_none() || store.get_hovered_request().is_none() {
                     return Ok(None);
                 }
@@ -385,9 +503,9 @@ impl<'a> Eventful for Sidebar<'a> {
                     }
                 }
             }
-            KeyCode::Char('j') | KeyCode::Down => store.dispatch(CollectionStoreAction::HoverNext),
-            KeyCode::Char('k') | KeyCode::Up => store.dispatch(CollectionStoreAction::HoverPrev),
-            KeyCode::Char('n') => {
+            Some(UiAction::MoveDown) => store.dispatch(CollectionStoreAction::HoverNext),
+            Some(UiAction::MoveUp) => store.dispatch(CollectionStoreAction::HoverPrev),
+            Some(UiAction::Create) => {
                 self.request_form =
                     RequestFormVariant::Create(RequestForm::<RequestFormCreate>::new(
                         self.colors,
@@ -395,7 +513,7 @@ impl<'a> Eventful for Sidebar<'a> {
                     ));
                 return Ok(Some(SidebarEvent::CreateRequest));
             }
-            KeyCode::Char('e') => {
+            Some(UiAction::Edit) => {
                 let hovered_request = store.find_hovered_request();
                 drop(store);
                 match hovered_request {
@@ -419,13 +537,62 @@ impl<'a> Eventful for Sidebar<'a> {
                     }
                 }
             }
-            KeyCode::Char('D') => {
+            Some(UiAction::Delete) => {
                 if let Some(item_id) = store.get_hovered_request() {
                     return Ok(Some(SidebarEvent::DeleteItem(item_id)));
                 }
             }
-            KeyCode::Char('d') => return Ok(Some(SidebarEvent::CreateDirectory)),
-            KeyCode::Esc => return Ok(Some(SidebarEvent::RemoveSelection)),
+            Some(UiAction::FindRequest) => return Ok(Some(SidebarEvent::FindRequest)),
+            Some(UiAction::Filter) => {
+                let filter = store.get_sidebar_filter().unwrap_or_default();
+                drop(store);
+                self.is_filtering = true;
+                self.set_filter(Some(filter));
+                return Ok(None);
+            }
+            Some(UiAction::Cut) => {
+                // cutting the item that is already cut gives up on moving it
+                let hovered = store.get_hovered_request();
+                let cut_item = match store.get_cut_item().eq(&hovered) {
+                    true => None,
+                    false => hovered,
+                };
+                store.dispatch(CollectionStoreAction::SetCutItem(cut_item));
+            }
+            Some(UiAction::Paste) if store.get_cut_item().is_some() => {
+                store.dispatch(CollectionStoreAction::PasteItem);
+                drop(store);
+                self.rebuild_tree_view();
+                return Ok(Some(SidebarEvent::SyncCollection));
+            }
+            Some(UiAction::Duplicate) => {
+                if let Some(item_id) = store.get_hovered_request() {
+                    store.dispatch(CollectionStoreAction::DuplicateItem(item_id));
+                    drop(store);
+                    self.rebuild_tree_view();
+                    return Ok(Some(SidebarEvent::SyncCollection));
+                }
+            }
+            Some(action @ (UiAction::MoveItemUp | UiAction::MoveItemDown)) => {
+                if let Some(item_id) = store.get_hovered_request() {
+                    store.dispatch(match action {
+                        UiAction::MoveItemUp => CollectionStoreAction::MoveItemUp(item_id),
+                        _ => CollectionStoreAction::MoveItemDown(item_id),
+                    });
+                    drop(store);
+                    self.rebuild_tree_view();
+                    return Ok(Some(SidebarEvent::SyncCollection));
+                }
+            }
+            Some(UiAction::CreateDirectory) => {
+                drop(store);
+                self.directory_form =
+                    DirectoryFormVariant::Create(DirectoryForm::<DirectoryFormCreate>::new(
+                        self.colors,
+                        self.collection_store.clone(),
+                    ));
+                return Ok(Some(SidebarEvent::CreateDirectory));
+            }
             _ => {}
         }
 
@@ -434,27 +601,101 @@ impl<'a> Eventful for Sidebar<'a> {
 
         Ok(None)
     }
+
+    fn handle_mouse_event(
+        &mut self,
+        mouse_event: MouseEvent,
+    ) -> anyhow::Result<Option<Self::Result>> {
+        let mut store = self.collection_store.borrow_mut();
+        if store.has_overlay() {
+            return Ok(None);
+        }
+
+        match mouse_event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // items are drawn one per row right below the top border
+                let first_row = self.size.y.saturating_add(1);
+                let last_row = self.size.bottom().saturating_sub(1);
+                if mouse_event.row.lt(&first_row) || mouse_event.row.ge(&last_row) {
+                    return Ok(None);
+                }
+
+                let items = store.visible_items();
+                let Some(item) = items.get(mouse_event.row.sub(first_row) as usize) else {
+                    return Ok(None);
+                };
+
+                store.dispatch(CollectionStoreAction::SetHoveredRequest(Some(
+                    item.get_id(),
+                )));
+                match item {
+                    RequestKind::Nested(dir) => {
+                        store.dispatch(CollectionStoreAction::ToggleDirectory(dir.id.clone()));
+                    }
+                    RequestKind::Single(req) => {
+                        store
+                            .dispatch(CollectionStoreAction::SetSelectedRequest(Some(req.clone())));
+                        return Ok(Some(SidebarEvent::RebuildView));
+                    }
+                }
+            }
+            MouseEventKind::ScrollDown => store.dispatch(CollectionStoreAction::HoverNext),
+            MouseEventKind::ScrollUp => store.dispatch(CollectionStoreAction::HoverPrev),
+            _ => return Ok(None),
+        }
+
+        drop(store);
+        self.rebuild_tree_view();
+
+        Ok(None)
+    }
+}
+
+/// the directory new items should be created on, based on the hovered item,
+/// along with its full path for display
+fn hovered_directory(store: &CollectionStore) -> Option<(String, String)> {
+    let dir_id = store.get_hovered_directory()?;
+    let requests = store.get_requests()?;
+    let dir_path = tree::directory_path(&requests.read().unwrap(), &dir_id)?;
+    Some((dir_id, dir_path))
+}
+
+/// everything needed to draw the items of the tree, other than the items
+pub struct TreeContext<'a> {
+    pub selected_request: Option<Arc<RwLock<Request>>>,
+    pub hovered_request: Option<String>,
+    pub cut_item: Option<String>,
+    /// the sidebar filter, along with the ids of every item it leaves visible
+    pub filter: Option<(String, HashSet<String>)>,
+    pub dirs_expanded: Rc<RefCell<HashMap<String, bool>>>,
+    pub colors: &'a hac_colors::Colors,
 }
 
 pub fn build_lines(
     requests: Option<Arc<RwLock<Vec<RequestKind>>>>,
     level: usize,
-    selected_request: Option<Arc<RwLock<Request>>>,
-    hovered_request: Option<String>,
-    dirs_expanded: Rc<RefCell<HashMap<String, bool>>>,
-    colors: &hac_colors::Colors,
+    context: &TreeContext,
 ) -> Vec<Paragraph<'static>> {
+    let colors = context.colors;
     requests
         .unwrap_or(Arc::new(RwLock::new(vec![])))
         .read()
         .unwrap()
         .iter()
+        .filter(|item| {
+            context
+                .filter
+                .as_ref()
+                .is_none_or(|(_, visible)| visible.contains(&item.get_id()))
+        })
         .flat_map(|item| match item {
             RequestKind::Nested(dir) => {
-                let is_hovered = hovered_request
+                let is_hovered = context
+                    .hovered_request
                     .as_ref()
                     .is_some_and(|id| id.eq(&item.get_id()));
-                let mut dirs = dirs_expanded.borrow_mut();
+                let is_cut = context.cut_item.as_ref().is_some_and(|id| id.eq(&dir.id));
+                let mut dirs = context.dirs_expanded.borrow_mut();
                 let is_expanded = dirs.entry(dir.id.to_string()).or_insert(false);
 
                 let dir_style = match is_hovered {
@@ -464,6 +705,10 @@ pub fn build_lines(
                         .bold(),
                     false => Style::default().fg(colors.normal.white).bold(),
                 };
+                let dir_style = match is_cut {
+                    true => dir_style.fg(colors.bright.black).italic(),
+                    false => dir_style,
+                };
 
                 let gap = " ".repeat(level * 2);
                 let chevron = if *is_expanded { "v" } else { ">" };
@@ -476,14 +721,8 @@ pub fn build_lines(
                 .set_style(dir_style)];
 
                 let nested_lines = if *is_expanded {
-                    build_lines(
-                        Some(dir.requests.clone()),
-                        level + 1,
-                        selected_request.clone(),
-                        hovered_request.clone(),
-                        dirs_expanded.clone(),
-                        colors,
-                    )
+                    drop(dirs);
+                    build_lines(Some(dir.requests.clone()), level + 1, context)
                 } else {
                     vec![]
                 };
@@ -491,10 +730,11 @@ pub fn build_lines(
             }
             RequestKind::Single(req) => {
                 let gap = " ".repeat(level * 2);
-                let is_selected = selected_request.as_ref().is_some_and(|selected| {
+                let is_selected = context.selected_request.as_ref().is_some_and(|selected| {
                     selected.read().unwrap().id.eq(&req.read().unwrap().id)
                 });
-                let is_hovered = hovered_request
+                let is_hovered = context
+                    .hovered_request
                     .as_ref()
                     .is_some_and(|id| id.eq(&item.get_id()));
 
@@ -511,19 +751,71 @@ pub fn build_lines(
                     (false, false) => Style::default().fg(colors.normal.white),
                 };
 
-                let line: Line<'_> = vec![
+                // items waiting to be pasted are dimmed, so it is clear what will move
+                let is_cut = context
+                    .cut_item
+                    .as_ref()
+                    .is_some_and(|id| id.eq(&req.read().unwrap().id));
+                let name_style = match is_cut {
+                    true => Style::default().fg(colors.bright.black).italic(),
+                    false => Style::default(),
+                };
+
+                let name = req.read().unwrap().name.clone();
+                let mut line = vec![
                     Span::from(gap.clone()),
                     colored_method(req.read().unwrap().method.clone(), colors),
-                    Span::from(format!(" {}", req.read().unwrap().name.clone())),
-                ]
-                .into();
-
-                vec![Paragraph::new(line).set_style(req_style)]
+                    Span::styled(" ", name_style),
+                ];
+                line.extend(highlight_filter(
+                    &name,
+                    context.filter.as_ref().map(|(filter, _)| filter.as_str()),
+                    name_style,
+                    colors,
+                ));
+
+                vec![Paragraph::new(Line::from(line)).set_style(req_style)]
             }
         })
         .collect()
 }
 
+/// splits `text` into spans, highlighting every occurrence of the filter on it
+fn highlight_filter(
+    text: &str,
+    filter: Option<&str>,
+    style: Style,
+    colors: &hac_colors::Colors,
+) -> Vec<Span<'static>> {
+    let ranges = filter
+        .map(|filter| search::substring_ranges(text, filter))
+        .unwrap_or_default();
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut spans = vec![];
+    let mut start = 0;
+    for range in ranges {
+        if start.lt(&range.start) {
+            spans.push(Span::styled(
+                chars[start..range.start].iter().collect::<String>(),
+                style,
+            ));
+        }
+        spans.push(Span::styled(
+            chars[range.clone()].iter().collect::<String>(),
+            style.fg(colors.normal.yellow).bold().underlined(),
+        ));
+        start = range.end;
+    }
+    if start.lt(&chars.len()) {
+        spans.push(Span::styled(
+            chars[start..].iter().collect::<String>(),
+            style,
+        ));
+    }
+    spans
+}
+
 fn colored_method(method: RequestMethod, colors: &hac_colors::Colors) -> Span<'static> {
     match method {
         RequestMethod::Get => "GET   ".fg(colors.normal.green).bold(),
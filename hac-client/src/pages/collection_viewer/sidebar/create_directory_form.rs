@@ -1,9 +1,10 @@
+use hac_core::collection::tree;
 use hac_core::collection::types::*;
 
 use super::directory_form::{DirectoryForm, DirectoryFormCreate, DirectoryFormEvent};
 use crate::ascii::LOGO_ASCII;
 use crate::pages::collection_viewer::collection_store::CollectionStore;
-use crate::pages::collection_viewer::sidebar::DirectoryFormTrait;
+use crate::pages::collection_viewer::sidebar::{hovered_directory, DirectoryFormTrait};
 use crate::pages::Eventful;
 
 use std::cell::RefCell;
@@ -19,6 +20,7 @@ impl<'df> DirectoryForm<'df, DirectoryFormCreate> {
         collection_store: Rc<RefCell<CollectionStore>>,
     ) -> DirectoryForm<'df, DirectoryFormCreate> {
         let logo_idx = rand::thread_rng().gen_range(0..LOGO_ASCII.len());
+        let parent_dir = hovered_directory(&collection_store.borrow());
 
         DirectoryForm {
             colors,
@@ -27,6 +29,7 @@ impl<'df> DirectoryForm<'df, DirectoryFormCreate> {
             logo_idx,
             marker: std::marker::PhantomData,
             directory: None,
+            parent_dir,
         }
     }
 }
@@ -42,6 +45,11 @@ impl Eventful for DirectoryForm<'_, DirectoryFormCreate> {
             return Ok(Some(DirectoryFormEvent::Cancel));
         }
 
+        if let (KeyCode::Char('p'), KeyModifiers::CONTROL) = (key_event.code, key_event.modifiers) {
+            self.parent_dir = None;
+            return Ok(None);
+        }
+
         match key_event.code {
             KeyCode::Esc => {
                 self.reset();
@@ -63,11 +71,16 @@ impl Eventful for DirectoryForm<'_, DirectoryFormCreate> {
                     self.dir_name = "unnamed directory".into();
                 }
 
-                requests.push(RequestKind::Nested(Directory {
+                let directory = RequestKind::Nested(Directory {
                     id: uuid::Uuid::new_v4().to_string(),
                     name: self.dir_name.clone(),
                     requests: Arc::new(RwLock::new(vec![])),
-                }));
+                    description: None,
+                });
+                let parent = self.parent_dir.as_ref().map(|(id, _)| id.as_str());
+                if let Err(directory) = tree::insert_entry(&mut requests, parent, directory) {
+                    requests.push(directory);
+                }
 
                 drop(store);
                 self.reset();
@@ -1,3 +1,4 @@
+use hac_core::collection::tree;
 use hac_core::collection::types::*;
 
 use super::request_form::FormField;
@@ -5,7 +6,7 @@ use super::request_form::RequestForm;
 use super::request_form::RequestFormCreate;
 use super::request_form::RequestFormEvent;
 use super::select_request_parent::{SelectRequestParent, SelectRequestParentEvent};
-use super::RequestFormTrait;
+use super::{hovered_directory, RequestFormTrait};
 use crate::ascii::LOGO_ASCII;
 use crate::pages::collection_viewer::collection_store::CollectionStore;
 use crate::pages::collection_viewer::collection_viewer::CollectionViewerOverlay;
@@ -40,6 +41,7 @@ impl<'rf> RequestForm<'rf, RequestFormCreate> {
         collection_store: Rc<RefCell<CollectionStore>>,
     ) -> Self {
         let logo_idx = rand::thread_rng().gen_range(0..LOGO_ASCII.len());
+        let parent_dir = hovered_directory(&collection_store.borrow());
 
         RequestForm {
             colors,
@@ -48,7 +50,7 @@ impl<'rf> RequestForm<'rf, RequestFormCreate> {
             logo_idx,
             request_name: String::default(),
             request_method: RequestMethod::Get,
-            parent_dir: None,
+            parent_dir,
             focused_field: FormField::Name,
             marker: std::marker::PhantomData,
             request: None,
@@ -76,15 +78,9 @@ impl Eventful for RequestForm<'_, RequestFormCreate> {
                         .requests
                         .as_ref()
                         .expect("tried to attach a parent to a request with empty collection");
-                    let dir_name = requests
-                        .read()
-                        .unwrap()
-                        .iter()
-                        .find(|req| req.get_id().eq(&dir_id))
-                        .as_ref()
+                    let dir_name = tree::directory_path(&requests.read().unwrap(), &dir_id)
                         // its safe to unwrap here as to have an id we for sure have the directory
-                        .unwrap()
-                        .get_name();
+                        .unwrap();
                     self.parent_dir = Some((dir_id, dir_name));
                     store.pop_overlay();
                 }
@@ -137,17 +133,12 @@ impl Eventful for RequestForm<'_, RequestFormCreate> {
                 method: self.request_method.clone(),
                 name: self.request_name.clone(),
                 uri: String::default(),
+                description: None,
             })));
 
-            if let Some((dir_id, _)) = self.parent_dir.as_ref() {
-                if let RequestKind::Nested(dir) = requests
-                    .iter_mut()
-                    .find(|req| req.get_id().eq(dir_id))
-                    .unwrap()
-                {
-                    dir.requests.write().unwrap().push(request);
-                }
-            } else {
+            let parent = self.parent_dir.as_ref().map(|(id, _)| id.as_str());
+            if let Err(request) = tree::insert_entry(&mut requests, parent, request) {
+                // the parent is gone, so we keep the request on the root instead
                 requests.push(request);
             }
 
@@ -204,19 +195,7 @@ impl Eventful for RequestForm<'_, RequestFormCreate> {
                         return Ok(None);
                     };
 
-                    let requests = requests.read().unwrap();
-                    if requests.is_empty() {
-                        drop(store);
-                        self.set_no_parent_timer();
-                        return Ok(None);
-                    }
-
-                    if requests
-                        .iter()
-                        .filter(|req| req.is_dir())
-                        .collect::<Vec<_>>()
-                        .is_empty()
-                    {
+                    if tree::directories(&requests.read().unwrap()).is_empty() {
                         drop(store);
                         self.set_no_parent_timer();
                         return Ok(None);
@@ -1,3 +1,5 @@
+use hac_core::collection::tree;
+
 use crate::ascii::LOGO_ASCII;
 use crate::pages::collection_viewer::collection_store::CollectionStore;
 use crate::pages::overlay::make_overlay;
@@ -57,11 +59,7 @@ impl Renderable for DeleteItemPrompt<'_> {
         let Some(ref requests) = collection.requests else {
             unreachable!();
         };
-        let is_dir = requests
-            .read()
-            .unwrap()
-            .iter()
-            .find(|req| req.get_id().eq(&hovered_id))
+        let is_dir = tree::find_entry(&requests.read().unwrap(), &hovered_id)
             .is_some_and(|req| req.is_dir());
 
         let mut lines = if is_dir {
@@ -40,6 +40,9 @@ pub struct DirectoryForm<'df, State = DirectoryFormCreate> {
     /// the id of the directory being edited, this is only used when editing a directory
     /// this is (dir_id, dir_name)
     pub directory: Option<(String, String)>,
+    /// directory the new directory is created inside of, this is only used when
+    /// creating a directory. this is (dir_id, dir_path)
+    pub parent_dir: Option<(String, String)>,
 
     pub marker: std::marker::PhantomData<State>,
 }
@@ -69,7 +72,7 @@ impl<State> Renderable for DirectoryForm<'_, State> {
                 .saturating_sub(logo_size.div(2))
                 .saturating_sub(2),
             50,
-            logo_size.add(4),
+            logo_size.add(5),
         );
 
         let logo = logo
@@ -80,9 +83,18 @@ impl<State> Renderable for DirectoryForm<'_, State> {
         let mut input = Input::new(self.colors, "Name".into());
         input.focus();
 
-        let hint = Line::from("[Confirm: Enter] [Cancel: Esc]")
-            .fg(self.colors.bright.black)
-            .centered();
+        let hint = match self.parent_dir {
+            Some(_) => "[Confirm: Enter] [Cancel: Esc] [Remove Parent: <C-p>]",
+            None => "[Confirm: Enter] [Cancel: Esc]",
+        };
+        let hint = Line::from(hint).fg(self.colors.bright.black).centered();
+        let parent = self.parent_dir.as_ref().map(|(_, dir_path)| {
+            Line::from(vec![
+                "Inside of ".fg(self.colors.bright.black),
+                dir_path.clone().fg(self.colors.normal.white),
+            ])
+            .centered()
+        });
 
         let logo_size = Rect::new(size.x, size.y, size.width, logo_size);
         let input_size = Rect::new(
@@ -91,10 +103,14 @@ impl<State> Renderable for DirectoryForm<'_, State> {
             size.width,
             3,
         );
-        let hint_size = Rect::new(size.x, input_size.y.add(4), size.width, 1);
+        let parent_size = Rect::new(size.x, input_size.y.add(3), size.width, 1);
+        let hint_size = Rect::new(size.x, input_size.y.add(5), size.width, 1);
 
         frame.render_widget(Paragraph::new(logo), logo_size);
         frame.render_stateful_widget(input, input_size, &mut self.dir_name);
+        if let Some(parent) = parent {
+            frame.render_widget(parent, parent_size);
+        }
         frame.render_widget(hint, hint_size);
 
         frame.set_cursor(
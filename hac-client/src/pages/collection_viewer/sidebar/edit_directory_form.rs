@@ -1,3 +1,4 @@
+use hac_core::collection::tree;
 use hac_core::collection::types::*;
 
 use super::directory_form::{DirectoryForm, DirectoryFormEdit, DirectoryFormEvent};
@@ -32,6 +33,7 @@ impl<'df> DirectoryForm<'df, DirectoryFormEdit> {
             logo_idx,
             marker: std::marker::PhantomData,
             directory,
+            parent_dir: None,
         }
     }
 }
@@ -68,12 +70,12 @@ impl Eventful for DirectoryForm<'_, DirectoryFormEdit> {
                     self.dir_name = "unnamed directory".into();
                 }
 
-                if let Some(RequestKind::Nested(dir)) = requests
-                    .iter_mut()
-                    .find(|req| req.get_id().eq(&self.directory.as_ref().unwrap().0))
-                {
-                    dir.name.clone_from(&self.dir_name);
-                }
+                let dir_id = &self.directory.as_ref().unwrap().0;
+                tree::update_entry(&mut requests, dir_id, |item| {
+                    if let RequestKind::Nested(dir) = item {
+                        dir.name.clone_from(&self.dir_name);
+                    }
+                });
 
                 drop(store);
                 self.reset();
@@ -1,3 +1,4 @@
+use hac_core::collection::tree;
 use hac_core::collection::types::*;
 
 use super::request_form::FormField;
@@ -55,15 +56,8 @@ impl<'rf> RequestForm<'rf, RequestFormEdit> {
             };
 
             let dir_id = request.read().unwrap().parent.clone().unwrap();
-            let dir_name = requests
-                .read()
-                .unwrap()
-                .iter()
-                .find(|req| req.get_id().eq(&dir_id))
-                .unwrap()
-                .get_name();
-
-            Some((dir_id, dir_name))
+            let dir_name = tree::directory_path(&requests.read().unwrap(), &dir_id);
+            dir_name.map(|dir_name| (dir_id, dir_name))
         } else {
             None
         };
@@ -102,15 +96,9 @@ impl Eventful for RequestForm<'_, RequestFormEdit> {
                         .requests
                         .as_ref()
                         .expect("tried to attach a parent to a request with empty collection");
-                    let dir_name = requests
-                        .read()
-                        .unwrap()
-                        .iter()
-                        .find(|req| req.get_id().eq(&dir_id))
-                        .as_ref()
+                    let dir_name = tree::directory_path(&requests.read().unwrap(), &dir_id)
                         // its safe to unwrap here as to have an id we for sure have the directory
-                        .unwrap()
-                        .get_name();
+                        .unwrap();
                     tracing::debug!("before: {:?}", self.parent_dir);
                     self.parent_dir = Some((dir_id, dir_name));
                     store.pop_overlay();
@@ -140,8 +128,10 @@ impl Eventful for RequestForm<'_, RequestFormEdit> {
 
             request.name.clone_from(&self.request_name);
             request.method.clone_from(&self.request_method);
+            let previous_parent = request.parent.clone();
             request.parent = self.parent_dir.as_ref().map(|(id, _)| id.clone());
             let request_id = request.id.clone();
+            let parent = request.parent.clone();
 
             drop(request);
 
@@ -155,26 +145,15 @@ impl Eventful for RequestForm<'_, RequestFormEdit> {
                 .get_or_insert(Arc::new(RwLock::new(vec![])));
             let mut requests = requests.write().unwrap();
 
-            requests.iter_mut().for_each(|req| {
-                if let RequestKind::Nested(dir) = req {
-                    dir.requests
-                        .write()
-                        .unwrap()
-                        .retain(|req| req.get_id().ne(&request_id));
-                }
-            });
-
-            if let Some((dir_id, _)) = self.parent_dir.as_ref() {
-                if let RequestKind::Nested(dir) = requests
-                    .iter_mut()
-                    .find(|req| req.get_id().eq(dir_id))
-                    .unwrap()
-                {
-                    let request = self.request.as_ref().unwrap().clone();
-                    dir.requests
-                        .write()
-                        .unwrap()
-                        .push(RequestKind::Single(request));
+            // requests only move when their parent changes, so editing anything
+            // else keeps them where they are
+            if previous_parent.ne(&parent) {
+                if let Some(request) = tree::remove_entry(&mut requests, &request_id) {
+                    if let Err(request) =
+                        tree::insert_entry(&mut requests, parent.as_deref(), request)
+                    {
+                        requests.push(request);
+                    }
                 }
             }
 
@@ -236,19 +215,7 @@ impl Eventful for RequestForm<'_, RequestFormEdit> {
                         return Ok(None);
                     };
 
-                    let requests = requests.read().unwrap();
-                    if requests.is_empty() {
-                        drop(store);
-                        self.set_no_parent_timer();
-                        return Ok(None);
-                    }
-
-                    if requests
-                        .iter()
-                        .filter(|req| req.is_dir())
-                        .collect::<Vec<_>>()
-                        .is_empty()
-                    {
+                    if tree::directories(&requests.read().unwrap()).is_empty() {
                         drop(store);
                         self.set_no_parent_timer();
                         return Ok(None);
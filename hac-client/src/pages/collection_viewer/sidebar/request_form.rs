@@ -164,21 +164,21 @@ impl<'rf, State> Renderable for RequestForm<'rf, State> {
             .constraints((0..5).map(|_| Constraint::Length(13)))
             .split(methods_size);
 
-        let parent_name = if self.parent_dir.is_none() {
+        let parent_name = if let Some((_, parent_name)) = self.parent_dir.as_ref() {
             format!(
-                "{}None{}",
+                "{}{}{}",
                 " ".repeat(parent_size.width.div(2).sub(2).into()),
+                parent_name,
                 " ".repeat(parent_size.width.div(2).sub(2).into())
             )
-            .fg(self.colors.bright.black)
+            .fg(self.colors.normal.white)
         } else {
             format!(
-                "{}{}{}",
+                "{}None{}",
                 " ".repeat(parent_size.width.div(2).sub(2).into()),
-                self.parent_dir.as_ref().unwrap().1.clone(),
                 " ".repeat(parent_size.width.div(2).sub(2).into())
             )
-            .fg(self.colors.normal.white)
+            .fg(self.colors.bright.black)
         };
         let parent = Paragraph::new(parent_name).centered().block(
             Block::default()
@@ -1,3 +1,6 @@
+use hac_core::collection::tree;
+use hac_core::collection::types::Directory;
+
 use crate::ascii::LOGO_ASCII;
 use crate::pages::collection_viewer::collection_store::CollectionStore;
 use crate::pages::overlay::make_overlay;
@@ -35,6 +38,22 @@ pub struct SelectRequestParent<'srp> {
 }
 
 impl<'srp> SelectRequestParent<'srp> {
+    /// every directory of the collection, at any depth, in the order they are
+    /// displayed on the sidebar, along with how deep they are
+    fn directories(&self) -> Vec<(Directory, usize)> {
+        let store = self.collection_store.borrow();
+        let collection = store
+            .get_collection()
+            .expect("trying to select a parent directory without a collection");
+
+        let collection = collection.borrow();
+        collection
+            .requests
+            .as_ref()
+            .map(|requests| tree::directories(&requests.read().unwrap()))
+            .unwrap_or_default()
+    }
+
     pub fn new(
         colors: &'srp hac_colors::Colors,
         collection_store: Rc<RefCell<CollectionStore>>,
@@ -53,20 +72,7 @@ impl Renderable for SelectRequestParent<'_> {
     fn draw(&mut self, frame: &mut Frame, _: Rect) -> anyhow::Result<()> {
         make_overlay(self.colors, self.colors.normal.black, 0.1, frame);
 
-        let store = self.collection_store.borrow();
-        let collection = store
-            .get_collection()
-            .expect("trying to select a parent directory without a collection");
-
-        let mut directories = vec![];
-        if let Some(ref requests) = collection.borrow().requests {
-            requests
-                .read()
-                .unwrap()
-                .iter()
-                .filter(|req| req.is_dir())
-                .for_each(|dir| directories.push(dir.get_name()));
-        };
+        let directories = self.directories();
 
         let mut logo = LOGO_ASCII[self.logo_idx];
         let size = frame.size();
@@ -100,15 +106,25 @@ impl Renderable for SelectRequestParent<'_> {
         let amount_on_view = remaining_space.div(item_height);
         let dirs_start_y = logo_size.add(3);
 
+        // keeps the selected directory on view when there are more directories
+        // than what fits on the screen
+        let amount_on_view = usize::max(amount_on_view.into(), 1);
+        if self.selected_dir.lt(&self.scroll) {
+            self.scroll = self.selected_dir;
+        }
+        if self.selected_dir.ge(&self.scroll.add(amount_on_view)) {
+            self.scroll = self.selected_dir.add(1).sub(amount_on_view);
+        }
+
         let header = Paragraph::new("Available directories".fg(self.colors.normal.yellow).bold());
         let header_size = Rect::new(size.x, size.y.add(logo_size).add(1), size.width, 2);
         frame.render_widget(header, header_size);
 
-        for (idx, dir) in directories
+        for (idx, (dir, depth)) in directories
             .into_iter()
             .enumerate()
             .skip(self.scroll)
-            .take(amount_on_view.into())
+            .take(amount_on_view)
         {
             let foreground = if self.selected_dir.eq(&idx) {
                 self.colors.normal.red
@@ -117,11 +133,14 @@ impl Renderable for SelectRequestParent<'_> {
             };
             let dir_size = Rect::new(
                 size.x,
-                size.y.add(dirs_start_y).add(idx.mul(2) as u16),
+                size.y
+                    .add(dirs_start_y)
+                    .add(idx.sub(self.scroll).mul(2) as u16),
                 size.width,
                 2,
             );
-            let dir = Paragraph::new(dir.fg(foreground));
+            let name = format!("{}{}/", "  ".repeat(depth), dir.name);
+            let dir = Paragraph::new(name.fg(foreground));
             frame.render_widget(dir, dir_size);
         }
 
@@ -137,36 +156,22 @@ impl Eventful for SelectRequestParent<'_> {
             return Ok(Some(SelectRequestParentEvent::Cancel));
         }
 
-        let store = self.collection_store.borrow();
-        let collection = store
-            .get_collection()
-            .expect("trying to select a parent directory without a collection");
-
-        let mut directories = vec![];
-        if let Some(ref requests) = collection.borrow().requests {
-            requests
-                .read()
-                .unwrap()
-                .iter()
-                .filter(|req| req.is_dir())
-                .for_each(|dir| directories.push(dir.get_id()))
-        };
+        let directories = self.directories();
         let total_dirs = directories.len();
 
         match key_event.code {
             KeyCode::Enter => {
-                let (_, dir_id) = directories
-                    .into_iter()
-                    .enumerate()
-                    .find(|(idx, _)| idx.eq(&self.selected_dir))
-                    .unwrap();
-                return Ok(Some(SelectRequestParentEvent::Confirm(dir_id)));
+                let Some((dir, _)) = directories.into_iter().nth(self.selected_dir) else {
+                    return Ok(Some(SelectRequestParentEvent::Cancel));
+                };
+                return Ok(Some(SelectRequestParentEvent::Confirm(dir.id)));
             }
             KeyCode::Esc => {
                 return Ok(Some(SelectRequestParentEvent::Cancel));
             }
             KeyCode::Down | KeyCode::Tab | KeyCode::Char('j') => {
-                self.selected_dir = usize::min(self.selected_dir.add(1), total_dirs.sub(1));
+                self.selected_dir =
+                    usize::min(self.selected_dir.add(1), total_dirs.saturating_sub(1));
             }
             KeyCode::Up | KeyCode::BackTab | KeyCode::Char('k') => {
                 self.selected_dir = self.selected_dir.saturating_sub(1);
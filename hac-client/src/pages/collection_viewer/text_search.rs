@@ -0,0 +1,227 @@
+use hac_core::text_object::{find_closest_match, SearchDirection, SearchMatch, TextObject};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+
+#[derive(Debug, PartialEq)]
+pub enum TextSearchEvent {
+    /// the pattern changed while typing, components should jump to the closest
+    /// match from where the search started
+    Changed,
+    /// the search was confirmed and the pattern is kept for `n` and `N`
+    Confirmed,
+    /// the search was cancelled, components should go back to where the search
+    /// started
+    Cancelled,
+}
+
+/// state of a `/` or `?` search on a text component. The prompt itself is drawn
+/// by the owning component, usually on its statusline, as each component has a
+/// different place to show it
+#[derive(Debug, Clone)]
+pub struct TextSearch {
+    pattern: String,
+    /// pattern before the prompt was opened, restored if the search is cancelled
+    previous_pattern: String,
+    direction: SearchDirection,
+    matches: Vec<SearchMatch>,
+    is_prompting: bool,
+}
+
+impl Default for TextSearch {
+    fn default() -> Self {
+        TextSearch {
+            pattern: String::default(),
+            previous_pattern: String::default(),
+            direction: SearchDirection::Forward,
+            matches: vec![],
+            is_prompting: false,
+        }
+    }
+}
+
+impl TextSearch {
+    pub fn open(&mut self, direction: SearchDirection) {
+        self.previous_pattern = std::mem::take(&mut self.pattern);
+        self.direction = direction;
+        self.is_prompting = true;
+    }
+
+    pub fn is_prompting(&self) -> bool {
+        self.is_prompting
+    }
+
+    pub fn matches(&self) -> &[SearchMatch] {
+        &self.matches
+    }
+
+    pub fn update_matches<State>(&mut self, text: &TextObject<State>) {
+        self.matches = text.find_matches(&self.pattern);
+    }
+
+    /// finds the closest match to `position`, `reverse` searches on the opposite
+    /// direction of the original search, like `N` does
+    pub fn closest_match(&self, position: (usize, usize), reverse: bool) -> Option<&SearchMatch> {
+        let direction = match reverse {
+            true => self.direction.reverse(),
+            false => self.direction,
+        };
+        find_closest_match(&self.matches, position, direction)
+    }
+
+    pub fn handle_key_event<State>(
+        &mut self,
+        key_event: KeyEvent,
+        text: &TextObject<State>,
+    ) -> Option<TextSearchEvent> {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) | (KeyCode::Esc, _) => {
+                self.is_prompting = false;
+                self.pattern = std::mem::take(&mut self.previous_pattern);
+                self.update_matches(text);
+                Some(TextSearchEvent::Cancelled)
+            }
+            (KeyCode::Enter, _) => {
+                self.is_prompting = false;
+                // confirming an empty search repeats the last one, just like vim
+                if self.pattern.is_empty() {
+                    self.pattern = std::mem::take(&mut self.previous_pattern);
+                }
+                self.update_matches(text);
+                Some(TextSearchEvent::Confirmed)
+            }
+            (KeyCode::Backspace, _) if self.pattern.is_empty() => {
+                self.is_prompting = false;
+                self.pattern = std::mem::take(&mut self.previous_pattern);
+                self.update_matches(text);
+                Some(TextSearchEvent::Cancelled)
+            }
+            (KeyCode::Backspace, _) => {
+                self.pattern.pop();
+                self.update_matches(text);
+                Some(TextSearchEvent::Changed)
+            }
+            (KeyCode::Char(c), _) => {
+                self.pattern.push(c);
+                self.update_matches(text);
+                Some(TextSearchEvent::Changed)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn prompt_line(&self, colors: &hac_colors::Colors) -> Line<'static> {
+        let prefix = match self.direction {
+            SearchDirection::Forward => "/",
+            SearchDirection::Backward => "?",
+        };
+
+        Line::from(vec![
+            prefix.fg(colors.bright.black),
+            self.pattern.clone().fg(colors.normal.white),
+        ])
+    }
+
+    /// length of the prompt, used by components to position the cursor at the end
+    /// of it while typing
+    pub fn prompt_len(&self) -> usize {
+        self.pattern.chars().count().saturating_add(1)
+    }
+
+    pub fn match_style(colors: &hac_colors::Colors) -> Style {
+        Style::default()
+            .fg(colors.normal.black)
+            .bg(colors.normal.yellow)
+    }
+}
+
+/// applies `style` over the chars of `line` that are within any of the given
+/// `(col, len)` ranges, splitting spans as needed while keeping their original style
+pub fn highlight_ranges(
+    line: Line<'static>,
+    ranges: &[(usize, usize)],
+    style: Style,
+) -> Line<'static> {
+    if ranges.is_empty() {
+        return line;
+    }
+
+    let is_highlighted = |col: usize| {
+        ranges
+            .iter()
+            .any(|(start, len)| col.ge(start) && col.lt(&start.saturating_add(*len)))
+    };
+
+    let mut spans = vec![];
+    let mut col = 0;
+    for span in line.spans.into_iter() {
+        let mut current = String::default();
+        let mut current_highlighted = is_highlighted(col);
+
+        for c in span.content.chars() {
+            let highlighted = is_highlighted(col);
+            if highlighted.ne(&current_highlighted) && !current.is_empty() {
+                let span_style = match current_highlighted {
+                    true => span.style.patch(style),
+                    false => span.style,
+                };
+                spans.push(Span::styled(std::mem::take(&mut current), span_style));
+            }
+            current_highlighted = highlighted;
+            current.push(c);
+            col += 1;
+        }
+
+        if !current.is_empty() {
+            let span_style = match current_highlighted {
+                true => span.style.patch(style),
+                false => span.style,
+            };
+            spans.push(Span::styled(current, span_style));
+        }
+    }
+
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_ranges_splits_spans() {
+        let line = Line::from(vec![Span::from("\"name\""), Span::from(": 1")]);
+        let style = Style::default().bg(ratatui::style::Color::Yellow);
+
+        let line = highlight_ranges(line, &[(1, 4)], style);
+
+        let contents = line
+            .spans
+            .iter()
+            .map(|span| span.content.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(contents, vec!["\"", "name", "\"", ": 1"]);
+        assert_eq!(line.spans[1].style, style);
+        assert_eq!(line.spans[0].style, Style::default());
+    }
+
+    #[test]
+    fn test_search_is_restored_on_cancel() {
+        let text = TextObject::from("abc\nabc");
+        let mut search = TextSearch::default();
+
+        search.open(SearchDirection::Forward);
+        search.handle_key_event(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::NONE), &text);
+        search.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE), &text);
+        assert_eq!(search.matches().len(), 2);
+
+        search.open(SearchDirection::Backward);
+        search.handle_key_event(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE), &text);
+        assert_eq!(search.matches().len(), 0);
+        let event = search.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE), &text);
+        assert_eq!(event, Some(TextSearchEvent::Cancelled));
+        assert_eq!(search.matches().len(), 2);
+        assert!(!search.is_prompting());
+    }
+}
@@ -1,6 +1,7 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect};
 use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph, Widget, Wrap};
 
 #[derive(Debug, PartialEq)]
@@ -20,8 +21,12 @@ impl<'a> ErrorPopup<'a> {
     }
 
     fn build_popup(&self) -> (Paragraph<'_>, Paragraph<'_>) {
-        let message = Paragraph::new(self.message.clone().fg(self.colors.normal.red))
-            .wrap(Wrap { trim: true });
+        let lines = self
+            .message
+            .lines()
+            .map(|line| Line::from(line.to_string().fg(self.colors.normal.red)))
+            .collect::<Vec<_>>();
+        let message = Paragraph::new(lines).wrap(Wrap { trim: true });
 
         let confirmation = Paragraph::new("(O)k".fg(self.colors.normal.green).into_centered_line())
             .wrap(Wrap { trim: true });
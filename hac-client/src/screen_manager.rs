@@ -1,3 +1,4 @@
+use hac_core::collection::CollectionLoadError;
 use hac_core::{collection::Collection, command::Command};
 
 use crate::event_pool::Event;
@@ -58,7 +59,7 @@ impl<'sm> ScreenManager<'sm> {
             prev_screen: Screens::CollectionDashboard,
             collection_viewer: None,
             terminal_too_small: TerminalTooSmall::new(colors),
-            collection_list: CollectionDashboard::new(size, colors, collections, dry_run)?,
+            collection_list: CollectionDashboard::new(size, colors, collections, config, dry_run)?,
             collection_store: Rc::new(RefCell::new(CollectionStore::default())),
             size,
             colors,
@@ -80,6 +81,11 @@ impl<'sm> ScreenManager<'sm> {
         self.curr_screen = screen;
     }
 
+    pub fn display_broken_collections(&mut self, broken_collections: Vec<CollectionLoadError>) {
+        self.collection_list
+            .display_broken_collections(broken_collections);
+    }
+
     // events can generate commands, which are sent back to the top level event loop through this
     // channel, and goes back down the chain of components as many components may be interested
     // in such command
@@ -108,6 +114,27 @@ impl<'sm> ScreenManager<'sm> {
             Command::Error(msg) => {
                 self.collection_list.display_error(msg);
             }
+            Command::ExternalEditorClosed(path) => match self.curr_screen {
+                Screens::CollectionDashboard => {
+                    self.collection_list.reload_broken_collection(&path)
+                }
+                _ => {
+                    if let Some(collection_viewer) = self.collection_viewer.as_mut() {
+                        collection_viewer.reload_body_from_file(&path);
+                    }
+                }
+            },
+            Command::CollectionSynced(hash) => {
+                if let Some(collection_viewer) = self.collection_viewer.as_mut() {
+                    collection_viewer.finish_sync(hash, true);
+                }
+            }
+            Command::CollectionSyncFailed(hash, msg) => {
+                if let Some(collection_viewer) = self.collection_viewer.as_mut() {
+                    collection_viewer.finish_sync(hash, false);
+                }
+                self.collection_list.display_error(msg);
+            }
             _ => {}
         }
     }
@@ -228,7 +255,7 @@ mod tests {
         let colors = hac_colors::Colors::default();
         let (_guard, path) = setup_temp_collections(10);
         let collections = collection::collection::get_collections(path).unwrap();
-        let config = hac_config::load_config();
+        let config = hac_config::load_default_config();
         let mut sm =
             ScreenManager::new(small_in_width, &colors, collections, &config, false).unwrap();
         let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
@@ -247,7 +274,7 @@ mod tests {
         let colors = hac_colors::Colors::default();
         let (_guard, path) = setup_temp_collections(10);
         let collections = collection::collection::get_collections(path).unwrap();
-        let config = hac_config::load_config();
+        let config = hac_config::load_default_config();
         let mut sm = ScreenManager::new(small, &colors, collections, &config, false).unwrap();
         let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
 
@@ -269,7 +296,7 @@ mod tests {
         let colors = hac_colors::Colors::default();
         let (_guard, path) = setup_temp_collections(10);
         let collection = collection::collection::get_collections(path).unwrap();
-        let config = hac_config::load_config();
+        let config = hac_config::load_default_config();
         let mut sm = ScreenManager::new(initial, &colors, collection, &config, false).unwrap();
 
         sm.resize(expected);
@@ -282,17 +309,20 @@ mod tests {
         let initial = Rect::new(0, 0, 80, 22);
         let colors = hac_colors::Colors::default();
         let collection = Collection {
+            version: hac_core::collection::COLLECTION_VERSION,
             info: Info {
                 name: String::from("any_name"),
                 description: None,
             },
+            environment: Default::default(),
             path: "any_path".into(),
+            layout: hac_config::CollectionLayout::File,
             requests: None,
         };
         let command = Command::SelectCollection(collection.clone());
         let (_guard, path) = setup_temp_collections(10);
         let collection = collection::collection::get_collections(path).unwrap();
-        let config = hac_config::load_config();
+        let config = hac_config::load_default_config();
         let (tx, _) = tokio::sync::mpsc::unbounded_channel::<Command>();
         let mut sm = ScreenManager::new(initial, &colors, collection, &config, false).unwrap();
         _ = sm.register_command_handler(tx.clone());
@@ -308,7 +338,7 @@ mod tests {
         let colors = hac_colors::Colors::default();
         let (_guard, path) = setup_temp_collections(10);
         let collections = collection::collection::get_collections(path).unwrap();
-        let config = hac_config::load_config();
+        let config = hac_config::load_default_config();
         let mut sm = ScreenManager::new(initial, &colors, collections, &config, false).unwrap();
 
         let (tx, _) = tokio::sync::mpsc::unbounded_channel::<Command>();
@@ -324,7 +354,7 @@ mod tests {
         let colors = hac_colors::Colors::default();
         let (_guard, path) = setup_temp_collections(10);
         let collections = collection::collection::get_collections(path).unwrap();
-        let config = hac_config::load_config();
+        let config = hac_config::load_default_config();
         let mut sm = ScreenManager::new(initial, &colors, collections, &config, false).unwrap();
 
         let event = Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
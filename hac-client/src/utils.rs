@@ -1,7 +1,10 @@
+use hac_config::{Keymap, UiAction};
 use hac_core::syntax::highlighter::HIGHLIGHTER;
 
 use std::ops::Add;
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::Rect;
 use ratatui::style::{Color, Stylize};
 use ratatui::text::{Line, Span};
 use tree_sitter::Tree;
@@ -222,6 +225,64 @@ fn ansi_to_rgb(val: u8) -> Option<(u8, u8, u8)> {
     }
 }
 
+/// the key bound to `action` on `keymap`, so hints show whatever the user
+/// configured. The shortest key wins, like `j` over `Down`
+pub fn key_for(keymap: &Keymap, action: UiAction) -> Option<&str> {
+    keymap
+        .iter()
+        .filter(|(_, bound)| bound.eq(&&action))
+        .map(|(key, _)| key.as_str())
+        .min_by_key(|key| (key.len(), *key))
+}
+
+/// formats a key event the same way keys are written on the configuration file,
+/// like `j`, `S-G`, `C-d` or `Enter`, so it can be looked up on keymaps
+pub fn keycode_as_string(key_event: KeyEvent) -> String {
+    match (key_event.code, key_event.modifiers) {
+        // some terminals don't report shift for uppercase letters
+        (KeyCode::Char(c), KeyModifiers::NONE) if c.is_uppercase() => format!("S-{}", c),
+        (KeyCode::Char(c), KeyModifiers::NONE) => c.into(),
+        // symbols already carry the shift on them, like `$` or `?`
+        (KeyCode::Char(c), KeyModifiers::SHIFT) if !c.is_alphabetic() => c.into(),
+        (KeyCode::Char(c), KeyModifiers::SHIFT) => format!("S-{}", c),
+        (KeyCode::Char(c), KeyModifiers::CONTROL) => format!("C-{}", c),
+        (KeyCode::Backspace, _) => "Backspace".into(),
+        (KeyCode::Left, _) => "Left".into(),
+        (KeyCode::Down, _) => "Down".into(),
+        (KeyCode::Up, _) => "Up".into(),
+        (KeyCode::Right, _) => "Right".into(),
+        (KeyCode::Home, _) => "Home".into(),
+        (KeyCode::End, _) => "End".into(),
+        (KeyCode::Enter, _) => "Enter".into(),
+        (KeyCode::Tab, _) => "Tab".into(),
+        (KeyCode::BackTab, _) => "BackTab".into(),
+        (KeyCode::Delete, _) => "Delete".into(),
+        (KeyCode::PageUp, _) => "PageUp".into(),
+        (KeyCode::PageDown, _) => "PageDown".into(),
+        (KeyCode::Esc, _) => "Esc".into(),
+        _ => String::default(),
+    }
+}
+
+/// finds which tab is under `column` when the titles are rendered with ratatui's
+/// `Tabs` widget on `area`, using its default padding of one space on each side
+/// and a single column divider between tabs
+pub fn tab_at_column(titles: &[&str], area: Rect, column: u16) -> Option<usize> {
+    let mut start = area.x;
+
+    for (idx, title) in titles.iter().enumerate() {
+        // one column of padding on each side of the title
+        let end = start.add(title.chars().count() as u16).add(2);
+        if column.ge(&start) && column.lt(&end) {
+            return Some(idx);
+        }
+        // skip the divider
+        start = end.add(1);
+    }
+
+    None
+}
+
 pub trait EnumIter {
     fn iter() -> &'static [Self]
     where
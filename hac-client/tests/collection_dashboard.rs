@@ -49,8 +49,9 @@ fn get_rendered_from_buffer(frame: &mut Frame, size: Rect) -> Vec<String> {
 #[test]
 fn test_draw_empty_message() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
-    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -78,10 +79,12 @@ fn test_draw_empty_message() {
 #[test]
 fn test_draw_no_matches_message() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
     let (_guard, path) = setup_temp_collections(3);
     let collections = collection::collection::get_collections(path).unwrap();
-    let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+    let mut dashboard =
+        CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -128,10 +131,12 @@ fn test_draw_no_matches_message() {
 #[test]
 fn draw_hint_text() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
     let (_guard, path) = setup_temp_collections(3);
     let collections = collection::collection::get_collections(path).unwrap();
-    let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+    let mut dashboard =
+        CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -148,10 +153,12 @@ fn draw_hint_text() {
 #[test]
 fn draw_filter_prompt() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
     let (_guard, path) = setup_temp_collections(3);
     let collections = collection::collection::get_collections(path).unwrap();
-    let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+    let mut dashboard =
+        CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
     let expected =
@@ -184,8 +191,9 @@ fn draw_filter_prompt() {
 #[test]
 fn test_draw_title() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
-    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -213,8 +221,9 @@ fn test_draw_title() {
 #[test]
 fn test_draw_error() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
-    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -259,8 +268,9 @@ fn test_draw_error() {
 #[test]
 fn test_draw_help() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
-    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -275,18 +285,18 @@ fn test_draw_help() {
         "                k/<up>      - select item above                                 ",
         "                l/<right>   - select right item                                 ",
         "                n/c         - creates a new collection                          ",
-        "            █▖▐▌d           - deletes the selected collection                   ",
-        "            █▜▟▌?           - toggle this help window█  ▟▀▙ █▀▙ ▟▀▀             ",
-        "            █ ▜▌enter       - select item under cursor  █ █ █ █ ▝▀▙             ",
-        "            ▀ ▝▘/           - enter filter mode  ▝▘ ▝▀▘ ▝▀▘ ▀ ▀ ▀▀▘             ",
+        "            █▖▐▌i           - imports an OpenAPI spec or HAR                    ",
+        "            █▜▟▌d           - deletes the selected collection▀▙ ▟▀▀             ",
+        "            █ ▜▌?           - toggle this help window█  █ █ █ █ ▝▀▙             ",
+        "            ▀ ▝▘enter       - select item under cursor▘ ▝▀▘ ▀ ▀ ▀▀▘             ",
+        "                /           - enter filter mode                                 ",
+        "                !           - list broken collections                           ",
         "                <C-c>       - quits the application                             ",
         "                                                                                ",
         "                           press any key to go back                             ",
         "                                                                                ",
         "                                                                                ",
         "                                                                                ",
-        "                                                                                ",
-        "                                                                                ",
     ];
 
     feed_keys(
@@ -308,8 +318,9 @@ fn test_draw_help() {
 #[test]
 fn test_draw_form_popup() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
-    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], false).unwrap();
+    let mut dashboard = CollectionDashboard::new(size, &colors, vec![], &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -359,10 +370,12 @@ fn test_draw_form_popup() {
 #[test]
 fn test_draw_delete_prompt() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
     let (_guard, path) = setup_temp_collections(3);
     let collections = collection::collection::get_collections(path).unwrap();
-    let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+    let mut dashboard =
+        CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
@@ -412,10 +425,12 @@ fn test_draw_delete_prompt() {
 #[test]
 fn test_draw_collections_list() {
     let colors = hac_colors::Colors::default();
+    let config = hac_config::load_default_config();
     let size = Rect::new(0, 0, 80, 22);
     let (_guard, path) = setup_temp_collections(3);
     let collections = collection::collection::get_collections(path).unwrap();
-    let mut dashboard = CollectionDashboard::new(size, &colors, collections, false).unwrap();
+    let mut dashboard =
+        CollectionDashboard::new(size, &colors, collections, &config, false).unwrap();
     let mut terminal = Terminal::new(TestBackend::new(80, 22)).unwrap();
     let mut frame = terminal.get_frame();
 
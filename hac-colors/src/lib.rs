@@ -1,3 +1,5 @@
 pub mod colors;
+pub mod theme;
 
 pub use colors::Colors;
+pub use theme::load_theme;
@@ -0,0 +1,303 @@
+use crate::colors::{BrightColors, Colors, NormalColors};
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Context;
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+/// representation of a theme file. Every field is optional, so a theme only has
+/// to specify the colors it wants to change, anything else keeps the default
+///
+/// colors can be written as hex (`#c4746e`), a color name (`red`, `light-blue`)
+/// or an ansi index (`8`), the same way ratatui parses them
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    pub primary: PrimaryTheme,
+    pub normal: PaletteTheme,
+    pub bright: PaletteTheme,
+    /// styles for tree-sitter highlight groups, like `string` or `property`
+    pub tokens: BTreeMap<String, TokenTheme>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PrimaryTheme {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreground: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hover: Option<String>,
+}
+
+/// both the normal and bright palettes have the same set of colors
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaletteTheme {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub black: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub red: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub green: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yellow: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub magenta: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orange: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub white: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TokenTheme {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bg: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub bold: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub italic: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub underlined: bool,
+}
+
+/// reads a theme file and builds the colors from it, falling back to the default
+/// colors for everything the theme doesn't specify
+pub fn load_theme<P>(path: P) -> anyhow::Result<Colors>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let theme = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read theme file at {}", path.display()))?;
+    Colors::from_theme_str(&theme).with_context(|| format!("invalid theme at {}", path.display()))
+}
+
+impl Colors {
+    pub fn from_theme_str(theme: &str) -> anyhow::Result<Colors> {
+        let theme = toml::from_str::<Theme>(theme)?;
+        let mut colors = Colors::default();
+
+        apply_color(
+            &mut colors.primary.foreground,
+            &theme.primary.foreground,
+            "primary.foreground",
+        )?;
+        apply_color(
+            &mut colors.primary.background,
+            &theme.primary.background,
+            "primary.background",
+        )?;
+        apply_color(
+            &mut colors.primary.accent,
+            &theme.primary.accent,
+            "primary.accent",
+        )?;
+        apply_color(
+            &mut colors.primary.hover,
+            &theme.primary.hover,
+            "primary.hover",
+        )?;
+        apply_normal_palette(&mut colors.normal, &theme.normal)?;
+        apply_bright_palette(&mut colors.bright, &theme.bright)?;
+
+        for (name, token) in theme.tokens.iter() {
+            let style = token_style(token).with_context(|| format!("at `tokens.{name}`"))?;
+            colors.tokens.insert(name.clone(), style);
+        }
+
+        Ok(colors)
+    }
+
+    /// serializes every color into a theme file, which is a good starting point
+    /// for writing a new theme
+    pub fn to_theme_string(&self) -> String {
+        let palette = |colors: [&Color; 8]| {
+            let [black, red, green, yellow, blue, magenta, orange, white] =
+                colors.map(|color| Some(color.to_string()));
+            PaletteTheme {
+                black,
+                red,
+                green,
+                yellow,
+                blue,
+                magenta,
+                orange,
+                white,
+            }
+        };
+
+        let theme = Theme {
+            primary: PrimaryTheme {
+                foreground: Some(self.primary.foreground.to_string()),
+                background: Some(self.primary.background.to_string()),
+                accent: Some(self.primary.accent.to_string()),
+                hover: Some(self.primary.hover.to_string()),
+            },
+            normal: palette(normal_colors(&self.normal)),
+            bright: palette(bright_colors(&self.bright)),
+            tokens: self
+                .tokens
+                .iter()
+                .map(|(name, style)| (name.clone(), style_token(style)))
+                .collect(),
+        };
+
+        toml::to_string(&theme).expect("theme should always be serializable")
+    }
+}
+
+fn normal_colors(colors: &NormalColors) -> [&Color; 8] {
+    [
+        &colors.black,
+        &colors.red,
+        &colors.green,
+        &colors.yellow,
+        &colors.blue,
+        &colors.magenta,
+        &colors.orange,
+        &colors.white,
+    ]
+}
+
+fn bright_colors(colors: &BrightColors) -> [&Color; 8] {
+    [
+        &colors.black,
+        &colors.red,
+        &colors.green,
+        &colors.yellow,
+        &colors.blue,
+        &colors.magenta,
+        &colors.orange,
+        &colors.white,
+    ]
+}
+
+fn apply_normal_palette(colors: &mut NormalColors, theme: &PaletteTheme) -> anyhow::Result<()> {
+    apply_color(&mut colors.black, &theme.black, "normal.black")?;
+    apply_color(&mut colors.red, &theme.red, "normal.red")?;
+    apply_color(&mut colors.green, &theme.green, "normal.green")?;
+    apply_color(&mut colors.yellow, &theme.yellow, "normal.yellow")?;
+    apply_color(&mut colors.blue, &theme.blue, "normal.blue")?;
+    apply_color(&mut colors.magenta, &theme.magenta, "normal.magenta")?;
+    apply_color(&mut colors.orange, &theme.orange, "normal.orange")?;
+    apply_color(&mut colors.white, &theme.white, "normal.white")?;
+    Ok(())
+}
+
+fn apply_bright_palette(colors: &mut BrightColors, theme: &PaletteTheme) -> anyhow::Result<()> {
+    apply_color(&mut colors.black, &theme.black, "bright.black")?;
+    apply_color(&mut colors.red, &theme.red, "bright.red")?;
+    apply_color(&mut colors.green, &theme.green, "bright.green")?;
+    apply_color(&mut colors.yellow, &theme.yellow, "bright.yellow")?;
+    apply_color(&mut colors.blue, &theme.blue, "bright.blue")?;
+    apply_color(&mut colors.magenta, &theme.magenta, "bright.magenta")?;
+    apply_color(&mut colors.orange, &theme.orange, "bright.orange")?;
+    apply_color(&mut colors.white, &theme.white, "bright.white")?;
+    Ok(())
+}
+
+fn apply_color(target: &mut Color, value: &Option<String>, key: &str) -> anyhow::Result<()> {
+    if let Some(value) = value {
+        *target = parse_color(value).with_context(|| format!("at `{key}`"))?;
+    }
+    Ok(())
+}
+
+fn parse_color(value: &str) -> anyhow::Result<Color> {
+    Color::from_str(value).map_err(|_| anyhow::anyhow!("invalid color `{value}`"))
+}
+
+fn token_style(token: &TokenTheme) -> anyhow::Result<Style> {
+    let mut style = Style::new();
+    if let Some(fg) = token.fg.as_ref() {
+        style = style.fg(parse_color(fg)?);
+    }
+    if let Some(bg) = token.bg.as_ref() {
+        style = style.bg(parse_color(bg)?);
+    }
+    if token.bold {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if token.italic {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if token.underlined {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    Ok(style)
+}
+
+fn style_token(style: &Style) -> TokenTheme {
+    TokenTheme {
+        fg: style.fg.map(|color| color.to_string()),
+        bg: style.bg.map(|color| color.to_string()),
+        bold: style.add_modifier.contains(Modifier::BOLD),
+        italic: style.add_modifier.contains(Modifier::ITALIC),
+        underlined: style.add_modifier.contains(Modifier::UNDERLINED),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Stylize;
+
+    #[test]
+    fn test_partial_theme_keeps_defaults() {
+        let colors = Colors::from_theme_str(
+            r##"
+            [primary]
+            background = "#ffffff"
+
+            [normal]
+            red = "light-red"
+
+            [tokens]
+            string = { fg = "#005f00", bold = true }
+            "##,
+        )
+        .unwrap();
+        let defaults = Colors::default();
+
+        assert_eq!(colors.primary.background, Color::Rgb(0xff, 0xff, 0xff));
+        assert_eq!(colors.primary.hover, defaults.primary.hover);
+        assert_eq!(colors.normal.red, Color::LightRed);
+        assert_eq!(colors.bright, defaults.bright);
+        assert_eq!(
+            colors.tokens.get("string"),
+            Some(&Style::new().fg(Color::Rgb(0x00, 0x5f, 0x00)).bold())
+        );
+        assert_eq!(colors.tokens.get("number"), defaults.tokens.get("number"));
+    }
+
+    #[test]
+    fn test_dumped_theme_roundtrips() {
+        let colors = Colors::default();
+        let theme = colors.to_theme_string();
+        assert_eq!(Colors::from_theme_str(&theme).unwrap(), colors);
+    }
+
+    #[test]
+    fn test_invalid_theme_points_at_the_key() {
+        let error = Colors::from_theme_str("[bright]\nblue = \"not-a-color\"").unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "at `bright.blue`: invalid color `not-a-color`"
+        );
+
+        assert!(Colors::from_theme_str("[primary]\nunknown = \"red\"").is_err());
+    }
+}
@@ -1,7 +1,10 @@
 use crate::default_config::DEFAULT_CONFIG;
-use crate::{EditorMode, APP_NAME, CONFIG_ENV_VAR, CONFIG_FILE, XDG_DEFAULTS, XDG_ENV_VARS};
+use crate::{
+    EditorMode, APP_NAME, CONFIG_ENV_VAR, CONFIG_FILE, THEMES_DIR, XDG_DEFAULTS, XDG_ENV_VARS,
+};
 
 use std::collections::HashMap;
+use std::ops::Add;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
@@ -9,8 +12,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum Action {
     Undo,
+    Redo,
     FindNext,
     FindPrevious,
+    Search,
+    SearchBackwards,
 
     NextWord,
     PreviousWord,
@@ -32,6 +38,16 @@ pub enum Action {
     InsertLineBelow,
     InsertLineAbove,
     PasteBelow,
+    PasteAbove,
+    YankLine,
+    YankSelection,
+    DeleteSelection,
+    ChangeSelection,
+    /// waits for the next key and uses it as the register for the next yank,
+    /// delete or paste, just like `"` on vim
+    SelectRegister,
+    /// opens the content on `$VISUAL` or `$EDITOR`
+    EditExternally,
     InsertAhead,
     EnterMode(EditorMode),
     InsertAtEOL,
@@ -49,15 +65,159 @@ pub enum Action {
     JumpToEmptyLineAbove,
 }
 
+/// actions shared by every component other than the body editor. Each component
+/// has its own keymap, and only acts upon the actions that make sense for it,
+/// so `Delete` deletes a request on the sidebar, and a header on the headers editor
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum UiAction {
+    /// focus and select a pane of the collection viewer
+    FocusSidebar,
+    FocusUri,
+    FocusEditor,
+    FocusPreview,
+    /// moves the focus around panes without selecting them
+    FocusNext,
+    FocusPrev,
+
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    ScrollToStart,
+    ScrollToEnd,
+    HalfPageDown,
+    HalfPageUp,
+    NextTab,
+    PrevTab,
+    /// closes the open request tab, selecting its neighbour
+    CloseTab,
+    /// shows the response of another open request next to the selected one
+    ToggleSplit,
+
+    Select,
+    Create,
+    /// creates a collection from a file written by another tool, like an
+    /// OpenAPI spec
+    Import,
+    CreateDirectory,
+    Edit,
+    /// loads the hovered item from disk again, like a collection that failed
+    /// to load after it was repaired
+    Reload,
+    Delete,
+    Toggle,
+    /// marks the hovered item to be moved by the next `Paste`
+    Cut,
+    Paste,
+    Duplicate,
+    /// moves the hovered item among the items of the same directory
+    MoveItemUp,
+    MoveItemDown,
+
+    Filter,
+    /// opens the fuzzy finder for every request of the collection
+    FindRequest,
+    Search,
+    SearchBackwards,
+    FindNext,
+    FindPrevious,
+    /// remembers the response on view, to be diffed against another one
+    MarkResponse,
+    /// diffs the marked response against the one on view
+    DiffResponses,
+    /// writes the request and the response on view to a HAR file
+    ExportHar,
+    Help,
+    /// lists the collections that failed to load
+    ShowErrors,
+    /// closes the popup on view, just like `Esc`
+    Close,
+}
+
+/// keys are written in the same format as the editor keys, like `j`, `S-D`,
+/// `C-d` or `Enter`
+pub type Keymap = HashMap<String, UiAction>;
+
+/// keymaps for every component other than the body editor. `Esc` and `C-c` are
+/// not configurable, as they are used to leave or quit any component
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Keymaps {
+    /// keys used on the collection viewer while no pane is selected
+    #[serde(
+        default = "default_panes_keymap",
+        deserialize_with = "deserialize_bindings"
+    )]
+    pub panes: Keymap,
+    #[serde(
+        default = "default_sidebar_keymap",
+        deserialize_with = "deserialize_bindings"
+    )]
+    pub sidebar: Keymap,
+    #[serde(
+        default = "default_dashboard_keymap",
+        deserialize_with = "deserialize_bindings"
+    )]
+    pub dashboard: Keymap,
+    #[serde(
+        default = "default_response_viewer_keymap",
+        deserialize_with = "deserialize_bindings"
+    )]
+    pub response_viewer: Keymap,
+    #[serde(
+        default = "default_headers_editor_keymap",
+        deserialize_with = "deserialize_bindings"
+    )]
+    pub headers_editor: Keymap,
+}
+
+/// every field has a default so a user configuration file can be validated on
+/// its own, before being merged over the default configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
+    #[serde(default = "default_editor_keys")]
     pub editor_keys: Keys,
+    #[serde(default = "default_keymaps")]
+    pub keymaps: Keymaps,
+    /// name of a theme on the themes directory, without the `.toml` extension,
+    /// when not set, the default colors are used
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// where collections are stored, defaults to the `collections` directory
+    /// inside of the data directory. On startup, this is replaced by the
+    /// directory actually in use, which may come from `--collections-dir` or
+    /// from a `.hac` workspace
+    #[serde(default)]
+    pub collections_dir: Option<PathBuf>,
+    /// how new collections are stored on disk, existing collections are always
+    /// kept on the layout they were found
+    #[serde(default)]
+    pub collection_layout: CollectionLayout,
+}
+
+/// on disk layout of a collection
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionLayout {
+    /// the whole collection is a single json file
+    #[default]
+    File,
+    /// the collection is a directory, with one pretty printed file per request
+    /// and one directory per directory, which plays nicely with version control
+    Directory,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Keys {
+    #[serde(default, deserialize_with = "deserialize_bindings")]
     pub normal: HashMap<String, KeyAction>,
+    #[serde(default, deserialize_with = "deserialize_bindings")]
     pub insert: HashMap<String, KeyAction>,
+    /// keys used on both visual and visual line modes
+    #[serde(default, deserialize_with = "deserialize_bindings")]
+    pub visual: HashMap<String, KeyAction>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -65,7 +225,39 @@ pub struct Keys {
 pub enum KeyAction {
     Simple(Action),
     Multiple(Vec<Action>),
-    Complex(HashMap<String, KeyAction>),
+    Complex(#[serde(deserialize_with = "deserialize_bindings")] HashMap<String, KeyAction>),
+}
+
+/// a single key binding, where `false` means the key is unbound
+struct Binding<T>(Option<T>);
+
+impl<'de, T: serde::de::DeserializeOwned> Deserialize<'de> for Binding<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match toml::Value::deserialize(deserializer)? {
+            toml::Value::Boolean(false) => Ok(Binding(None)),
+            value => value
+                .try_into()
+                .map(|action| Binding(Some(action)))
+                .map_err(|e: toml::de::Error| serde::de::Error::custom(e.message())),
+        }
+    }
+}
+
+/// deserializes a table of key bindings, leaving out every key bound to `false`,
+/// which is how a default binding is removed
+fn deserialize_bindings<'de, D, T>(deserializer: D) -> Result<HashMap<String, T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let bindings = HashMap::<String, Binding<T>>::deserialize(deserializer)?;
+    Ok(bindings
+        .into_iter()
+        .filter_map(|(key, Binding(action))| action.map(|action| (key, action)))
+        .collect())
 }
 
 impl std::fmt::Display for EditorMode {
@@ -73,18 +265,40 @@ impl std::fmt::Display for EditorMode {
         match self {
             Self::Normal => f.write_str("NORMAL"),
             Self::Insert => f.write_str("INSERT"),
+            Self::Visual => f.write_str("VISUAL"),
+            Self::VisualLine => f.write_str("V-LINE"),
         }
     }
 }
 
-fn load_config_from_file<P>(path: P) -> anyhow::Result<Config>
-where
-    P: AsRef<Path>,
-{
-    let config_file = std::fs::read_to_string(path.as_ref())?;
-    Ok(toml::from_str::<Config>(&config_file)?)
+/// a problem found on the user configuration file. Entries with problems are
+/// ignored, and everything else is still merged over the default configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigDiagnostic {
+    pub path: PathBuf,
+    /// line and column of the problem, both starting at 1
+    pub location: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location {
+            Some((line, column)) => write!(
+                f,
+                "{}:{line}:{column}: {}",
+                self.path.to_string_lossy(),
+                self.message
+            ),
+            None => write!(f, "{}: {}", self.path.to_string_lossy(), self.message),
+        }
+    }
 }
 
+/// how many problems we report before giving up on the user configuration, each
+/// problem removes a line from it, so this also bounds how many times we parse it
+const MAX_DIAGNOSTICS: usize = 32;
+
 /// try to get the configuration path from `XDG_CONFIG_HOME` on unix or `LOCALAPPDATA` on windows
 /// if that fails, fallback to the default path specified on the specification, or `AppData\\Local`
 /// on windows
@@ -124,27 +338,314 @@ pub fn get_config_dir_path() -> Option<PathBuf> {
     None
 }
 
-fn load_default_config() -> Config {
+/// themes are stored on a `themes` directory right next to the configuration file
+pub fn get_themes_dir_path() -> Option<PathBuf> {
+    get_config_dir_path()
+        .and_then(|config_file| config_file.parent().map(|dir| dir.join(THEMES_DIR)))
+}
+
+/// path of the theme file for a theme name, as selected on the `theme` key
+pub fn get_theme_path(theme: &str) -> Option<PathBuf> {
+    get_themes_dir_path().map(|themes_dir| themes_dir.join(format!("{theme}.toml")))
+}
+
+fn default_config_table() -> toml::Table {
+    toml::from_str::<toml::Table>(DEFAULT_CONFIG).expect("failed to parse default config string")
+}
+
+/// the default configuration, ignoring the user configuration, which keeps
+/// tests from depending on the configuration of whoever runs them
+pub fn load_default_config() -> Config {
     toml::from_str::<Config>(DEFAULT_CONFIG).expect("failed to parse default config string")
 }
 
+fn default_editor_keys() -> Keys {
+    load_default_config().editor_keys
+}
+
+fn default_keymaps() -> Keymaps {
+    load_default_config().keymaps
+}
+
+fn default_panes_keymap() -> Keymap {
+    default_keymaps().panes
+}
+
+fn default_sidebar_keymap() -> Keymap {
+    default_keymaps().sidebar
+}
+
+fn default_dashboard_keymap() -> Keymap {
+    default_keymaps().dashboard
+}
+
+fn default_response_viewer_keymap() -> Keymap {
+    default_keymaps().response_viewer
+}
+
+fn default_headers_editor_keymap() -> Keymap {
+    default_keymaps().headers_editor
+}
+
 pub fn default_as_str() -> &'static str {
     DEFAULT_CONFIG
 }
 
+/// loads the configuration, logging any problem found on the user configuration,
+/// use `load_config_with_diagnostics` to display them
 pub fn load_config() -> Config {
-    let config = get_config_dir_path().and_then(|path| load_config_from_file(path).ok());
+    let (config, diagnostics) = load_config_with_diagnostics();
+    diagnostics
+        .iter()
+        .for_each(|diagnostic| tracing::error!("{diagnostic}"));
+    config
+}
+
+/// loads the user configuration merged over the default one, along with every
+/// problem found on the user configuration. A missing configuration file is not
+/// a problem, and just means the default configuration is used
+pub fn load_config_with_diagnostics() -> (Config, Vec<ConfigDiagnostic>) {
+    let Some(path) = get_config_dir_path() else {
+        return (load_default_config(), vec![]);
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(source) => parse_config(&path, &source),
+        Err(e) if e.kind().eq(&std::io::ErrorKind::NotFound) => (load_default_config(), vec![]),
+        Err(e) => {
+            let diagnostic = ConfigDiagnostic {
+                path,
+                location: None,
+                message: e.to_string(),
+            };
+            (load_default_config(), vec![diagnostic])
+        }
+    }
+}
+
+/// parses `source` as a user configuration and deep merges it over the default
+/// configuration, so a configuration only needs the keys it wants to change.
+///
+/// whenever the configuration has a problem, the offending line is reported and
+/// removed, and we try again, so a single typo doesn't throw away every other key
+pub fn parse_config(path: &Path, source: &str) -> (Config, Vec<ConfigDiagnostic>) {
+    let mut source = source.to_string();
+    let mut diagnostics = vec![];
+
+    let user_table = loop {
+        let error = match toml::from_str::<Config>(&source) {
+            Ok(_) => break toml::from_str::<toml::Table>(&source).unwrap_or_default(),
+            Err(error) => error,
+        };
+
+        let span = error.span();
+        diagnostics.push(ConfigDiagnostic {
+            path: path.to_path_buf(),
+            location: span
+                .as_ref()
+                .map(|span| line_and_column(&source, span.start)),
+            message: error.message().trim().to_string(),
+        });
+
+        match span {
+            Some(span) if diagnostics.len().lt(&MAX_DIAGNOSTICS) => {
+                source = remove_offending_lines(&source, span.start)
+            }
+            _ => break toml::Table::default(),
+        }
+    };
+
+    let mut table = default_config_table();
+    merge_tables(&mut table, user_table);
 
-    if let Some(config) = config {
-        config
-    } else {
-        load_default_config()
+    match toml::Value::Table(table).try_into::<Config>() {
+        Ok(config) => (config, diagnostics),
+        Err(e) => {
+            diagnostics.push(ConfigDiagnostic {
+                path: path.to_path_buf(),
+                location: None,
+                message: e.message().trim().to_string(),
+            });
+            (load_default_config(), diagnostics)
+        }
+    }
+}
+
+/// merges `overrides` into `base`, tables present on both are merged recursively,
+/// and every other value on `overrides` replaces the one on `base`. Key bindings
+/// are merged by `merge_bindings` instead
+fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        let is_bindings = matches!(key.as_str(), "editor_keys" | "keymaps");
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overrides)) if is_bindings => {
+                merge_bindings(base, overrides)
+            }
+            (Some(toml::Value::Table(base)), toml::Value::Table(overrides)) => {
+                merge_tables(base, overrides)
+            }
+            (_, value) => _ = base.insert(key, value),
+        }
     }
 }
 
+/// merges key bindings, a binding on `overrides` replaces the one on `base` for
+/// the same key, unless both are tables of keys to be pressed next, in which case
+/// they are merged
+fn merge_bindings(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overrides))
+                if !is_action(base) && !is_action(&overrides) =>
+            {
+                merge_bindings(base, overrides)
+            }
+            (_, value) => _ = base.insert(key, value),
+        }
+    }
+}
+
+/// some actions, like `{ EnterMode = "Visual" }`, are tables themselves, and
+/// must not be mixed with a table of keys
+fn is_action(table: &toml::Table) -> bool {
+    toml::Value::Table(table.clone())
+        .try_into::<Action>()
+        .is_ok()
+}
+
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count().add(1);
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map(|line| line.chars().count())
+        .unwrap_or_default()
+        .add(1);
+    (line, column)
+}
+
+/// blanks the line containing `offset`, keeping every other line where it was,
+/// so later problems are still reported on the right line. When the line is a
+/// table header, the whole table goes with it, otherwise its keys would end up
+/// on the table above
+fn remove_offending_lines(source: &str, offset: usize) -> String {
+    let (offending_line, _) = line_and_column(source, offset);
+    let mut inside_removed_table = false;
+
+    source
+        .split('\n')
+        .enumerate()
+        .map(|(idx, line)| {
+            let is_header = line.trim_start().starts_with('[');
+            if idx.add(1).eq(&offending_line) {
+                inside_removed_table = is_header;
+                return "";
+            }
+            if is_header {
+                inside_removed_table = false;
+            }
+            match inside_removed_table {
+                true => "",
+                false => line,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 pub fn get_usual_path() -> PathBuf {
     dirs::home_dir()
         .expect("failed to get the home directory")
         .join(XDG_DEFAULTS[0])
         .join(APP_NAME)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_config_is_merged_over_defaults() {
+        let source = r#"
+            [editor_keys.normal]
+            "t" = "MoveDown"
+
+            [keymaps.sidebar]
+            "t" = "MoveDown"
+        "#;
+
+        let (config, diagnostics) = parse_config(Path::new("hac.toml"), source);
+
+        assert!(diagnostics.is_empty());
+        assert!(config.editor_keys.normal.contains_key("t"));
+        assert!(config.editor_keys.normal.contains_key("j"));
+        assert!(config.editor_keys.insert.contains_key("Enter"));
+        assert_eq!(config.keymaps.sidebar.get("t"), Some(&UiAction::MoveDown));
+        assert_eq!(config.keymaps.sidebar.get("k"), Some(&UiAction::MoveUp));
+        assert!(config.keymaps.panes.contains_key("r"));
+    }
+
+    #[test]
+    fn test_bindings_of_different_kinds_are_replaced() {
+        let source = r#"
+            [editor_keys.normal]
+            "y" = { EnterMode = "Visual" }
+            "d" = false
+
+            [keymaps.sidebar]
+            "k" = false
+        "#;
+
+        let (config, diagnostics) = parse_config(Path::new("hac.toml"), source);
+
+        assert!(diagnostics.is_empty());
+        assert!(matches!(
+            config.editor_keys.normal.get("y"),
+            Some(KeyAction::Simple(Action::EnterMode(EditorMode::Visual)))
+        ));
+        assert!(!config.editor_keys.normal.contains_key("d"));
+        assert!(config.editor_keys.normal.contains_key("j"));
+        assert_eq!(config.keymaps.sidebar.get("k"), None);
+        assert_eq!(config.keymaps.sidebar.get("j"), Some(&UiAction::MoveDown));
+    }
+
+    #[test]
+    fn test_problems_are_reported_and_skipped() {
+        let source = [
+            "theme = \"light\"",
+            "[keymaps.sidebar]",
+            "\"t\" = \"MoveDwn\"",
+            "\"s\" = \"MoveUp\"",
+            "[keymaps.unknown]",
+            "\"x\" = \"MoveUp\"",
+            "[keymaps.panes]",
+            "\"x\" = \"FocusSidebar\"",
+        ]
+        .join("\n");
+
+        let (config, diagnostics) = parse_config(Path::new("hac.toml"), &source);
+
+        let lines = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.location.map(|(line, _)| line))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![Some(3), Some(5)]);
+        assert!(diagnostics[0].to_string().starts_with("hac.toml:3:"));
+        assert_eq!(config.theme.as_deref(), Some("light"));
+        assert_eq!(config.keymaps.sidebar.get("t"), None);
+        assert_eq!(config.keymaps.sidebar.get("s"), Some(&UiAction::MoveUp));
+        assert_eq!(config.keymaps.panes.get("x"), Some(&UiAction::FocusSidebar));
+    }
+
+    #[test]
+    fn test_syntax_errors_keep_the_rest_of_the_config() {
+        let source = "theme = \"light\"\n\"broken = 1\n";
+
+        let (config, diagnostics) = parse_config(Path::new("hac.toml"), source);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location.map(|(line, _)| line), Some(2));
+        assert_eq!(config.theme.as_deref(), Some("light"));
+    }
+}
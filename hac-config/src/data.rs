@@ -1,6 +1,7 @@
-use crate::{APP_NAME, COLLECTIONS_DIR, XDG_DEFAULTS, XDG_ENV_VARS};
+use crate::config::Config;
+use crate::{APP_NAME, BACKUPS_DIR, COLLECTIONS_DIR, WORKSPACE_DIR, XDG_DEFAULTS, XDG_ENV_VARS};
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub fn get_data_dir() -> PathBuf {
     let data_dir = std::env::var(XDG_ENV_VARS[1])
@@ -32,16 +33,56 @@ pub fn get_or_create_data_dir() -> PathBuf {
     data_dir
 }
 
-pub fn get_collections_dir() -> PathBuf {
-    let data_dir = get_data_dir();
-    data_dir.join(COLLECTIONS_DIR)
+/// where collections are stored, which is `collections_dir` on the configuration
+/// when set, or the `collections` directory inside of the data directory
+pub fn get_collections_dir(config: &Config) -> PathBuf {
+    match config.collections_dir.as_ref() {
+        Some(collections_dir) => expand_home(collections_dir),
+        None => get_data_dir().join(COLLECTIONS_DIR),
+    }
+}
+
+/// finds the collections directory to use for this run, in order of precedence:
+/// the `--collections-dir` flag, a `.hac` workspace on the current directory or
+/// any of its parents, and then whatever `get_collections_dir` resolves to
+pub fn resolve_collections_dir(config: &Config, cli_dir: Option<PathBuf>) -> PathBuf {
+    if let Some(cli_dir) = cli_dir {
+        tracing::debug!("using collections dir from --collections-dir: {cli_dir:?}");
+        return cli_dir;
+    }
+
+    let workspace_dir = std::env::current_dir()
+        .ok()
+        .and_then(|current_dir| find_workspace_dir(&current_dir));
+    if let Some(workspace_dir) = workspace_dir {
+        tracing::debug!("using collections from workspace at: {workspace_dir:?}");
+        return workspace_dir;
+    }
+
+    get_collections_dir(config)
+}
+
+/// walks up from `start` looking for a `.hac` directory, which holds the
+/// collections of a project, so they can be versioned along with its code
+pub fn find_workspace_dir(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(WORKSPACE_DIR))
+        .find(|workspace_dir| workspace_dir.is_dir())
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home_dir)) => home_dir.join(rest),
+        _ => path.to_path_buf(),
+    }
 }
 
-pub fn get_or_create_collections_dir() -> PathBuf {
-    let collections_dir = get_collections_dir();
+pub fn get_or_create_collections_dir(config: &Config) -> PathBuf {
+    let collections_dir = get_collections_dir(config);
 
     if !collections_dir.exists() && !collections_dir.is_dir() {
-        match std::fs::create_dir(&collections_dir) {
+        match std::fs::create_dir_all(&collections_dir) {
             // if we create the collections dir, theres nothing to do
             Ok(_) => {}
             // if we fail to do so, panicking is adequate as we won't be able to properly run the
@@ -56,6 +97,49 @@ pub fn get_or_create_collections_dir() -> PathBuf {
     collections_dir
 }
 
+/// backups of collections are kept on the data directory, so they are never
+/// committed along with a workspace
+pub fn get_backups_dir() -> PathBuf {
+    get_data_dir().join(BACKUPS_DIR)
+}
+
 pub fn log_file() -> (PathBuf, String) {
     (get_data_dir(), format!("{}.log", APP_NAME))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_is_found_on_parents() {
+        let project_dir = tempfile::tempdir().unwrap();
+        let nested_dir = project_dir.path().join("src").join("nested");
+        std::fs::create_dir_all(&nested_dir).unwrap();
+
+        assert_eq!(find_workspace_dir(&nested_dir), None);
+
+        std::fs::create_dir(project_dir.path().join(WORKSPACE_DIR)).unwrap();
+
+        assert_eq!(
+            find_workspace_dir(&nested_dir),
+            Some(project_dir.path().join(WORKSPACE_DIR))
+        );
+    }
+
+    #[test]
+    fn test_collections_dir_from_config() {
+        let mut config = crate::load_default_config();
+        config.collections_dir = Some(PathBuf::from("/any/dir"));
+        assert_eq!(get_collections_dir(&config), PathBuf::from("/any/dir"));
+
+        config.collections_dir = Some(PathBuf::from("~/collections"));
+        let home_dir = dirs::home_dir().unwrap();
+        assert_eq!(get_collections_dir(&config), home_dir.join("collections"));
+
+        assert_eq!(
+            resolve_collections_dir(&config, Some(PathBuf::from("/from/cli"))),
+            PathBuf::from("/from/cli")
+        );
+    }
+}
@@ -1,8 +1,28 @@
 pub static DEFAULT_CONFIG: &str = r##"
+# name of a theme inside of the `themes` directory next to this file, without
+# the `.toml` extension. Use `hac --theme-dump` to get a theme to start from
+# theme = "light"
+
+# directory where collections are stored, defaults to `collections` inside of
+# the data directory. A `.hac` directory on the current directory, or any of
+# its parents, takes precedence over this
+# collections_dir = "~/collections"
+
+# how new collections are stored, either "file", where the whole collection is
+# a single file, or "directory", with one file for each request, which is way
+# easier to review and merge when collections are versioned
+# collection_layout = "directory"
+
+# keys bound here replace their default binding, and a key set to `false` is
+# unbound, like `"d" = false`
+
 [editor_keys.normal]
 "u" = "Undo"
+"C-r" = "Redo"
 "n" = "FindNext"
 "S-N" = "FindPrevious"
+"/" = "Search"
+"?" = "SearchBackwards"
 "w" = "NextWord"
 "b" = "PreviousWord"
 "h" = "MoveLeft"
@@ -26,6 +46,12 @@ pub static DEFAULT_CONFIG: &str = r##"
 "o" = ["InsertLineBelow", "InsertAtEOL"]
 "S-O" = "InsertLineAbove"
 "p" = "PasteBelow"
+"S-P" = "PasteAbove"
+"y" = { "y" = "YankLine" }
+"v" = { EnterMode = "Visual" }
+"S-V" = { EnterMode = "VisualLine" }
+"\"" = "SelectRegister"
+"C-e" = "EditExternally"
 "a" = "InsertAhead"
 "i" = { EnterMode = "Insert" }
 "S-I" = ["MoveToLineStart", { EnterMode = "Insert" }]
@@ -46,6 +72,40 @@ pub static DEFAULT_CONFIG: &str = r##"
 "l" = "DeleteCurrentChar"
 "h" = "DeletePreviousChar"
 
+[editor_keys.visual]
+"w" = "NextWord"
+"b" = "PreviousWord"
+"h" = "MoveLeft"
+"Left" = "MoveLeft"
+"j" = "MoveDown"
+"Down" = "MoveDown"
+"k" = "MoveUp"
+"Up" = "MoveUp"
+"l" = "MoveRight"
+"Right" = "MoveRight"
+"S-G" = "MoveToBottom"
+"g" = { "g" = "MoveToTop" }
+"$" = "MoveToLineEnd"
+"End" = "MoveToLineEnd"
+"Home" = "MoveToLineStart"
+"0" = "MoveToLineStart"
+"C-d" = "PageDown"
+"C-u" = "PageUp"
+"S-B" = "MoveAfterWhitespaceReverse"
+"S-W" = "MoveAfterWhitespace"
+"%" = "JumpToClosing"
+"{" = "JumpToEmptyLineAbove"
+"}" = "JumpToEmptyLineBelow"
+"y" = "YankSelection"
+"d" = "DeleteSelection"
+"x" = "DeleteSelection"
+"c" = "ChangeSelection"
+"v" = { EnterMode = "Normal" }
+"S-V" = { EnterMode = "VisualLine" }
+"Esc" = { EnterMode = "Normal" }
+"C-c" = { EnterMode = "Normal" }
+"\"" = "SelectRegister"
+
 [editor_keys.insert]
 "Tab" = "InsertTab"
 "Enter" = "InsertLine"
@@ -53,4 +113,94 @@ pub static DEFAULT_CONFIG: &str = r##"
 "Esc" = { EnterMode = "Normal" }
 "C-c" = { EnterMode = "Normal" }
 "C-w" = "DeleteBack"
+
+# keymaps for everything other than the body editor, keys use the same format
+# as the editor keys. `Esc` and `C-c` always leave or quit the current pane
+[keymaps.panes]
+"r" = "FocusSidebar"
+"u" = "FocusUri"
+"e" = "FocusEditor"
+"p" = "FocusPreview"
+"Tab" = "FocusNext"
+"BackTab" = "FocusPrev"
+"Enter" = "Select"
+"C-p" = "FindRequest"
+"]" = "NextTab"
+"[" = "PrevTab"
+"x" = "CloseTab"
+"|" = "ToggleSplit"
+
+[keymaps.sidebar]
+"j" = "MoveDown"
+"Down" = "MoveDown"
+"k" = "MoveUp"
+"Up" = "MoveUp"
+"Enter" = "Select"
+"n" = "Create"
+"d" = "CreateDirectory"
+"e" = "Edit"
+"S-D" = "Delete"
+"x" = "Cut"
+"p" = "Paste"
+"y" = "Duplicate"
+"S-K" = "MoveItemUp"
+"S-J" = "MoveItemDown"
+"C-p" = "FindRequest"
+"/" = "Filter"
+
+[keymaps.dashboard]
+"h" = "MoveLeft"
+"Left" = "MoveLeft"
+"j" = "MoveDown"
+"Down" = "MoveDown"
+"k" = "MoveUp"
+"Up" = "MoveUp"
+"l" = "MoveRight"
+"Right" = "MoveRight"
+"Enter" = "Select"
+"n" = "Create"
+"c" = "Create"
+"i" = "Import"
+"e" = "Edit"
+"r" = "Reload"
+"d" = "Delete"
+"/" = "Filter"
+"?" = "Help"
+"!" = "ShowErrors"
+
+[keymaps.response_viewer]
+"Tab" = "NextTab"
+"BackTab" = "PrevTab"
+"h" = "MoveLeft"
+"j" = "MoveDown"
+"Down" = "MoveDown"
+"k" = "MoveUp"
+"Up" = "MoveUp"
+"l" = "MoveRight"
+"0" = "ScrollToStart"
+"g" = "ScrollToStart"
+"$" = "ScrollToEnd"
+"S-G" = "ScrollToEnd"
+"C-d" = "HalfPageDown"
+"C-u" = "HalfPageUp"
+"q" = "Close"
+"f" = "Filter"
+"/" = "Search"
+"?" = "SearchBackwards"
+"n" = "FindNext"
+"S-N" = "FindPrevious"
+"m" = "MarkResponse"
+"d" = "DiffResponses"
+"e" = "ExportHar"
+
+[keymaps.headers_editor]
+"j" = "MoveDown"
+"Down" = "MoveDown"
+"k" = "MoveUp"
+"Up" = "MoveUp"
+"Enter" = "Edit"
+"n" = "Create"
+"d" = "Delete"
+" " = "Toggle"
+"?" = "Help"
 "##;
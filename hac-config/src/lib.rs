@@ -3,10 +3,13 @@ pub mod data;
 mod default_config;
 
 pub use config::{
-    default_as_str, get_config_dir_path, get_usual_path, load_config, Action, Config, KeyAction,
+    default_as_str, get_config_dir_path, get_theme_path, get_themes_dir_path, get_usual_path,
+    load_config, load_config_with_diagnostics, load_default_config, parse_config, Action,
+    CollectionLayout, Config, ConfigDiagnostic, KeyAction, Keymap, Keymaps, UiAction,
 };
 pub use data::{
-    get_collections_dir, get_or_create_collections_dir, get_or_create_data_dir, log_file,
+    find_workspace_dir, get_backups_dir, get_collections_dir, get_or_create_collections_dir,
+    get_or_create_data_dir, log_file, resolve_collections_dir,
 };
 use serde::{Deserialize, Serialize};
 
@@ -14,12 +17,18 @@ use serde::{Deserialize, Serialize};
 pub enum EditorMode {
     Insert,
     Normal,
+    Visual,
+    VisualLine,
 }
 
 pub static APP_NAME: &str = "hac";
 pub static COLLECTIONS_DIR: &str = "collections";
+/// project local directory holding collections, discovered from the current
+/// directory upwards
+pub static WORKSPACE_DIR: &str = ".hac";
 pub static CONFIG_FILE: &str = "hac.toml";
 pub static THEMES_DIR: &str = "themes";
+pub static BACKUPS_DIR: &str = "backups";
 pub static CONFIG_ENV_VAR: &str = "HAC_CONFIG";
 
 #[cfg(unix)]
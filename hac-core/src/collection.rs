@@ -1,5 +1,16 @@
 #[allow(clippy::module_inception)]
 pub mod collection;
+pub mod docs;
+pub mod environment;
+pub mod har;
+pub mod layout;
+pub mod migrations;
+pub mod openapi;
+pub mod search;
+pub mod tree;
 pub mod types;
+pub mod yaml;
+pub use errors::CollectionLoadError;
+pub use migrations::COLLECTION_VERSION;
 pub use types::Collection;
 mod errors;
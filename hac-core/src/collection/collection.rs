@@ -1,38 +1,140 @@
+use crate::collection::errors::{CollectionLoadError, FileContext};
+use crate::collection::layout;
+use crate::collection::migrations::{self, COLLECTION_VERSION};
 use crate::collection::types::{Collection, Info};
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::{self, UNIX_EPOCH};
 
-#[tracing::instrument(err)]
-pub fn get_collections_from_config() -> anyhow::Result<Vec<Collection>> {
-    let collections_dir = hac_config::get_or_create_collections_dir();
-    get_collections(collections_dir)
+use anyhow::Context;
+use hac_config::CollectionLayout;
+
+/// loads every collection on the configured collections directory, along with
+/// the collections that failed to load
+#[tracing::instrument(err, skip_all)]
+pub fn get_collections_from_config(
+    config: &hac_config::Config,
+) -> anyhow::Result<(Vec<Collection>, Vec<CollectionLoadError>)> {
+    let collections_dir = hac_config::get_or_create_collections_dir(config);
+    get_collections_with_errors(collections_dir)
 }
 
+/// loads every healthy collection on `collections_dir`, collections that fail
+/// to load are only logged, use `get_collections_with_errors` to get them
 #[tracing::instrument(skip(collections_dir), err)]
 pub fn get_collections<P>(collections_dir: P) -> anyhow::Result<Vec<Collection>>
+where
+    P: AsRef<Path>,
+{
+    let (collections, errors) = get_collections_with_errors(collections_dir)?;
+    errors
+        .iter()
+        .for_each(|error| tracing::error!("failed to load collection: {error}"));
+    Ok(collections)
+}
+
+/// loads every collection on `collections_dir`. A broken collection doesn't
+/// prevent the others from loading, instead its error is returned along with
+/// the healthy collections. Only reading the directory itself is fatal
+#[tracing::instrument(skip(collections_dir), err)]
+pub fn get_collections_with_errors<P>(
+    collections_dir: P,
+) -> anyhow::Result<(Vec<Collection>, Vec<CollectionLoadError>)>
 where
     P: AsRef<Path>,
 {
     let items = std::fs::read_dir(&collections_dir)?;
 
     let mut collections = vec![];
+    let mut errors = vec![];
 
     for item in items.into_iter().flatten() {
         let file_name = item.file_name();
-        let collection_name = collections_dir.as_ref().join(file_name);
-        let file = std::fs::read_to_string(&collection_name)?;
-        let mut collection: Collection = serde_json::from_str(&file)?;
-        collection.path = collection_name;
-        collections.push(collection);
+        let collection_name = collections_dir.as_ref().join(&file_name);
+
+        if !is_collection_entry(&collection_name, &file_name.to_string_lossy()) {
+            continue;
+        }
+
+        match load_collection(&collection_name) {
+            Ok(collection) => collections.push(collection),
+            Err(e) => errors.push(CollectionLoadError::new(&collection_name, &e)),
+        }
     }
 
     collections.sort_by(|a, b| a.info.name.cmp(&b.info.name));
+    errors.sort_by(|a, b| a.collection_path.cmp(&b.collection_path));
 
-    Ok(collections)
+    Ok((collections, errors))
+}
+
+/// collections directories may be shared with other tools, like a `.git`
+/// directory on a workspace, or things like `.DS_Store` and editor swap files,
+/// so only json files and collection directories are considered collections
+fn is_collection_entry(path: &Path, name: &str) -> bool {
+    if name.starts_with('.') {
+        return false;
+    }
+
+    match path.is_dir() {
+        true => layout::is_collection_dir(path),
+        false => name.ends_with(".json"),
+    }
 }
 
-pub fn create_from_form(name: String, description: String) -> Collection {
+/// loads a single collection, which may be stored with either layout, and
+/// upgrades it to the current version of the collection format
+pub fn load_collection<P>(path: P) -> anyhow::Result<Collection>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    if layout::is_collection_dir(path) {
+        return layout::read_collection_dir(path);
+    }
+
+    let file = std::fs::read_to_string(path).with_context(|| FileContext::read(path))?;
+    let value = serde_json::from_str(&file).with_context(|| FileContext::parse(path))?;
+    let mut collection = migrations::deserialize_collection(value)
+        .with_context(|| format!("failed to load collection {}", path.display()))?;
+    collection.path = path.to_path_buf();
+    collection.layout = CollectionLayout::File;
+    Ok(collection)
+}
+
+/// loads the collection `name_or_path` refers to, which is either the path to
+/// a collection, or the name of a collection on `collections_dir`, ignoring case
+pub fn find_collection<P>(collections_dir: P, name_or_path: &str) -> anyhow::Result<Collection>
+where
+    P: AsRef<Path>,
+{
+    let path = Path::new(name_or_path);
+    if path.exists() {
+        return load_collection(path);
+    }
+
+    get_collections(collections_dir)?
+        .into_iter()
+        .find(|collection| {
+            let file_stem = collection
+                .path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy());
+            collection.info.name.eq_ignore_ascii_case(name_or_path)
+                || file_stem.is_some_and(|stem| stem.eq_ignore_ascii_case(name_or_path))
+        })
+        .ok_or_else(|| anyhow::anyhow!("could not find a collection named {name_or_path}"))
+}
+
+pub fn create_from_form<P>(
+    name: String,
+    description: String,
+    collections_dir: P,
+    collection_layout: CollectionLayout,
+) -> Collection
+where
+    P: AsRef<Path>,
+{
     let name = if name.is_empty() {
         let now = time::SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -43,17 +145,35 @@ pub fn create_from_form(name: String, description: String) -> Collection {
         name
     };
 
-    let collections_dir = hac_config::get_collections_dir();
-    let name_as_file_name = name.to_lowercase().replace(' ', "_");
-    let collection_name = collections_dir.join(name_as_file_name);
-
     Collection {
+        version: COLLECTION_VERSION,
+        path: collection_path(&name, collections_dir, collection_layout),
         info: Info {
             name,
             description: Some(description),
         },
         requests: None,
-        path: format!("{}.json", collection_name.to_string_lossy()).into(),
+        environment: Default::default(),
+        layout: collection_layout,
+    }
+}
+
+/// where a collection named `name` is stored on `collections_dir`. Names come
+/// from users and imported files, so they are slugified, a name like `../x` or
+/// `pets / v2` can't point outside of `collections_dir` or into a directory
+pub fn collection_path<P>(
+    name: &str,
+    collections_dir: P,
+    collection_layout: CollectionLayout,
+) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    let collection_name = collections_dir.as_ref().join(layout::slugify(name));
+
+    match collection_layout {
+        CollectionLayout::File => format!("{}.json", collection_name.to_string_lossy()).into(),
+        CollectionLayout::Directory => collection_name,
     }
 }
 
@@ -62,10 +182,92 @@ mod tests {
     use super::*;
     #[test]
     fn test_creating_from_form() {
-        let collection = create_from_form("any valid name".into(), "any desctiption".into());
+        let collection = create_from_form(
+            "any valid name".into(),
+            "any desctiption".into(),
+            "any_dir",
+            CollectionLayout::File,
+        );
 
         assert!(collection.path.to_string_lossy().ends_with(".json"));
+        assert!(collection.path.starts_with("any_dir"));
         assert!(collection.info.name.eq("any valid name"));
         assert!(collection.info.description.is_some())
     }
+
+    #[test]
+    fn test_collection_paths_stay_inside_of_the_collections_dir() {
+        let escaped = collection_path("../escaped", "cols", CollectionLayout::File);
+        assert_eq!(escaped, Path::new("cols").join("___escaped.json"));
+
+        let nested = collection_path("Pets / v2", "cols", CollectionLayout::Directory);
+        assert_eq!(nested, Path::new("cols").join("pets___v2"));
+    }
+
+    #[test]
+    fn test_creating_from_form_with_directory_layout() {
+        let collection = create_from_form(
+            "any valid name".into(),
+            "any desctiption".into(),
+            "any_dir",
+            CollectionLayout::Directory,
+        );
+
+        assert_eq!(collection.path, Path::new("any_dir").join("any_valid_name"));
+        assert_eq!(collection.layout, CollectionLayout::Directory);
+    }
+
+    #[test]
+    fn test_broken_collections_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+
+        write("healthy.json", r#"{ "info": { "name": "healthy" } }"#);
+        write("broken.json", "{\n  \"info\": {\n}");
+        write(".DS_Store", "not a collection");
+        write(".gitkeep", "");
+        write("notes.txt", "not a collection either");
+        write(
+            "layout/collection.json",
+            r#"{ "info": { "name": "layout" } }"#,
+        );
+        write("layout/request.json", "{ \"id\": ");
+
+        let (collections, errors) = get_collections_with_errors(dir.path()).unwrap();
+
+        assert_eq!(collections.len(), 1);
+        assert_eq!(collections[0].info.name, "healthy");
+        assert_eq!(errors.len(), 2);
+
+        assert_eq!(errors[0].collection_path, dir.path().join("broken.json"));
+        assert_eq!(errors[0].path, dir.path().join("broken.json"));
+        assert_eq!(errors[0].location, Some((3, 1)));
+        assert_eq!(errors[0].message, "EOF while parsing an object");
+
+        assert_eq!(errors[1].collection_path, dir.path().join("layout"));
+        assert_eq!(
+            errors[1].path,
+            dir.path().join("layout").join("request.json")
+        );
+        assert!(errors[1].location.is_some());
+    }
+
+    #[test]
+    fn test_finding_collections_by_name_or_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users_api.json");
+        std::fs::write(&path, r#"{ "info": { "name": "Users API" } }"#).unwrap();
+
+        let by_name = find_collection(dir.path(), "users api").unwrap();
+        assert_eq!(by_name.path, path);
+        let by_stem = find_collection(dir.path(), "users_api").unwrap();
+        assert_eq!(by_stem.info.name, "Users API");
+        let by_path = find_collection("elsewhere", &path.to_string_lossy()).unwrap();
+        assert_eq!(by_path.info.name, "Users API");
+        assert!(find_collection(dir.path(), "orders").is_err());
+    }
 }
@@ -0,0 +1,345 @@
+use crate::collection::types::{AuthMethod, BodyType, Collection, Request, RequestKind};
+
+use std::fmt::Write;
+
+use pulldown_cmark::{Event, Parser};
+
+/// markdown only has six levels of headings, anything nested deeper than that
+/// shares the last level
+const MAX_HEADING_LEVEL: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocsFormat {
+    Markdown,
+    Html,
+}
+
+/// renders every request of a collection, directory by directory, as a single
+/// static document
+pub fn render_docs(collection: &Collection, format: DocsFormat) -> String {
+    let markdown = render_markdown(collection);
+    match format {
+        DocsFormat::Markdown => markdown,
+        DocsFormat::Html => render_page(&collection.info.name, &markdown_to_html(&markdown)),
+    }
+}
+
+fn render_markdown(collection: &Collection) -> String {
+    let mut doc = format!("# {}\n\n", collection.info.name);
+    if let Some(description) = non_empty(collection.info.description.as_ref()) {
+        doc.push_str(&shift_headings(description, 1));
+        doc.push_str("\n\n");
+    }
+
+    if let Some(requests) = collection.requests.as_ref() {
+        render_entries(&requests.read().unwrap(), 2, &mut doc);
+    }
+
+    doc.truncate(doc.trim_end().len());
+    doc.push('\n');
+    doc
+}
+
+fn render_entries(entries: &[RequestKind], level: usize, doc: &mut String) {
+    let level = level.min(MAX_HEADING_LEVEL);
+    for entry in entries {
+        match entry {
+            RequestKind::Single(request) => render_request(&request.read().unwrap(), level, doc),
+            RequestKind::Nested(dir) => {
+                _ = write!(doc, "{} {}\n\n", "#".repeat(level), dir.name);
+                if let Some(description) = non_empty(dir.description.as_ref()) {
+                    doc.push_str(&shift_headings(description, level));
+                    doc.push_str("\n\n");
+                }
+                render_entries(&dir.requests.read().unwrap(), level + 1, doc);
+            }
+        }
+    }
+}
+
+/// responses only live for as long as hac is open, collections have no saved
+/// example responses to show, so a request is documented by what it sends
+fn render_request(request: &Request, level: usize, doc: &mut String) {
+    _ = write!(
+        doc,
+        "{} {} {}\n\n",
+        "#".repeat(level),
+        request.method,
+        request.name
+    );
+    _ = write!(doc, "`{} {}`\n\n", request.method, request.uri);
+
+    if let Some(description) = non_empty(request.description.as_ref()) {
+        doc.push_str(&shift_headings(description, level));
+        doc.push_str("\n\n");
+    }
+
+    let headers = request
+        .headers
+        .iter()
+        .flatten()
+        .filter(|header| header.enabled)
+        .collect::<Vec<_>>();
+    if !headers.is_empty() {
+        doc.push_str("**Headers**\n\n");
+        // docs are meant to be published, so values that may be secrets are
+        // replaced by a placeholder
+        for header in headers {
+            let (name, value) = &header.pair;
+            match header.is_shareable() {
+                true => _ = writeln!(doc, "- `{name}: {value}`"),
+                false => _ = writeln!(doc, "- `{name}: <value>`"),
+            }
+        }
+        doc.push('\n');
+    }
+
+    if let Some(auth_method) = request.auth_method.as_ref() {
+        let auth_method = match auth_method {
+            AuthMethod::Bearer => "bearer token",
+            AuthMethod::Basic => "basic auth",
+        };
+        _ = write!(doc, "**Auth**: {auth_method}\n\n");
+    }
+
+    if let Some(body) = non_empty(request.body.as_ref()) {
+        let language = match request.body_type {
+            Some(BodyType::Json) | None => "json",
+        };
+        _ = write!(
+            doc,
+            "**Body**\n\n```{language}\n{}\n```\n\n",
+            body.trim_end()
+        );
+    }
+}
+
+fn non_empty(text: Option<&String>) -> Option<&str> {
+    text.map(|text| text.trim()).filter(|text| !text.is_empty())
+}
+
+/// pushes the headings of a description below the heading it is under, so a
+/// `# Title` on a request description doesn't end up as big as the collection
+/// name
+fn shift_headings(markdown: &str, by: usize) -> String {
+    let mut in_code = false;
+    markdown
+        .lines()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_code = !in_code;
+            }
+            match (in_code, heading(line)) {
+                (false, Some((level, text))) => {
+                    let level = (level + by).min(MAX_HEADING_LEVEL);
+                    format!("{} {text}", "#".repeat(level))
+                }
+                _ => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// level and text of an ATX heading, like `## Title`
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| c.eq(&'#')).count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=MAX_HEADING_LEVEL)
+        .contains(&level)
+        .then_some((level, text.trim()))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// converts markdown to html following CommonMark. Descriptions are written by
+/// whoever edits the collection, so raw html on them is shown as text instead
+/// of ending up on the page
+pub fn markdown_to_html(markdown: &str) -> String {
+    let events = Parser::new(markdown).map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        event => event,
+    });
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, events);
+    html
+}
+
+fn render_page(title: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{}</title>
+<style>
+body {{ font-family: system-ui, sans-serif; line-height: 1.5; color: #1f2328; }}
+main {{ max-width: 860px; margin: 0 auto; padding: 2rem 1rem; }}
+code {{ font-family: ui-monospace, monospace; background: #f0f1f3; padding: 0.1rem 0.3rem; border-radius: 4px; }}
+pre {{ background: #f0f1f3; padding: 1rem; border-radius: 6px; overflow-x: auto; }}
+pre code {{ padding: 0; }}
+h2, h3, h4, h5, h6 {{ border-bottom: 1px solid #d0d7de; padding-bottom: 0.3rem; }}
+</style>
+</head>
+<body>
+<main>
+{}</main>
+</body>
+</html>
+"#,
+        escape_html(title),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::types::{Directory, HeaderMap, Info, RequestMethod};
+
+    use std::sync::{Arc, RwLock};
+
+    fn make_collection() -> Collection {
+        let create_user = Request {
+            id: "create".into(),
+            method: RequestMethod::Post,
+            name: "Create user".into(),
+            uri: "https://api.com/users".into(),
+            headers: Some(vec![
+                HeaderMap {
+                    pair: ("Content-Type".into(), "application/json".into()),
+                    enabled: true,
+                },
+                HeaderMap {
+                    pair: ("X-Debug".into(), "1".into()),
+                    enabled: false,
+                },
+                HeaderMap {
+                    pair: ("X-Api-Key".into(), "secret".into()),
+                    enabled: true,
+                },
+            ]),
+            auth_method: Some(AuthMethod::Bearer),
+            parent: Some("users".into()),
+            body: Some("{\n  \"name\": \"john\"\n}".into()),
+            body_type: Some(BodyType::Json),
+            description: Some("# Notes\ncreates a **new** user".into()),
+        };
+
+        Collection {
+            version: crate::collection::COLLECTION_VERSION,
+            info: Info {
+                name: "Users API".into(),
+                description: Some("everything about users".into()),
+            },
+            requests: Some(Arc::new(RwLock::new(vec![RequestKind::Nested(
+                Directory {
+                    id: "users".into(),
+                    name: "Users".into(),
+                    requests: Arc::new(RwLock::new(vec![RequestKind::Single(Arc::new(
+                        RwLock::new(create_user),
+                    ))])),
+                    description: Some("manage users".into()),
+                },
+            )]))),
+            environment: Default::default(),
+            path: "any".into(),
+            layout: hac_config::CollectionLayout::File,
+        }
+    }
+
+    #[test]
+    fn test_rendering_markdown_docs() {
+        let docs = render_docs(&make_collection(), DocsFormat::Markdown);
+
+        assert_eq!(
+            docs,
+            [
+                "# Users API",
+                "",
+                "everything about users",
+                "",
+                "## Users",
+                "",
+                "manage users",
+                "",
+                "### POST Create user",
+                "",
+                "`POST https://api.com/users`",
+                "",
+                "#### Notes",
+                "creates a **new** user",
+                "",
+                "**Headers**",
+                "",
+                "- `Content-Type: application/json`",
+                "- `X-Api-Key: <value>`",
+                "",
+                "**Auth**: bearer token",
+                "",
+                "**Body**",
+                "",
+                "```json",
+                "{",
+                "  \"name\": \"john\"",
+                "}",
+                "```",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_rendering_html_docs() {
+        let docs = render_docs(&make_collection(), DocsFormat::Html);
+
+        assert!(docs.starts_with("<!DOCTYPE html>"));
+        assert!(docs.contains("<title>Users API</title>"));
+        assert!(docs.contains("<h3>POST Create user</h3>"));
+        assert!(docs.contains("<p>creates a <strong>new</strong> user</p>"));
+        assert!(docs.contains("X-Api-Key: &lt;value&gt;"));
+        assert!(!docs.contains("secret"));
+        assert!(docs.contains("<li><code>Content-Type: application/json</code></li>"));
+        assert!(docs.contains("<pre><code class=\"language-json\">{\n  \"name\""));
+    }
+
+    #[test]
+    fn test_markdown_to_html() {
+        let html = markdown_to_html(
+            "a *b* [c](https://d.com?a=1&b=2)\nnext line\n\n1. one\n2. two\n- three\n\n```\n<x>\n",
+        );
+        assert_eq!(
+            html,
+            [
+                "<p>a <em>b</em> <a href=\"https://d.com?a=1&amp;b=2\">c</a>",
+                "next line</p>",
+                "<ol>",
+                "<li>one</li>",
+                "<li>two</li>",
+                "</ol>",
+                "<ul>",
+                "<li>three</li>",
+                "</ul>",
+                "<pre><code>&lt;x&gt;",
+                "</code></pre>",
+                "",
+            ]
+            .join("\n")
+        );
+
+        // unclosed markers are kept as they are
+        assert_eq!(markdown_to_html("2 * 3 = `6"), "<p>2 * 3 = `6</p>\n");
+        // and so is raw html
+        assert_eq!(
+            markdown_to_html("<script>alert(1)</script>"),
+            "&lt;script&gt;alert(1)&lt;/script&gt;"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::collection::types::Request;
+
+use std::collections::BTreeMap;
+
+/// replaces every `{{name}}` on `text` with the value of `name` on the
+/// environment. Names may be surrounded by spaces, like `{{ name }}`, and
+/// variables that don't exist are kept as they are, so they are easy to spot
+pub fn expand(text: &str, environment: &BTreeMap<String, String>) -> String {
+    if environment.is_empty() {
+        return text.to_string();
+    }
+
+    let mut expanded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}").map(|end| start + end) else {
+            break;
+        };
+        expanded.push_str(&rest[..start]);
+
+        let name = rest[start + 2..end].trim();
+        match environment.get(name) {
+            Some(value) => expanded.push_str(value),
+            None => expanded.push_str(&rest[start..end + 2]),
+        }
+        rest = &rest[end + 2..];
+    }
+    expanded.push_str(rest);
+
+    expanded
+}
+
+/// a copy of the request with every variable on its uri, headers and body
+/// replaced, which is what gets sent
+pub fn expand_request(request: &Request, environment: &BTreeMap<String, String>) -> Request {
+    let mut request = request.clone();
+    request.uri = expand(&request.uri, environment);
+    request.body = request.body.map(|body| expand(&body, environment));
+    request.headers.iter_mut().flatten().for_each(|header| {
+        header.pair.0 = expand(&header.pair.0, environment);
+        header.pair.1 = expand(&header.pair.1, environment);
+    });
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expanding_variables() {
+        let environment = BTreeMap::from([
+            ("base_url".to_string(), "https://api.com".to_string()),
+            ("token".to_string(), "secret".to_string()),
+        ]);
+
+        assert_eq!(
+            expand("{{base_url}}/users/{id}", &environment),
+            "https://api.com/users/{id}"
+        );
+        assert_eq!(expand("Bearer {{ token }}", &environment), "Bearer secret");
+        assert_eq!(
+            expand("{{missing}} and {{token", &environment),
+            "{{missing}} and {{token"
+        );
+        assert_eq!(expand("{{base_url}}", &BTreeMap::new()), "{{base_url}}");
+    }
+}
@@ -1,3 +1,6 @@
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
 pub enum CollectionError {
     Unknown(String),
 }
@@ -20,3 +23,96 @@ where
         CollectionError::Unknown(msg)
     }
 }
+
+/// a collection that failed to load, along with the file the problem is on and
+/// where on that file, when we know it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionLoadError {
+    /// path of the collection, either a file or a directory, depending on the
+    /// layout the collection is stored with
+    pub collection_path: PathBuf,
+    /// file with the problem, which for collections with the directory layout
+    /// may be any file inside of the collection directory
+    pub path: PathBuf,
+    /// line and column of the problem, both starting from 1
+    pub location: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl CollectionLoadError {
+    pub fn new(collection_path: &Path, error: &anyhow::Error) -> Self {
+        let path = error
+            .downcast_ref::<FileContext>()
+            .map(|context| context.path.clone())
+            .unwrap_or_else(|| collection_path.to_path_buf());
+
+        // errors from parsing json carry the location of the problem, errors
+        // from converting it into a collection have line 0 instead
+        let location = error
+            .downcast_ref::<serde_json::Error>()
+            .filter(|error| error.line() > 0)
+            .map(|error| (error.line(), error.column()));
+
+        let message = error.root_cause().to_string();
+        let message = match location {
+            Some(_) => message
+                .rsplit_once(" at line ")
+                .map(|(message, _)| message.to_string())
+                .unwrap_or(message),
+            None => message,
+        };
+
+        CollectionLoadError {
+            collection_path: collection_path.to_path_buf(),
+            path,
+            location,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for CollectionLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location {
+            Some((line, column)) => {
+                write!(
+                    f,
+                    "{}:{line}:{column}: {}",
+                    self.path.display(),
+                    self.message
+                )
+            }
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+/// context attached to errors from reading or parsing a single file, so errors
+/// on collections with many files can point to the file with the problem
+#[derive(Debug)]
+pub(crate) struct FileContext {
+    pub path: PathBuf,
+    pub action: &'static str,
+}
+
+impl FileContext {
+    pub fn read(path: &Path) -> Self {
+        FileContext {
+            path: path.to_path_buf(),
+            action: "read",
+        }
+    }
+
+    pub fn parse(path: &Path) -> Self {
+        FileContext {
+            path: path.to_path_buf(),
+            action: "parse",
+        }
+    }
+}
+
+impl std::fmt::Display for FileContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to {} {}", self.action, self.path.display())
+    }
+}
@@ -0,0 +1,476 @@
+use crate::collection::types::{
+    BodyType, Collection, Directory, HeaderMap, Info, Request, RequestKind, RequestMethod,
+};
+use crate::collection::COLLECTION_VERSION;
+use crate::net::request_manager::Response;
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Map, Value};
+
+/// headers a browser records but that are computed again when sending the
+/// request, like http/2 pseudo headers, or the length of the body
+const SKIPPED_HEADERS: [&str; 3] = ["host", "content-length", "connection"];
+
+/// builds a collection from a HAR file, like the ones exported by the network
+/// tab of a browser. Every entry becomes a request, grouped into one directory
+/// for each host they were sent to. When `dedupe` is set, only the first entry
+/// of each method and url is kept, as pages tend to repeat the same requests.
+/// Entries with methods hac can't send, like `OPTIONS`, are skipped
+pub fn import_har(har: &str, dedupe: bool) -> anyhow::Result<Collection> {
+    let har: Value = serde_json::from_str(har)?;
+    let Some(entries) = har.pointer("/log/entries").and_then(Value::as_array) else {
+        anyhow::bail!("not a HAR file, it has no `log.entries`");
+    };
+
+    let mut directories: Vec<Directory> = vec![];
+    let mut seen = HashSet::new();
+    for entry in entries {
+        let Some(request) = entry.get("request") else {
+            continue;
+        };
+        let Some(method) = request
+            .get("method")
+            .and_then(Value::as_str)
+            .and_then(method_from_str)
+        else {
+            continue;
+        };
+        let Some(url) = request.get("url").and_then(Value::as_str) else {
+            continue;
+        };
+        if dedupe && !seen.insert((method.to_string(), url.to_string())) {
+            continue;
+        }
+
+        let (host, path) = split_url(url);
+        let idx = match directories.iter().position(|dir| dir.name.eq(host)) {
+            Some(idx) => idx,
+            None => {
+                directories.push(Directory {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    name: host.to_string(),
+                    requests: Arc::default(),
+                    description: None,
+                });
+                directories.len() - 1
+            }
+        };
+        let directory = &directories[idx];
+
+        let headers = request
+            .get("headers")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|header| {
+                let name = header.get("name").and_then(Value::as_str)?;
+                let value = header.get("value").and_then(Value::as_str)?;
+                let is_skipped = name.starts_with(':')
+                    || SKIPPED_HEADERS.contains(&name.to_lowercase().as_str());
+                (!is_skipped).then(|| HeaderMap {
+                    pair: (name.to_string(), value.to_string()),
+                    enabled: true,
+                })
+            })
+            .collect::<Vec<_>>();
+        let body = request
+            .pointer("/postData/text")
+            .and_then(Value::as_str)
+            .filter(|body| !body.is_empty())
+            .map(String::from);
+
+        let request = Request {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: format!("{method} {path}"),
+            method,
+            uri: url.to_string(),
+            headers: (!headers.is_empty()).then_some(headers),
+            auth_method: None,
+            parent: Some(directory.id.clone()),
+            body_type: body.as_ref().map(|_| BodyType::Json),
+            body,
+            description: None,
+        };
+        directory
+            .requests
+            .write()
+            .unwrap()
+            .push(RequestKind::Single(Arc::new(RwLock::new(request))));
+    }
+
+    let requests = directories
+        .into_iter()
+        .map(RequestKind::Nested)
+        .collect::<Vec<_>>();
+    // browsers title pages after their url, in which case the host makes for a
+    // better name
+    let name = har
+        .pointer("/log/pages/0/title")
+        .and_then(Value::as_str)
+        .map(|title| match title.contains("://") {
+            true => split_url(title).0,
+            false => title,
+        })
+        .filter(|title| !title.is_empty())
+        .unwrap_or("HAR import");
+
+    Ok(Collection {
+        version: COLLECTION_VERSION,
+        info: Info {
+            name: name.to_string(),
+            description: None,
+        },
+        requests: (!requests.is_empty()).then(|| Arc::new(RwLock::new(requests))),
+        environment: BTreeMap::default(),
+        path: Default::default(),
+        layout: Default::default(),
+    })
+}
+
+fn method_from_str(method: &str) -> Option<RequestMethod> {
+    match method.to_uppercase().as_str() {
+        "GET" => Some(RequestMethod::Get),
+        "POST" => Some(RequestMethod::Post),
+        "PUT" => Some(RequestMethod::Put),
+        "PATCH" => Some(RequestMethod::Patch),
+        "DELETE" => Some(RequestMethod::Delete),
+        _ => None,
+    }
+}
+
+/// host of an url, along with its path without the query string
+fn split_url(url: &str) -> (&str, &str) {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let (host, path) = match url.find('/') {
+        Some(idx) => url.split_at(idx),
+        None => (url, "/"),
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    (host, path)
+}
+
+/// a HAR file with a single entry, made of the request as it was sent and the
+/// response it got. `started_at` is when the request was sent. hac only times
+/// the whole request, so all of it is reported as waiting for the server
+pub fn export_har(request: &Request, response: &Response, started_at: SystemTime) -> Value {
+    let milliseconds = response.duration.as_secs_f64() * 1000.0;
+    let mut entry = Map::new();
+    entry.insert(
+        "startedDateTime".into(),
+        Value::from(format_timestamp(started_at)),
+    );
+    entry.insert("time".into(), Value::from(milliseconds));
+    entry.insert("request".into(), har_request(request));
+    entry.insert("response".into(), har_response(response));
+    entry.insert("cache".into(), json!({}));
+    entry.insert(
+        "timings".into(),
+        json!({ "send": 0, "wait": milliseconds, "receive": 0 }),
+    );
+    // browsers record failed requests the same way
+    if let Some(cause) = response.cause.as_ref() {
+        entry.insert("_error".into(), Value::from(cause.clone()));
+    }
+
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": { "name": "hac", "version": env!("CARGO_PKG_VERSION") },
+            "entries": [entry],
+        }
+    })
+}
+
+fn har_request(request: &Request) -> Value {
+    let headers = request
+        .headers
+        .iter()
+        .flatten()
+        .filter(|header| header.enabled)
+        .map(|header| json!({ "name": header.pair.0, "value": header.pair.1 }))
+        .collect::<Vec<_>>();
+    let query = request
+        .uri
+        .split_once('?')
+        .map(|(_, query)| query.split('#').next().unwrap_or_default())
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            json!({ "name": name, "value": value })
+        })
+        .collect::<Vec<_>>();
+
+    let mut har_request = Map::new();
+    har_request.insert("method".into(), Value::from(request.method.to_string()));
+    har_request.insert("url".into(), Value::from(request.uri.clone()));
+    har_request.insert("httpVersion".into(), Value::from("HTTP/1.1"));
+    har_request.insert("cookies".into(), json!([]));
+    har_request.insert("headers".into(), Value::Array(headers));
+    har_request.insert("queryString".into(), Value::Array(query));
+    if let Some(body) = request.body.as_ref().filter(|body| !body.is_empty()) {
+        let mime_type = request
+            .headers
+            .iter()
+            .flatten()
+            .find(|header| header.enabled && header.pair.0.eq_ignore_ascii_case("content-type"))
+            .map_or("application/json", |header| header.pair.1.as_str());
+        har_request.insert(
+            "postData".into(),
+            json!({ "mimeType": mime_type, "text": body }),
+        );
+    }
+    har_request.insert("headersSize".into(), Value::from(-1));
+    har_request.insert(
+        "bodySize".into(),
+        Value::from(request.body.as_ref().map_or(0, String::len)),
+    );
+
+    Value::Object(har_request)
+}
+
+fn har_response(response: &Response) -> Value {
+    let header = |name: &str| {
+        response
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(name))
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+    };
+    let headers = response
+        .headers
+        .iter()
+        .flatten()
+        .map(|(name, value)| {
+            json!({ "name": name.as_str(), "value": String::from_utf8_lossy(value.as_bytes()) })
+        })
+        .collect::<Vec<_>>();
+    // sizes hac couldn't measure, like when the request failed, are unknown
+    let size = |size: Option<u64>| size.map_or(Value::from(-1), Value::from);
+
+    let mut content = Map::new();
+    content.insert(
+        "size".into(),
+        Value::from(response.body_size.unwrap_or_default()),
+    );
+    content.insert("mimeType".into(), Value::from(header("content-type")));
+    if let Some(body) = response.body.as_ref() {
+        content.insert("text".into(), Value::from(body.clone()));
+    }
+
+    json!({
+        "status": response.status.map_or(0, |status| status.as_u16()),
+        "statusText": response
+            .status
+            .and_then(|status| status.canonical_reason())
+            .unwrap_or_default(),
+        "httpVersion": "HTTP/1.1",
+        "cookies": [],
+        "headers": headers,
+        "content": content,
+        "redirectURL": header("location"),
+        "headersSize": size(response.headers_size),
+        "bodySize": size(response.body_size),
+    })
+}
+
+/// ISO 8601 representation of `time`, in UTC, as HAR files expect
+fn format_timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let days = (seconds / 86_400) as i64;
+    let seconds_of_day = seconds % 86_400;
+
+    // converts days since the epoch to a civil date, as described on
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use reqwest::header::{HeaderMap as ResponseHeaders, HeaderValue};
+
+    static HAR: &str = r#"{
+        "log": {
+            "version": "1.2",
+            "pages": [{ "title": "http://shop.com/cart" }],
+            "entries": [
+                {
+                    "request": {
+                        "method": "GET",
+                        "url": "https://api.shop.com/cart?user=1",
+                        "headers": [
+                            { "name": ":authority", "value": "api.shop.com" },
+                            { "name": "Cookie", "value": "session=abc" },
+                            { "name": "Content-Length", "value": "0" }
+                        ]
+                    }
+                },
+                { "request": { "method": "OPTIONS", "url": "https://api.shop.com/cart" } },
+                { "request": { "method": "GET", "url": "https://api.shop.com/cart?user=1" } },
+                {
+                    "request": {
+                        "method": "POST",
+                        "url": "https://cdn.shop.com/events",
+                        "postData": { "mimeType": "application/json", "text": "{\"id\":1}" }
+                    }
+                }
+            ]
+        }
+    }"#;
+
+    fn requests_of(kind: &RequestKind) -> Vec<Request> {
+        let RequestKind::Nested(directory) = kind else {
+            panic!("expected a directory");
+        };
+        directory
+            .requests
+            .read()
+            .unwrap()
+            .iter()
+            .map(|kind| match kind {
+                RequestKind::Single(request) => request.read().unwrap().clone(),
+                RequestKind::Nested(_) => panic!("expected a request"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_importing_har_files() {
+        let collection = import_har(HAR, false).unwrap();
+        assert_eq!(collection.info.name, "shop.com");
+
+        let requests = collection.requests.as_ref().unwrap().read().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].get_name(), "api.shop.com");
+        assert_eq!(requests[1].get_name(), "cdn.shop.com");
+
+        let api = requests_of(&requests[0]);
+        // options requests are skipped, repeated ones are not
+        assert_eq!(api.len(), 2);
+        assert_eq!(api[0].name, "GET /cart");
+        assert_eq!(api[0].uri, "https://api.shop.com/cart?user=1");
+        let headers = api[0].headers.as_ref().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].pair, ("Cookie".into(), "session=abc".into()));
+
+        let events = requests_of(&requests[1]);
+        assert_eq!(events[0].method, RequestMethod::Post);
+        assert_eq!(events[0].body.as_deref(), Some("{\"id\":1}"));
+        assert_eq!(events[0].body_type, Some(BodyType::Json));
+
+        let deduped = import_har(HAR, true).unwrap();
+        let requests = deduped.requests.as_ref().unwrap().read().unwrap();
+        assert_eq!(requests_of(&requests[0]).len(), 1);
+
+        assert!(import_har(r#"{ "log": {} }"#, false).is_err());
+    }
+
+    #[test]
+    fn test_exporting_har_files() {
+        let request = Request {
+            id: String::from("id"),
+            method: RequestMethod::Post,
+            name: String::from("create user"),
+            uri: String::from("https://api.com/users?notify=true"),
+            headers: Some(vec![
+                HeaderMap {
+                    pair: ("Content-Type".into(), "application/json".into()),
+                    enabled: true,
+                },
+                HeaderMap {
+                    pair: ("X-Debug".into(), "1".into()),
+                    enabled: false,
+                },
+            ]),
+            auth_method: None,
+            parent: None,
+            body: Some(String::from(r#"{"name":"john"}"#)),
+            body_type: Some(BodyType::Json),
+            description: None,
+        };
+        let mut headers = ResponseHeaders::new();
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+        let response = Response {
+            body: Some(String::from(r#"{"id":1}"#)),
+            pretty_body: None,
+            headers: Some(headers),
+            duration: Duration::from_millis(120),
+            status: Some(reqwest::StatusCode::CREATED),
+            headers_size: Some(30),
+            body_size: Some(8),
+            size: Some(38),
+            is_error: false,
+            cause: None,
+            sent: None,
+        };
+        let started_at = UNIX_EPOCH + Duration::from_millis(1_718_454_896_789);
+
+        let har = export_har(&request, &response, started_at);
+        let entry = &har["log"]["entries"][0];
+
+        assert_eq!(har["log"]["version"], json!("1.2"));
+        assert_eq!(entry["startedDateTime"], json!("2024-06-15T12:34:56.789Z"));
+        assert_eq!(entry["time"], json!(120.0));
+        assert_eq!(entry["timings"]["wait"], json!(120.0));
+        assert_eq!(entry["request"]["method"], json!("POST"));
+        assert_eq!(
+            entry["request"]["headers"],
+            json!([{ "name": "Content-Type", "value": "application/json" }])
+        );
+        assert_eq!(
+            entry["request"]["queryString"],
+            json!([{ "name": "notify", "value": "true" }])
+        );
+        assert_eq!(
+            entry["request"]["postData"]["text"],
+            json!(r#"{"name":"john"}"#)
+        );
+        assert_eq!(entry["request"]["bodySize"], json!(15));
+        assert_eq!(entry["response"]["status"], json!(201));
+        assert_eq!(entry["response"]["statusText"], json!("Created"));
+        assert_eq!(entry["response"]["headersSize"], json!(30));
+        assert_eq!(entry["response"]["bodySize"], json!(8));
+        assert_eq!(
+            entry["response"]["content"]["mimeType"],
+            json!("application/json")
+        );
+        assert_eq!(entry["response"]["content"]["text"], json!(r#"{"id":1}"#));
+
+        // what is exported can be imported back
+        let collection = import_har(&har.to_string(), false).unwrap();
+        let requests = collection.requests.as_ref().unwrap().read().unwrap();
+        let imported = requests_of(&requests[0]);
+        assert_eq!(imported[0].uri, request.uri);
+        assert_eq!(imported[0].body, request.body);
+    }
+}
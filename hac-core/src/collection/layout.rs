@@ -0,0 +1,541 @@
+use crate::collection::errors::FileContext;
+use crate::collection::migrations::{self, COLLECTION_VERSION};
+use crate::collection::types::{Collection, Info, RequestKind};
+use crate::fs::write_atomic;
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use hac_config::CollectionLayout;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// metadata of a collection stored with the directory layout, living at the root
+/// of the collection directory
+pub static COLLECTION_FILE: &str = "collection.json";
+/// metadata of a directory of requests, living inside of the directory itself
+pub static DIRECTORY_FILE: &str = "directory.json";
+
+/// files are not ordered on disk, so every directory keeps the order its entries
+/// are displayed on, reordering requests only changes this list. A collection
+/// without a list of requests, like a new one, has no order at all, which keeps
+/// it apart from a collection whose requests were all deleted
+#[derive(Debug, Serialize)]
+struct CollectionMeta {
+    version: u32,
+    info: Info,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    environment: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+struct DirectoryMeta {
+    id: String,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    order: Vec<String>,
+}
+
+/// whether `path` holds a collection stored with the directory layout
+pub fn is_collection_dir(path: &Path) -> bool {
+    path.is_dir() && path.join(COLLECTION_FILE).is_file()
+}
+
+/// reads a collection stored with the directory layout. Entries that are not on
+/// the order of their directory, like requests added by hand or from a merge,
+/// are still loaded, after every ordered entry, as long as they look like one.
+///
+/// files are read as plain json and put together into the same shape a single
+/// file collection has, so both layouts go through the same migrations
+pub fn read_collection_dir(path: &Path) -> anyhow::Result<Collection> {
+    let mut meta: Value = read_json(&path.join(COLLECTION_FILE))?;
+    let has_order = meta.get("order").is_some();
+    let requests = read_entries(path, &take_order(&mut meta))?;
+    if let Some(meta) = meta.as_object_mut() {
+        meta.insert("requests".into(), Value::Array(requests));
+    }
+
+    let mut collection = migrations::deserialize_collection(meta)
+        .with_context(|| format!("failed to load collection {}", path.display()))?;
+    // loading must give back what was written, or the written collection would
+    // look like it was changed on disk
+    if !has_order
+        && collection
+            .requests
+            .as_ref()
+            .is_some_and(|requests| requests.read().unwrap().is_empty())
+    {
+        collection.requests = None;
+    }
+    collection.path = path.to_path_buf();
+    collection.layout = CollectionLayout::Directory;
+
+    Ok(collection)
+}
+
+fn read_entries(dir: &Path, order: &[String]) -> anyhow::Result<Vec<Value>> {
+    let mut unordered = std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read directory {}", dir.display()))?
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(String::from))
+        .filter(|name| !order.contains(name) && is_entry(dir, name))
+        .filter(|name| looks_like_entry(&dir.join(name)))
+        .collect::<Vec<_>>();
+    unordered.sort();
+
+    let names = order
+        .iter()
+        .filter(|name| is_entry(dir, name))
+        .chain(unordered.iter());
+
+    let mut entries = vec![];
+    for name in names {
+        let path = dir.join(name);
+        if path.is_dir() {
+            let mut meta: Value = read_json(&path.join(DIRECTORY_FILE))?;
+            let requests = read_entries(&path, &take_order(&mut meta))?;
+            if let Some(meta) = meta.as_object_mut() {
+                meta.insert("requests".into(), Value::Array(requests));
+            }
+            entries.push(meta);
+        } else {
+            entries.push(read_json(&path)?);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// removes the order from a collection or directory file, as it only exists on
+/// disk, anything that is not a list of names is treated as no order at all
+fn take_order(meta: &mut Value) -> Vec<String> {
+    meta.as_object_mut()
+        .and_then(|meta| meta.remove("order"))
+        .and_then(|order| serde_json::from_value(order).ok())
+        .unwrap_or_default()
+}
+
+/// entries are request files and directories with a directory file, anything
+/// else on a collection directory is ignored
+fn is_entry(dir: &Path, name: &str) -> bool {
+    let path = dir.join(name);
+    match path.is_dir() {
+        true => path.join(DIRECTORY_FILE).is_file(),
+        false => name.ends_with(".json") && name.ne(COLLECTION_FILE) && name.ne(DIRECTORY_FILE),
+    }
+}
+
+/// entries that are not on the order were not written by us, so json files are
+/// only taken as requests when they have the fields every request has. Files
+/// that fail to parse are still taken, so the problem is reported
+fn looks_like_entry(path: &Path) -> bool {
+    path.is_dir()
+        || read_json::<Value>(path).map_or(true, |value| {
+            value.get("id").is_some() && value.get("method").is_some()
+        })
+}
+
+/// id of the request or directory stored at `path`, if it can be read
+fn entry_id(path: &Path) -> Option<String> {
+    let path = match path.is_dir() {
+        true => path.join(DIRECTORY_FILE),
+        false => path.to_path_buf(),
+    };
+    read_json::<Value>(&path)
+        .ok()?
+        .get("id")?
+        .as_str()
+        .map(String::from)
+}
+
+fn read_json<T>(path: &Path) -> anyhow::Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let content = std::fs::read_to_string(path).with_context(|| FileContext::read(path))?;
+    serde_json::from_str(&content).with_context(|| FileContext::parse(path))
+}
+
+/// every file of a collection on the directory layout, relative to the collection
+/// directory, along with its content. Contents are pretty printed with a trailing
+/// newline, and keys follow the order of the fields on each type, so the same
+/// collection always produces the same files
+pub fn collection_files(collection: &Collection) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let mut files = vec![];
+    let requests = collection
+        .requests
+        .as_ref()
+        .map(|requests| requests.read().unwrap().clone())
+        .unwrap_or_default();
+
+    let order = entries_files(Path::new(""), &requests, &mut files)?;
+    let meta = CollectionMeta {
+        version: COLLECTION_VERSION,
+        info: collection.info.clone(),
+        environment: collection.environment.clone(),
+        order: collection.requests.as_ref().map(|_| order),
+    };
+    files.push((PathBuf::from(COLLECTION_FILE), to_pretty_json(&meta)?));
+
+    Ok(files)
+}
+
+fn entries_files(
+    dir: &Path,
+    entries: &[RequestKind],
+    files: &mut Vec<(PathBuf, String)>,
+) -> anyhow::Result<Vec<String>> {
+    let mut used_names = HashSet::from([COLLECTION_FILE.to_string(), DIRECTORY_FILE.to_string()]);
+    let mut order = vec![];
+
+    for entry in entries {
+        match entry {
+            RequestKind::Single(request) => {
+                let request = request.read().unwrap();
+                let file_name = unique_name(&mut used_names, &slugify(&request.name), ".json");
+                files.push((dir.join(&file_name), to_pretty_json(&*request)?));
+                order.push(file_name);
+            }
+            RequestKind::Nested(directory) => {
+                let dir_name = unique_name(&mut used_names, &slugify(&directory.name), "");
+                let requests = directory.requests.read().unwrap();
+                let dir_order = entries_files(&dir.join(&dir_name), &requests, files)?;
+                let meta = DirectoryMeta {
+                    id: directory.id.clone(),
+                    name: directory.name.clone(),
+                    description: directory.description.clone(),
+                    order: dir_order,
+                };
+                files.push((
+                    dir.join(&dir_name).join(DIRECTORY_FILE),
+                    to_pretty_json(&meta)?,
+                ));
+                order.push(dir_name);
+            }
+        }
+    }
+
+    Ok(order)
+}
+
+fn to_pretty_json<T>(value: &T) -> anyhow::Result<String>
+where
+    T: Serialize,
+{
+    let mut content = serde_json::to_string_pretty(value)?;
+    content.push('\n');
+    Ok(content)
+}
+
+/// turns a name into something safe to use as a file name on every platform
+pub fn slugify(name: &str) -> String {
+    let slug = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c.is_alphanumeric() || c.eq(&'-') {
+            true => c,
+            false => '_',
+        })
+        .collect::<String>();
+
+    match slug.is_empty() {
+        true => String::from("unnamed"),
+        false => slug,
+    }
+}
+
+fn unique_name(used_names: &mut HashSet<String>, slug: &str, extension: &str) -> String {
+    let mut name = format!("{slug}{extension}");
+    let mut suffix = 2;
+    while used_names.contains(&name) {
+        name = format!("{slug}-{suffix}{extension}");
+        suffix += 1;
+    }
+    used_names.insert(name.clone());
+    name
+}
+
+/// writes a collection with the directory layout. Files that didn't change are
+/// left untouched, and request files or directories that are not part of the
+/// collection anymore are removed, anything else on the directory is kept.
+///
+/// only entries we wrote before, which are the ones on the previous order lists,
+/// or entries we loaded, which share an id with the collection, are ever removed
+pub fn write_collection_dir(collection: &Collection) -> anyhow::Result<()> {
+    let files = collection_files(collection)?;
+    let root = &collection.path;
+
+    let mut written_entries = HashSet::new();
+    previous_entries(root, COLLECTION_FILE, &mut written_entries);
+    let mut ids = HashSet::new();
+    if let Some(requests) = collection.requests.as_ref() {
+        collect_ids(&requests.read().unwrap(), &mut ids);
+    }
+
+    for (relative_path, content) in files.iter() {
+        let path = root.join(relative_path);
+        if std::fs::read_to_string(&path).is_ok_and(|current| current.eq(content)) {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write_atomic(&path, content.as_bytes())
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    let known_files = files
+        .into_iter()
+        .map(|(relative_path, _)| root.join(relative_path))
+        .collect::<HashSet<_>>();
+    let owned = |path: &Path| {
+        written_entries.contains(path) || entry_id(path).is_some_and(|id| ids.contains(&id))
+    };
+    remove_stale_entries(root, &known_files, &owned)
+}
+
+/// every entry on the order lists currently on disk, which are the entries we
+/// wrote on the last sync
+fn previous_entries(dir: &Path, meta_file: &str, entries: &mut HashSet<PathBuf>) {
+    let Ok(mut meta) = read_json::<Value>(&dir.join(meta_file)) else {
+        return;
+    };
+
+    for name in take_order(&mut meta) {
+        let path = dir.join(name);
+        if path.is_dir() {
+            previous_entries(&path, DIRECTORY_FILE, entries);
+        }
+        entries.insert(path);
+    }
+}
+
+fn collect_ids(entries: &[RequestKind], ids: &mut HashSet<String>) {
+    for entry in entries {
+        ids.insert(entry.get_id());
+        if let RequestKind::Nested(directory) = entry {
+            collect_ids(&directory.requests.read().unwrap(), ids);
+        }
+    }
+}
+
+fn remove_stale_entries(
+    dir: &Path,
+    known_files: &HashSet<PathBuf>,
+    owned: &dyn Fn(&Path) -> bool,
+) -> anyhow::Result<()> {
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        if !is_entry(dir, name) {
+            continue;
+        }
+
+        match path.is_dir() {
+            true if known_files.contains(&path.join(DIRECTORY_FILE)) => {
+                remove_stale_entries(&path, known_files, owned)?
+            }
+            true if owned(&path) => std::fs::remove_dir_all(&path)?,
+            false if !known_files.contains(&path) && owned(&path) => std::fs::remove_file(&path)?,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::types::{Directory, Request, RequestMethod};
+
+    use std::sync::{Arc, RwLock};
+
+    fn make_request(id: &str, name: &str, parent: Option<String>) -> RequestKind {
+        RequestKind::Single(Arc::new(RwLock::new(Request {
+            id: id.to_string(),
+            method: RequestMethod::Get,
+            name: name.to_string(),
+            uri: "https://example.com".to_string(),
+            headers: None,
+            auth_method: None,
+            parent,
+            body: None,
+            body_type: None,
+            description: None,
+        })))
+    }
+
+    fn make_collection(path: PathBuf) -> Collection {
+        let directory = RequestKind::Nested(Directory {
+            id: "dir".to_string(),
+            name: "Auth".to_string(),
+            requests: Arc::new(RwLock::new(vec![
+                make_request("login", "Login", Some("dir".to_string())),
+                make_request("logout", "Logout", Some("dir".to_string())),
+            ])),
+            description: Some("# Auth\nlogging in and out".to_string()),
+        });
+
+        Collection {
+            version: COLLECTION_VERSION,
+            info: Info {
+                name: "my collection".to_string(),
+                description: None,
+            },
+            requests: Some(Arc::new(RwLock::new(vec![
+                make_request("users", "Get users", None),
+                directory,
+                make_request("users_again", "Get users", None),
+            ]))),
+            environment: BTreeMap::from([("base_url".to_string(), "https://api.com".to_string())]),
+            path,
+            layout: CollectionLayout::Directory,
+        }
+    }
+
+    #[test]
+    fn test_unknown_files_are_neither_loaded_nor_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("my_collection");
+        let collection = make_collection(path.clone());
+
+        write_collection_dir(&collection).unwrap();
+        std::fs::write(path.join("package.json"), "{ \"name\": \"api\" }").unwrap();
+        std::fs::write(
+            path.join("Added By Hand.json"),
+            "{ \"id\": \"by_hand\", \"method\": \"GET\", \"name\": \"By hand\", \"uri\": \"\" }",
+        )
+        .unwrap();
+        let loaded = read_collection_dir(&path).unwrap();
+        let ids = loaded
+            .requests
+            .as_ref()
+            .unwrap()
+            .read()
+            .unwrap()
+            .iter()
+            .map(|entry| entry.get_id())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["users", "dir", "users_again", "by_hand"]);
+
+        write_collection_dir(&loaded).unwrap();
+
+        // the request added by hand now lives on its own slug, and is not loaded twice
+        assert!(path.join("package.json").exists());
+        assert!(!path.join("Added By Hand.json").exists());
+        assert!(path.join("by_hand.json").exists());
+        assert_eq!(
+            read_collection_dir(&path)
+                .unwrap()
+                .requests
+                .unwrap()
+                .read()
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_collection_files_are_stable() {
+        let collection = make_collection(PathBuf::from("any"));
+
+        let files = collection_files(&collection).unwrap();
+        let paths = files
+            .iter()
+            .map(|(path, _)| path.to_string_lossy().replace('\\', "/"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            vec![
+                "get_users.json",
+                "auth/login.json",
+                "auth/logout.json",
+                "auth/directory.json",
+                "get_users-2.json",
+                "collection.json",
+            ]
+        );
+        assert_eq!(files, collection_files(&collection).unwrap());
+        assert!(files[0]
+            .1
+            .starts_with("{\n  \"id\": \"users\",\n  \"method\": \"GET\""));
+        assert!(files[0].1.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_empty_collections_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("my_collection");
+        let mut collection = make_collection(path.clone());
+
+        for requests in [Some(Arc::new(RwLock::new(vec![]))), None] {
+            collection.requests = requests;
+            write_collection_dir(&collection).unwrap();
+            let loaded = read_collection_dir(&path).unwrap();
+
+            assert_eq!(
+                serde_json::to_string(&loaded).unwrap(),
+                serde_json::to_string(&collection).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_directory_layout_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("my_collection");
+        let collection = make_collection(path.clone());
+
+        write_collection_dir(&collection).unwrap();
+        std::fs::write(path.join("notes.txt"), "kept").unwrap();
+        let loaded = read_collection_dir(&path).unwrap();
+
+        assert!(is_collection_dir(&path));
+        assert_eq!(loaded.version, COLLECTION_VERSION);
+        assert_eq!(loaded.info, collection.info);
+        assert_eq!(loaded.environment, collection.environment);
+        let names = loaded
+            .requests
+            .as_ref()
+            .unwrap()
+            .read()
+            .unwrap()
+            .iter()
+            .map(|entry| entry.get_id())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["users", "dir", "users_again"]);
+        let description = match &loaded.requests.as_ref().unwrap().read().unwrap()[1] {
+            RequestKind::Nested(directory) => directory.description.clone(),
+            RequestKind::Single(_) => panic!("expected a directory"),
+        };
+        assert_eq!(description.as_deref(), Some("# Auth\nlogging in and out"));
+
+        // removing a directory removes its files, but keeps unrelated ones
+        loaded.requests.as_ref().unwrap().write().unwrap().remove(1);
+        write_collection_dir(&loaded).unwrap();
+
+        assert!(!path.join("auth").exists());
+        assert!(path.join("notes.txt").exists());
+        assert_eq!(
+            read_collection_dir(&path)
+                .unwrap()
+                .requests
+                .unwrap()
+                .read()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
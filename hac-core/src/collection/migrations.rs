@@ -0,0 +1,127 @@
+use crate::collection::types::Collection;
+
+use serde_json::{Map, Value};
+
+/// version of the collection format written by this version of hac. Every
+/// change to the format bumps this, and adds a migration from the previous one
+pub const COLLECTION_VERSION: u32 = 3;
+
+/// a migration upgrades a collection from one version to the next, working on
+/// the raw json, as older collections may not be valid for the current types
+type Migration = fn(&mut Map<String, Value>) -> anyhow::Result<()>;
+
+/// `MIGRATIONS[n]` upgrades a collection from version `n` into version `n + 1`,
+/// so there is always one migration for each version before the current one
+const MIGRATIONS: [Migration; COLLECTION_VERSION as usize] =
+    [unversioned_to_v1, v1_to_v2, v2_to_v3];
+
+/// upgrades a collection to the current version and parses it. Collections
+/// written by a newer version of hac are refused, as we have no way of knowing
+/// what changed, and saving them would lose whatever we don't understand
+pub fn deserialize_collection(mut value: Value) -> anyhow::Result<Collection> {
+    migrate(&mut value)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+/// runs every migration needed to bring `value` up to the current version
+pub fn migrate(value: &mut Value) -> anyhow::Result<()> {
+    let Some(collection) = value.as_object_mut() else {
+        anyhow::bail!("expected a collection to be a json object");
+    };
+
+    let version = collection_version(collection)?;
+    if version > COLLECTION_VERSION {
+        anyhow::bail!(
+            "collection was created by a newer version of hac (format version {version}, \
+            but this version of hac only supports up to {COLLECTION_VERSION}), \
+            please update hac to open it"
+        );
+    }
+
+    for (from, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        tracing::debug!("migrating collection from version {from} to {}", from + 1);
+        migration(collection)?;
+        collection.insert("version".into(), Value::from(from + 1));
+    }
+
+    Ok(())
+}
+
+/// collections written before the format was versioned have no version at all,
+/// which we treat as version 0
+fn collection_version(collection: &Map<String, Value>) -> anyhow::Result<u32> {
+    match collection.get("version") {
+        None => Ok(0),
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| anyhow::anyhow!("invalid collection version: {version}")),
+    }
+}
+
+/// version 1 is the first versioned format, and is otherwise the same as the
+/// unversioned one, so there is nothing to do other than setting the version
+fn unversioned_to_v1(_: &mut Map<String, Value>) -> anyhow::Result<()> {
+    Ok(())
+}
+
+/// version 2 adds an optional description to requests and directories, older
+/// collections have none, so they are already valid
+fn v1_to_v2(_: &mut Map<String, Value>) -> anyhow::Result<()> {
+    Ok(())
+}
+
+/// version 3 adds the environment of a collection, which is empty when missing
+fn v2_to_v3(_: &mut Map<String, Value>) -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unversioned_collections_are_upgraded() {
+        let value = serde_json::json!({
+            "info": { "name": "legacy" },
+            "requests": [
+                {
+                    "id": "dir",
+                    "name": "Auth",
+                    "requests": [{
+                        "id": "login",
+                        "method": "POST",
+                        "name": "Login",
+                        "uri": "https://example.com/login",
+                        "parent": "dir"
+                    }]
+                }
+            ]
+        });
+
+        let collection = deserialize_collection(value).unwrap();
+
+        assert_eq!(collection.version, COLLECTION_VERSION);
+        assert_eq!(collection.info.name, "legacy");
+        let requests = collection.requests.unwrap();
+        assert!(requests.read().unwrap()[0].is_dir());
+    }
+
+    #[test]
+    fn test_newer_collections_are_refused() {
+        let value = serde_json::json!({
+            "version": COLLECTION_VERSION + 1,
+            "info": { "name": "from the future" },
+        });
+
+        let error = deserialize_collection(value).unwrap_err().to_string();
+
+        assert!(error.contains("newer version of hac"));
+    }
+
+    #[test]
+    fn test_invalid_versions_are_refused() {
+        let value = serde_json::json!({ "version": "one", "info": { "name": "any" } });
+        assert!(deserialize_collection(value).is_err());
+    }
+}
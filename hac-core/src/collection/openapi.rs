@@ -0,0 +1,1059 @@
+use crate::collection::types::{
+    AuthMethod, BodyType, Collection, Directory, HeaderMap, Info, Request, RequestKind,
+    RequestMethod,
+};
+use crate::collection::{yaml, COLLECTION_VERSION};
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use serde_json::{json, Map, Value};
+
+/// name of the environment variable holding the url of the first server on the
+/// spec, every imported uri starts with it
+pub static BASE_URL_VARIABLE: &str = "base_url";
+/// generated examples stop going deeper after this many levels, even when no
+/// schema references itself
+const MAX_EXAMPLE_DEPTH: usize = 8;
+
+/// builds a collection from an OpenAPI 3.0 or 3.1 spec, written in either json
+/// or yaml. Tags become directories and operations become requests, with
+/// example bodies taken from the spec or generated from their schemas. The
+/// collection has no path yet, as it isn't stored anywhere
+pub fn import_openapi(spec: &str) -> anyhow::Result<Collection> {
+    let spec: Value = match spec.trim_start().starts_with('{') {
+        true => serde_json::from_str(spec)?,
+        false => yaml::parse(spec)?,
+    };
+
+    // yaml reads an unquoted `openapi: 3.0` as a number
+    let version = match spec.get("openapi") {
+        Some(Value::String(version)) => Some(version.clone()),
+        Some(Value::Number(version)) => Some(version.to_string()),
+        _ => None,
+    };
+    match version {
+        Some(version) if version.starts_with("3.") => {}
+        Some(version) => anyhow::bail!("OpenAPI {version} is not supported, only 3.0 and 3.1 are"),
+        None if spec.get("swagger").is_some() => {
+            anyhow::bail!("swagger 2.0 specs are not supported, only OpenAPI 3.0 and 3.1 are")
+        }
+        None => anyhow::bail!("not an OpenAPI spec, it has no `openapi` version"),
+    }
+
+    let info = spec.get("info");
+    let text = |value: Option<&Value>, key: &str| {
+        value
+            .and_then(|value| value.get(key))
+            .and_then(Value::as_str)
+            .map(String::from)
+    };
+
+    let mut directories = tag_directories(&spec);
+    let mut untagged = vec![];
+
+    let paths = spec.get("paths").and_then(Value::as_object);
+    for (path, item) in paths.into_iter().flatten() {
+        let item = resolve(&spec, item);
+        for (method_name, method) in METHODS {
+            let Some(operation) = item.get(method_name) else {
+                continue;
+            };
+            let tag = operation
+                .get("tags")
+                .and_then(Value::as_array)
+                .and_then(|tags| tags.first())
+                .and_then(Value::as_str);
+            let directory = tag
+                .map(|tag| directory_index(&mut directories, tag))
+                .map(|idx| &directories[idx]);
+
+            let request = build_request(&spec, path, item, method, operation, directory);
+            let request = RequestKind::Single(Arc::new(RwLock::new(request)));
+            match directory {
+                Some(directory) => directory.requests.write().unwrap().push(request),
+                None => untagged.push(request),
+            }
+        }
+    }
+
+    let requests = directories
+        .into_iter()
+        .filter(|dir| !dir.requests.read().unwrap().is_empty())
+        .map(RequestKind::Nested)
+        .chain(untagged)
+        .collect::<Vec<_>>();
+
+    Ok(Collection {
+        version: COLLECTION_VERSION,
+        info: Info {
+            name: text(info, "title").unwrap_or_else(|| String::from("Imported API")),
+            description: text(info, "description"),
+        },
+        requests: (!requests.is_empty()).then(|| Arc::new(RwLock::new(requests))),
+        environment: BTreeMap::from([(BASE_URL_VARIABLE.to_string(), server_url(&spec))]),
+        path: Default::default(),
+        layout: Default::default(),
+    })
+}
+
+/// OpenAPI methods hac can send, `head`, `options` and `trace` are skipped
+const METHODS: [(&str, RequestMethod); 5] = [
+    ("get", RequestMethod::Get),
+    ("post", RequestMethod::Post),
+    ("put", RequestMethod::Put),
+    ("patch", RequestMethod::Patch),
+    ("delete", RequestMethod::Delete),
+];
+
+fn make_directory(name: &str, description: Option<String>) -> Directory {
+    Directory {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        requests: Arc::default(),
+        description,
+    }
+}
+
+/// index of the directory of `tag`, which is created when it doesn't exist
+fn directory_index(directories: &mut Vec<Directory>, tag: &str) -> usize {
+    match directories.iter().position(|dir| dir.name.eq(tag)) {
+        Some(idx) => idx,
+        None => {
+            directories.push(make_directory(tag, None));
+            directories.len() - 1
+        }
+    }
+}
+
+/// directories for the tags declared at the root of the spec, in the order they
+/// are declared, tags that are only used by operations are added as found
+fn tag_directories(spec: &Value) -> Vec<Directory> {
+    spec.get("tags")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|tag| {
+            let name = tag.get("name").and_then(Value::as_str)?;
+            let description = tag
+                .get("description")
+                .and_then(Value::as_str)
+                .map(String::from);
+            Some(make_directory(name, description))
+        })
+        .collect()
+}
+
+/// url of the first server, with its variables replaced by their defaults
+fn server_url(spec: &Value) -> String {
+    let Some(server) = spec
+        .get("servers")
+        .and_then(Value::as_array)
+        .and_then(|servers| servers.first())
+    else {
+        return String::default();
+    };
+
+    let mut url = server
+        .get("url")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let variables = server.get("variables").and_then(Value::as_object);
+    for (name, variable) in variables.into_iter().flatten() {
+        if let Some(default) = variable.get("default").and_then(Value::as_str) {
+            url = url.replace(&format!("{{{name}}}"), default);
+        }
+    }
+
+    url.trim_end_matches('/').to_string()
+}
+
+/// follows a local `$ref`, like `#/components/schemas/User`, references to
+/// other files are not followed
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    let mut value = value;
+    // references may point to other references, but never forever
+    for _ in 0..MAX_EXAMPLE_DEPTH {
+        let Some(reference) = value.get("$ref").and_then(Value::as_str) else {
+            break;
+        };
+        match reference
+            .strip_prefix('#')
+            .and_then(|pointer| spec.pointer(pointer))
+        {
+            Some(target) => value = target,
+            None => break,
+        }
+    }
+    value
+}
+
+/// parameters of an operation, along with the ones shared by every operation
+/// of its path, operations can override shared parameters
+fn parameters<'a>(spec: &'a Value, item: &'a Value, operation: &'a Value) -> Vec<&'a Value> {
+    let list = |value: &'a Value| {
+        value
+            .get("parameters")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|parameter| resolve(spec, parameter))
+    };
+    let key = |parameter: &Value| (parameter.get("name").cloned(), parameter.get("in").cloned());
+
+    let overrides = list(operation).collect::<Vec<_>>();
+    list(item)
+        .filter(|shared| !overrides.iter().any(|other| key(other).eq(&key(shared))))
+        .chain(overrides.iter().copied())
+        .collect()
+}
+
+/// example of a parameter, as it would be written on a url. When the spec has
+/// none, a placeholder is generated from its schema if `generate` is set
+fn parameter_example(spec: &Value, parameter: &Value, generate: bool) -> Option<String> {
+    let schema = parameter.get("schema").map(|schema| resolve(spec, schema));
+    let example = parameter
+        .get("example")
+        .cloned()
+        .or_else(|| first_example(spec, parameter))
+        .or_else(|| {
+            let schema = schema?;
+            ["example", "default"]
+                .iter()
+                .find_map(|key| schema.get(key))
+                .cloned()
+        })
+        .or_else(|| {
+            let example = example_from_schema(spec, schema?, 0);
+            (generate && !example.is_null()).then_some(example)
+        })?;
+
+    Some(match example {
+        Value::String(example) => example,
+        other => other.to_string(),
+    })
+}
+
+/// value of the first example of an `examples` map
+fn first_example(spec: &Value, value: &Value) -> Option<Value> {
+    value
+        .get("examples")
+        .and_then(Value::as_object)
+        .and_then(|examples| examples.values().next())
+        .and_then(|example| resolve(spec, example).get("value"))
+        .cloned()
+}
+
+fn build_request(
+    spec: &Value,
+    path: &str,
+    item: &Value,
+    method: RequestMethod,
+    operation: &Value,
+    directory: Option<&Directory>,
+) -> Request {
+    let text = |key: &str| operation.get(key).and_then(Value::as_str).map(String::from);
+    let name = text("summary")
+        .or_else(|| text("operationId"))
+        .unwrap_or_else(|| format!("{method} {path}"));
+
+    let mut uri = format!("{{{{{BASE_URL_VARIABLE}}}}}{path}");
+    let mut query = vec![];
+    let mut headers = vec![];
+    for parameter in parameters(spec, item, operation) {
+        let Some(name) = parameter.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        let required = parameter
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        match parameter.get("in").and_then(Value::as_str) {
+            // path parameters without examples are kept as `{name}`, so they
+            // are easy to spot and fill in
+            Some("path") => {
+                if let Some(example) = parameter_example(spec, parameter, false) {
+                    uri = uri.replace(&format!("{{{name}}}"), &example);
+                }
+            }
+            // the query editor doesn't exist yet, so only the parameters that
+            // are needed go on the uri
+            Some("query") if required => query.push(format!(
+                "{name}={}",
+                parameter_example(spec, parameter, true).unwrap_or_default()
+            )),
+            Some("header") => headers.push(HeaderMap {
+                pair: (
+                    name.to_string(),
+                    parameter_example(spec, parameter, true).unwrap_or_default(),
+                ),
+                enabled: required,
+            }),
+            _ => {}
+        }
+    }
+    if !query.is_empty() {
+        uri = format!("{uri}?{}", query.join("&"));
+    }
+
+    let body = operation
+        .get("requestBody")
+        .map(|body| resolve(spec, body))
+        .and_then(|body| body_example(spec, body));
+    if body.is_some() {
+        headers.insert(
+            0,
+            HeaderMap {
+                pair: ("Content-Type".into(), "application/json".into()),
+                enabled: true,
+            },
+        );
+    }
+
+    Request {
+        id: uuid::Uuid::new_v4().to_string(),
+        method,
+        name,
+        uri,
+        headers: (!headers.is_empty()).then_some(headers),
+        auth_method: auth_method(spec, operation),
+        parent: directory.map(|directory| directory.id.clone()),
+        body_type: body.as_ref().map(|_| BodyType::Json),
+        body,
+        description: text("description"),
+    }
+}
+
+/// pretty printed example of a json request body
+fn body_example(spec: &Value, body: &Value) -> Option<String> {
+    let content = body.get("content").and_then(Value::as_object)?;
+    let (_, media) = content
+        .iter()
+        .find(|(kind, _)| kind.starts_with("application/json"))
+        .or_else(|| content.iter().find(|(kind, _)| kind.ends_with("+json")))?;
+
+    let example = media
+        .get("example")
+        .cloned()
+        .or_else(|| first_example(spec, media))
+        .or_else(|| {
+            let schema = media.get("schema")?;
+            Some(example_from_schema(spec, schema, 0))
+        })?;
+
+    serde_json::to_string_pretty(&example).ok()
+}
+
+/// the first security scheme of the operation, or of the whole spec, that hac
+/// knows how to send
+fn auth_method(spec: &Value, operation: &Value) -> Option<AuthMethod> {
+    let requirements = operation
+        .get("security")
+        .or_else(|| spec.get("security"))
+        .and_then(Value::as_array)?;
+    let schemes = spec.pointer("/components/securitySchemes")?;
+
+    requirements
+        .iter()
+        .filter_map(Value::as_object)
+        .flat_map(Map::keys)
+        .filter_map(|name| schemes.get(name))
+        .map(|scheme| resolve(spec, scheme))
+        .find_map(|scheme| {
+            let is_http = scheme.get("type").and_then(Value::as_str).eq(&Some("http"));
+            let kind = scheme.get("scheme").and_then(Value::as_str)?;
+            match (is_http, kind.to_ascii_lowercase().as_str()) {
+                (true, "bearer") => Some(AuthMethod::Bearer),
+                (true, "basic") => Some(AuthMethod::Basic),
+                _ => None,
+            }
+        })
+}
+
+/// a value matching `schema`, preferring the examples and defaults written on
+/// the spec, and falling back to placeholders for each type
+pub fn example_from_schema(spec: &Value, schema: &Value, depth: usize) -> Value {
+    example_expanding(spec, schema, depth, &mut vec![])
+}
+
+/// `expanding` holds the references being expanded, a schema referencing one of
+/// them again, like a node with a list of children nodes, becomes `null`
+/// instead of another level of the same schema
+fn example_expanding<'a>(
+    spec: &'a Value,
+    schema: &'a Value,
+    depth: usize,
+    expanding: &mut Vec<&'a str>,
+) -> Value {
+    if depth > MAX_EXAMPLE_DEPTH {
+        return Value::Null;
+    }
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        if expanding.contains(&reference) {
+            return Value::Null;
+        }
+        if let Some(target) = reference
+            .strip_prefix('#')
+            .and_then(|pointer| spec.pointer(pointer))
+        {
+            expanding.push(reference);
+            let example = example_expanding(spec, target, depth, expanding);
+            expanding.pop();
+            return example;
+        }
+    }
+
+    if let Some(example) = schema.get("example") {
+        return example.clone();
+    }
+    // 3.1 schemas have a list of examples instead
+    if let Some(example) = schema
+        .get("examples")
+        .and_then(Value::as_array)
+        .and_then(|examples| examples.first())
+    {
+        return example.clone();
+    }
+    if let Some(value) = schema.get("default").or_else(|| schema.get("const")) {
+        return value.clone();
+    }
+    if let Some(value) = schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|values| values.first())
+    {
+        return value.clone();
+    }
+
+    if let Some(schemas) = schema.get("allOf").and_then(Value::as_array) {
+        let mut merged = Map::new();
+        for schema in schemas {
+            match example_expanding(spec, schema, depth + 1, expanding) {
+                Value::Object(fields) => merged.extend(fields),
+                other if merged.is_empty() => return other,
+                _ => {}
+            }
+        }
+        return Value::Object(merged);
+    }
+    if let Some(schema) = ["oneOf", "anyOf"]
+        .iter()
+        .filter_map(|key| schema.get(key).and_then(Value::as_array))
+        .find_map(|schemas| schemas.first())
+    {
+        return example_expanding(spec, schema, depth + 1, expanding);
+    }
+
+    // 3.1 types may be a list, like `["string", "null"]`
+    let kind = match schema.get("type") {
+        Some(Value::String(kind)) => Some(kind.as_str()),
+        Some(Value::Array(kinds)) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|kind| kind.ne(&"null")),
+        _ => None,
+    };
+    let format = schema.get("format").and_then(Value::as_str);
+
+    match kind {
+        Some("object") | None if schema.get("properties").is_some() => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            Value::Object(
+                properties
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, property)| {
+                        (
+                            name.clone(),
+                            example_expanding(spec, property, depth + 1, expanding),
+                        )
+                    })
+                    .collect(),
+            )
+        }
+        Some("object") => Value::Object(Map::new()),
+        Some("array") => match schema.get("items") {
+            Some(items) => Value::Array(vec![example_expanding(spec, items, depth + 1, expanding)]),
+            None => Value::Array(vec![]),
+        },
+        Some("string") => Value::String(
+            match format {
+                Some("date-time") => "2024-01-01T00:00:00Z",
+                Some("date") => "2024-01-01",
+                Some("email") => "user@example.com",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("uri") | Some("url") => "https://example.com",
+                _ => "string",
+            }
+            .to_string(),
+        ),
+        Some("integer") => Value::from(0),
+        Some("number") => Value::from(0.0),
+        Some("boolean") => Value::Bool(false),
+        _ => Value::Null,
+    }
+}
+
+/// headers OpenAPI doesn't allow as parameters, as they are described by the
+/// request body and the security schemes instead
+const RESERVED_HEADERS: [&str; 3] = ["accept", "content-type", "authorization"];
+
+/// query parameters that shape the response, like paging or sorting, the
+/// values of any other parameter are left out of exported specs
+const SHAREABLE_QUERY_PARAMETERS: [&str; 12] = [
+    "fields", "filter", "format", "include", "lang", "limit", "offset", "order", "page",
+    "per_page", "size", "sort",
+];
+
+/// builds an OpenAPI 3.0 spec describing every request of the collection. Each
+/// request becomes an operation, tagged with the directory it is in, and json
+/// bodies are described by a schema inferred from them. When two requests share
+/// a method and path, only the first one is described
+pub fn export_openapi(collection: &Collection) -> Value {
+    let mut builder = SpecBuilder::default();
+    if let Some(requests) = collection.requests.as_ref() {
+        builder.add_entries(&requests.read().unwrap(), None);
+    }
+
+    let mut info = Map::new();
+    info.insert("title".into(), Value::from(collection.info.name.clone()));
+    if let Some(description) = collection.info.description.as_ref() {
+        info.insert("description".into(), Value::from(description.clone()));
+    }
+    info.insert("version".into(), Value::from("1.0.0"));
+
+    let mut spec = Map::new();
+    spec.insert("openapi".into(), Value::from("3.0.3"));
+    spec.insert("info".into(), Value::Object(info));
+    if let Some(url) = collection
+        .environment
+        .get(BASE_URL_VARIABLE)
+        .filter(|url| !url.is_empty())
+    {
+        spec.insert("servers".into(), json!([{ "url": url }]));
+    }
+    if !builder.tags.is_empty() {
+        spec.insert("tags".into(), Value::Array(builder.tags));
+    }
+    spec.insert("paths".into(), Value::Object(builder.paths));
+    if !builder.security_schemes.is_empty() {
+        spec.insert(
+            "components".into(),
+            json!({ "securitySchemes": builder.security_schemes }),
+        );
+    }
+
+    Value::Object(spec)
+}
+
+#[derive(Default)]
+struct SpecBuilder {
+    paths: Map<String, Value>,
+    tags: Vec<Value>,
+    security_schemes: Map<String, Value>,
+}
+
+impl SpecBuilder {
+    fn add_entries(&mut self, entries: &[RequestKind], tag: Option<&str>) {
+        for entry in entries {
+            match entry {
+                RequestKind::Single(request) => self.add_operation(&request.read().unwrap(), tag),
+                RequestKind::Nested(directory) => {
+                    let mut declared = Map::new();
+                    declared.insert("name".into(), Value::from(directory.name.clone()));
+                    if let Some(description) = directory.description.as_ref() {
+                        declared.insert("description".into(), Value::from(description.clone()));
+                    }
+                    if !self
+                        .tags
+                        .iter()
+                        .any(|tag| tag["name"].eq(&declared["name"]))
+                    {
+                        self.tags.push(Value::Object(declared));
+                    }
+                    self.add_entries(&directory.requests.read().unwrap(), Some(&directory.name));
+                }
+            }
+        }
+    }
+
+    fn add_operation(&mut self, request: &Request, tag: Option<&str>) {
+        let (path, query) = split_uri(&request.uri);
+        let method = request.method.to_string().to_lowercase();
+        let item = self
+            .paths
+            .entry(path.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if item.get(&method).is_some() {
+            return;
+        }
+
+        let mut operation = Map::new();
+        if let Some(tag) = tag {
+            operation.insert("tags".into(), json!([tag]));
+        }
+        operation.insert("summary".into(), Value::from(request.name.clone()));
+        if let Some(description) = request.description.as_ref() {
+            operation.insert("description".into(), Value::from(description.clone()));
+        }
+
+        let path_parameters = path
+            .split('/')
+            .filter(|segment| !segment.starts_with("{{"))
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+            .map(|name| json!({ "name": name, "in": "path", "required": true, "schema": { "type": "string" } }));
+        let query_parameters = query.into_iter().map(|(name, value)| {
+            let mut parameter = json!({
+                "name": name,
+                "in": "query",
+                "required": true,
+                "schema": { "type": "string" },
+            });
+            // query strings carry credentials too, like `?api_key=`, so just
+            // like headers, only the values of well known parameters are kept
+            if SHAREABLE_QUERY_PARAMETERS.contains(&name.to_lowercase().as_str()) {
+                parameter["example"] = Value::from(value);
+            }
+            parameter
+        });
+        let header_parameters = request
+            .headers
+            .iter()
+            .flatten()
+            .filter(|header| !RESERVED_HEADERS.contains(&header.pair.0.to_lowercase().as_str()))
+            .map(|header| {
+                let mut parameter = json!({
+                    "name": header.pair.0,
+                    "in": "header",
+                    "required": header.enabled,
+                    "schema": { "type": "string" },
+                });
+                // specs are handed to whoever consumes the api, so values that
+                // may be credentials, like an api key, are left out
+                if header.is_shareable() {
+                    parameter["example"] = Value::from(header.pair.1.as_str());
+                }
+                parameter
+            });
+        let parameters = path_parameters
+            .chain(query_parameters)
+            .chain(header_parameters)
+            .collect::<Vec<_>>();
+        if !parameters.is_empty() {
+            operation.insert("parameters".into(), Value::Array(parameters));
+        }
+
+        if let Some(body) = request.body.as_ref().filter(|body| !body.trim().is_empty()) {
+            // bodies with variables on them may not be valid json until they
+            // are expanded, so they are kept as they are written
+            let media = match serde_json::from_str::<Value>(body) {
+                Ok(example) => {
+                    json!({ "schema": schema_from_example(&example), "example": example })
+                }
+                Err(_) => json!({ "example": body }),
+            };
+            operation.insert(
+                "requestBody".into(),
+                json!({ "content": { "application/json": media } }),
+            );
+        }
+
+        if let Some(auth_method) = request.auth_method.as_ref() {
+            let scheme = match auth_method {
+                AuthMethod::Bearer => "bearer",
+                AuthMethod::Basic => "basic",
+            };
+            self.security_schemes
+                .insert(scheme.into(), json!({ "type": "http", "scheme": scheme }));
+            operation.insert("security".into(), json!([{ scheme: [] }]));
+        }
+
+        // collections have no saved example responses to infer schemas from,
+        // but every operation needs a response
+        operation.insert(
+            "responses".into(),
+            json!({ "default": { "description": "response" } }),
+        );
+
+        if let Value::Object(item) = item {
+            item.insert(method, Value::Object(operation));
+        }
+    }
+}
+
+/// path of an uri relative to the server it is sent to, along with the pairs
+/// of its query string. Uris starting with `{{base_url}}` are relative to the
+/// server on the environment, while every other uri loses its host
+fn split_uri(uri: &str) -> (String, Vec<(String, String)>) {
+    let base_url = format!("{{{{{BASE_URL_VARIABLE}}}}}");
+    let relative = match uri.strip_prefix(&base_url) {
+        Some(relative) => relative,
+        None => {
+            let uri = uri.split_once("://").map_or(uri, |(_, rest)| rest);
+            uri.find('/').map_or("", |idx| &uri[idx..])
+        }
+    };
+
+    let (path, query) = relative.split_once('?').unwrap_or((relative, ""));
+    let path = match path.trim_end_matches('/') {
+        path if path.starts_with('/') => path.to_string(),
+        path => format!("/{path}"),
+    };
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (name.to_string(), value.to_string())
+        })
+        .collect();
+
+    (path, query)
+}
+
+/// a schema describing values shaped like `example`
+pub fn schema_from_example(example: &Value) -> Value {
+    match example {
+        Value::Null => json!({ "nullable": true }),
+        Value::Bool(_) => json!({ "type": "boolean" }),
+        Value::Number(number) if number.is_f64() => json!({ "type": "number" }),
+        Value::Number(_) => json!({ "type": "integer" }),
+        Value::String(_) => json!({ "type": "string" }),
+        Value::Array(items) => json!({
+            "type": "array",
+            "items": items.first().map(schema_from_example).unwrap_or_else(|| json!({})),
+        }),
+        Value::Object(fields) => json!({
+            "type": "object",
+            "properties": fields
+                .iter()
+                .map(|(name, value)| (name.clone(), schema_from_example(value)))
+                .collect::<Map<_, _>>(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static SPEC: &str = r##"
+openapi: 3.0.3
+info:
+  title: Users API
+  description: manages users
+  version: "1.0"
+servers:
+  - url: https://{region}.api.com/v1/
+    variables:
+      region:
+        default: eu
+security:
+  - token: []
+tags:
+  - name: users
+    description: everything about users
+  - name: unused
+paths:
+  /users/{id}:
+    parameters:
+      - name: id
+        in: path
+        required: true
+        schema:
+          type: integer
+    get:
+      tags: [users]
+      summary: Get user
+      description: fetches a **single** user
+      parameters:
+        - name: X-Trace
+          in: header
+          schema:
+            type: string
+        - name: fields
+          in: query
+          required: true
+          example: name
+    put:
+      tags: [users]
+      operationId: updateUser
+      security:
+        - basic: []
+      requestBody:
+        $ref: "#/components/requestBodies/User"
+  /health:
+    get:
+      security: []
+components:
+  securitySchemes:
+    token:
+      type: http
+      scheme: bearer
+    basic:
+      type: http
+      scheme: basic
+  requestBodies:
+    User:
+      content:
+        application/json:
+          schema:
+            $ref: "#/components/schemas/User"
+  schemas:
+    User:
+      type: object
+      properties:
+        id:
+          type: string
+          format: uuid
+        name:
+          type: string
+          example: john
+        role:
+          type: string
+          enum: [admin, member]
+        tags:
+          type: array
+          items:
+            type: string
+        manager:
+          $ref: "#/components/schemas/User"
+"##;
+
+    fn request_at(requests: &[RequestKind], idx: usize) -> Request {
+        match &requests[idx] {
+            RequestKind::Single(request) => request.read().unwrap().clone(),
+            RequestKind::Nested(_) => panic!("expected a request"),
+        }
+    }
+
+    #[test]
+    fn test_importing_openapi_specs() {
+        let collection = import_openapi(SPEC).unwrap();
+
+        assert_eq!(collection.info.name, "Users API");
+        assert_eq!(
+            collection.info.description.as_deref(),
+            Some("manages users")
+        );
+        assert_eq!(
+            collection
+                .environment
+                .get(BASE_URL_VARIABLE)
+                .map(String::as_str),
+            Some("https://eu.api.com/v1")
+        );
+
+        let requests = collection.requests.as_ref().unwrap().read().unwrap();
+        // unused tags don't create empty directories
+        assert_eq!(requests.len(), 2);
+        let RequestKind::Nested(users) = &requests[0] else {
+            panic!("expected a directory");
+        };
+        assert_eq!(users.name, "users");
+        assert_eq!(users.description.as_deref(), Some("everything about users"));
+
+        let users_requests = users.requests.read().unwrap();
+        let get_user = request_at(&users_requests, 0);
+        assert_eq!(get_user.name, "Get user");
+        assert_eq!(get_user.method, RequestMethod::Get);
+        assert_eq!(get_user.uri, "{{base_url}}/users/{id}?fields=name");
+        assert_eq!(get_user.parent.as_ref(), Some(&users.id));
+        assert_eq!(get_user.auth_method, Some(AuthMethod::Bearer));
+        assert_eq!(
+            get_user.description.as_deref(),
+            Some("fetches a **single** user")
+        );
+        let headers = get_user.headers.unwrap();
+        assert_eq!(headers[0].pair, ("X-Trace".into(), "string".into()));
+        assert!(!headers[0].enabled);
+
+        let update_user = request_at(&users_requests, 1);
+        assert_eq!(update_user.name, "updateUser");
+        assert_eq!(update_user.auth_method, Some(AuthMethod::Basic));
+        assert_eq!(update_user.body_type, Some(BodyType::Json));
+        let body: Value = serde_json::from_str(update_user.body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["id"], json!("00000000-0000-0000-0000-000000000000"));
+        assert_eq!(body["name"], json!("john"));
+        assert_eq!(body["role"], json!("admin"));
+        assert_eq!(body["tags"], json!(["string"]));
+        // self referencing schemas are expanded once instead of recursing forever
+        assert_eq!(body["manager"], Value::Null);
+
+        let health = request_at(&requests, 1);
+        assert_eq!(health.name, "GET /health");
+        assert_eq!(health.uri, "{{base_url}}/health");
+        assert!(health.auth_method.is_none());
+        assert!(health.parent.is_none());
+    }
+
+    #[test]
+    fn test_importing_json_specs() {
+        let spec = json!({
+            "openapi": "3.1.0",
+            "info": { "title": "Orders" },
+            "paths": {
+                "/orders": {
+                    "post": {
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "examples": { "simple": { "value": { "total": 10 } } }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+        let collection = import_openapi(&spec.to_string()).unwrap();
+
+        assert_eq!(collection.environment[BASE_URL_VARIABLE], "");
+        let requests = collection.requests.as_ref().unwrap().read().unwrap();
+        let create_order = request_at(&requests, 0);
+        assert_eq!(create_order.uri, "{{base_url}}/orders");
+        assert_eq!(create_order.body.as_deref(), Some("{\n  \"total\": 10\n}"));
+
+        assert!(import_openapi(r#"{ "swagger": "2.0" }"#).is_err());
+        assert!(import_openapi("openapi: 2.0").is_err());
+    }
+
+    #[test]
+    fn test_exporting_collections() {
+        let collection = import_openapi(SPEC).unwrap();
+        let spec = export_openapi(&collection);
+
+        assert_eq!(spec["info"]["title"], json!("Users API"));
+        assert_eq!(spec["servers"][0]["url"], json!("https://eu.api.com/v1"));
+        // only tags with requests were imported as directories
+        assert_eq!(
+            spec["tags"],
+            json!([{ "name": "users", "description": "everything about users" }])
+        );
+
+        let get_user = &spec["paths"]["/users/{id}"]["get"];
+        assert_eq!(get_user["tags"], json!(["users"]));
+        assert_eq!(get_user["summary"], json!("Get user"));
+        assert_eq!(get_user["security"], json!([{ "bearer": [] }]));
+        let parameters = get_user["parameters"].as_array().unwrap();
+        assert_eq!(parameters[0]["name"], json!("id"));
+        assert_eq!(parameters[0]["in"], json!("path"));
+        assert_eq!(parameters[1]["example"], json!("name"));
+        assert_eq!(parameters[2]["name"], json!("X-Trace"));
+        assert_eq!(parameters[2]["required"], json!(false));
+        assert!(parameters[2].get("example").is_none());
+
+        let update_user = &spec["paths"]["/users/{id}"]["put"];
+        // content type is described by the request body instead
+        assert_eq!(update_user["parameters"].as_array().unwrap().len(), 1);
+        let body = &update_user["requestBody"]["content"]["application/json"];
+        assert_eq!(body["example"]["name"], json!("john"));
+        assert_eq!(
+            body["schema"]["properties"]["name"],
+            json!({ "type": "string" })
+        );
+        assert_eq!(
+            body["schema"]["properties"]["tags"]["items"]["type"],
+            json!("string")
+        );
+        assert_eq!(
+            spec["components"]["securitySchemes"]["basic"],
+            json!({ "type": "http", "scheme": "basic" })
+        );
+
+        let health = &spec["paths"]["/health"]["get"];
+        assert!(health.get("tags").is_none());
+        assert!(health["responses"]["default"].is_object());
+
+        // what is exported can be imported back
+        let imported = import_openapi(&spec.to_string()).unwrap();
+        let requests = imported.requests.as_ref().unwrap().read().unwrap();
+        let RequestKind::Nested(users) = &requests[0] else {
+            panic!("expected a directory");
+        };
+        let users_requests = users.requests.read().unwrap();
+        assert_eq!(
+            request_at(&users_requests, 0).uri,
+            "{{base_url}}/users/{id}?fields=name"
+        );
+        assert_eq!(request_at(&requests, 1).uri, "{{base_url}}/health");
+    }
+
+    #[test]
+    fn test_exporting_query_parameters() {
+        let spec = json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Search API" },
+            "paths": {
+                "/search": {
+                    "get": {
+                        "parameters": [
+                            { "name": "page", "in": "query", "required": true, "example": "2" },
+                            { "name": "api_key", "in": "query", "required": true, "example": "secret" }
+                        ]
+                    }
+                }
+            }
+        });
+        let collection = import_openapi(&spec.to_string()).unwrap();
+        let spec = export_openapi(&collection);
+
+        let parameters = spec["paths"]["/search"]["get"]["parameters"]
+            .as_array()
+            .unwrap();
+        assert_eq!(parameters[0]["name"], json!("page"));
+        assert_eq!(parameters[0]["example"], json!("2"));
+        assert_eq!(parameters[1]["name"], json!("api_key"));
+        assert!(parameters[1].get("example").is_none());
+        assert!(!spec.to_string().contains("secret"));
+    }
+
+    #[test]
+    fn test_examples_of_self_referencing_schemas() {
+        let spec = json!({
+            "components": {
+                "schemas": {
+                    "Node": {
+                        "type": "object",
+                        "properties": {
+                            "value": { "type": "integer" },
+                            "parent": { "$ref": "#/components/schemas/Node" },
+                            "children": {
+                                "type": "array",
+                                "items": { "$ref": "#/components/schemas/Node" }
+                            }
+                        }
+                    },
+                    "Loop": { "$ref": "#/components/schemas/Loop" }
+                }
+            }
+        });
+
+        let node = json!({ "$ref": "#/components/schemas/Node" });
+        assert_eq!(
+            example_from_schema(&spec, &node, 0),
+            json!({ "value": 0, "parent": null, "children": [null] })
+        );
+        let looping = json!({ "$ref": "#/components/schemas/Loop" });
+        assert_eq!(example_from_schema(&spec, &looping, 0), Value::Null);
+    }
+
+    #[test]
+    fn test_splitting_uris() {
+        assert_eq!(
+            split_uri("https://api.com/users/?page=2&all"),
+            (
+                "/users".to_string(),
+                vec![
+                    ("page".to_string(), "2".to_string()),
+                    ("all".to_string(), String::new())
+                ]
+            )
+        );
+        assert_eq!(split_uri("https://api.com").0, "/");
+        assert_eq!(split_uri("{{base_url}}/health").0, "/health");
+        assert_eq!(split_uri("localhost:3000/health").0, "/health");
+    }
+}
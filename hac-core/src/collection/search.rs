@@ -0,0 +1,267 @@
+use crate::collection::types::{Request, RequestKind};
+
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+
+/// matching a character right after the previous match, like typing a word
+const CONSECUTIVE_BONUS: i64 = 5;
+/// matching the first character of a word, like `cu` for `create user`
+const WORD_START_BONUS: i64 = 3;
+
+/// where `pattern` matched on a text, and how good the match is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    /// char indices of the text that matched the pattern, in order
+    pub positions: Vec<usize>,
+}
+
+/// a request matching a search, along with the directories holding it
+#[derive(Debug, Clone)]
+pub struct RequestMatch {
+    pub request: Arc<RwLock<Request>>,
+    /// names of the directories holding the request, like `users / accounts`
+    pub path: Option<String>,
+    /// the text the query was matched against, see [`request_haystack`]
+    pub haystack: String,
+    pub score: i64,
+    pub positions: Vec<usize>,
+}
+
+/// matches every character of `pattern`, in order, anywhere on `text`, ignoring
+/// case and whitespace on the pattern. Matches that are close together, or that
+/// start words, score higher
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let text = text.chars().collect::<Vec<_>>();
+    let mut positions = vec![];
+    let mut score = 0;
+    let mut cursor = 0;
+
+    for needle in pattern.chars().filter(|c| !c.is_whitespace()) {
+        let offset = text[cursor..]
+            .iter()
+            .position(|c| c.to_lowercase().eq(needle.to_lowercase()))?;
+        let idx = cursor + offset;
+
+        score += 1;
+        match positions.last() {
+            Some(&last) if idx.eq(&(last + 1)) => score += CONSECUTIVE_BONUS,
+            Some(_) => score -= offset as i64,
+            None => {}
+        }
+        if idx.eq(&0) || !text[idx - 1].is_alphanumeric() {
+            score += WORD_START_BONUS;
+        }
+
+        positions.push(idx);
+        cursor = idx + 1;
+    }
+
+    Some(FuzzyMatch { score, positions })
+}
+
+/// what a request is matched against when searching, which is its method, name
+/// and uri, like `GET list users /users`
+pub fn request_haystack(request: &Request) -> String {
+    format!("{} {} {}", request.method, request.name, request.uri)
+}
+
+/// every request on the tree matching `query`, at any depth, best matches first.
+/// An empty query matches every request, in the order they are on the tree
+pub fn find_requests(tree: &[RequestKind], query: &str) -> Vec<RequestMatch> {
+    fn inner(
+        items: &[RequestKind],
+        query: &str,
+        path: &mut Vec<String>,
+        matches: &mut Vec<RequestMatch>,
+    ) {
+        for item in items {
+            match item {
+                RequestKind::Single(request) => {
+                    let haystack = request_haystack(&request.read().unwrap());
+                    let Some(found) = fuzzy_match(query, &haystack) else {
+                        continue;
+                    };
+                    matches.push(RequestMatch {
+                        request: request.clone(),
+                        path: (!path.is_empty()).then(|| path.join(" / ")),
+                        haystack,
+                        score: found.score,
+                        positions: found.positions,
+                    });
+                }
+                RequestKind::Nested(dir) => {
+                    path.push(dir.name.clone());
+                    inner(&dir.requests.read().unwrap(), query, path, matches);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    let mut matches = vec![];
+    inner(tree, query, &mut vec![], &mut matches);
+    // sorting is stable, so equally good matches keep the order of the tree
+    matches.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+    matches
+}
+
+/// char ranges of every occurrence of `filter` on `text`, ignoring case
+pub fn substring_ranges(text: &str, filter: &str) -> Vec<Range<usize>> {
+    let text = text.chars().collect::<Vec<_>>();
+    let filter = filter.chars().collect::<Vec<_>>();
+    if filter.is_empty() || filter.len() > text.len() {
+        return vec![];
+    }
+
+    let mut ranges = vec![];
+    let mut start = 0;
+    while start + filter.len() <= text.len() {
+        let is_match = text[start..start + filter.len()]
+            .iter()
+            .zip(filter.iter())
+            .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()));
+        match is_match {
+            true => {
+                ranges.push(start..start + filter.len());
+                start += filter.len();
+            }
+            false => start += 1,
+        }
+    }
+    ranges
+}
+
+/// whether `filter` is anywhere on the name, uri or method of a request
+pub fn request_matches_filter(request: &Request, filter: &str) -> bool {
+    [&request.name, &request.uri, &request.method.to_string()]
+        .iter()
+        .any(|field| !substring_ranges(field, filter).is_empty())
+}
+
+/// ids of every request matching `filter`, along with every directory holding
+/// one of them, which is everything that stays visible when filtering the tree
+pub fn filter_tree(tree: &[RequestKind], filter: &str) -> HashSet<String> {
+    fn inner(items: &[RequestKind], filter: &str, visible: &mut HashSet<String>) -> bool {
+        let mut any_visible = false;
+        for item in items {
+            let is_visible = match item {
+                RequestKind::Single(request) => {
+                    request_matches_filter(&request.read().unwrap(), filter)
+                }
+                RequestKind::Nested(dir) => inner(&dir.requests.read().unwrap(), filter, visible),
+            };
+            if is_visible {
+                visible.insert(item.get_id());
+                any_visible = true;
+            }
+        }
+        any_visible
+    }
+
+    let mut visible = HashSet::new();
+    inner(tree, filter, &mut visible);
+    visible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::types::{Directory, RequestMethod};
+
+    fn make_request(id: &str, method: RequestMethod, uri: &str) -> RequestKind {
+        RequestKind::Single(Arc::new(RwLock::new(Request {
+            id: id.to_string(),
+            method,
+            name: id.to_string(),
+            uri: uri.to_string(),
+            headers: None,
+            auth_method: None,
+            parent: None,
+            body: None,
+            body_type: None,
+            description: None,
+        })))
+    }
+
+    #[test]
+    fn test_fuzzy_matching() {
+        let found = fuzzy_match("cu", "create user").unwrap();
+        assert_eq!(found.positions, vec![0, 7]);
+
+        assert!(fuzzy_match("CREATE", "create user").is_some());
+        assert!(fuzzy_match("user create", "create user").is_none());
+        assert!(fuzzy_match("", "anything").unwrap().positions.is_empty());
+
+        // closer matches are better matches
+        let tight = fuzzy_match("user", "create user").unwrap();
+        let loose = fuzzy_match("user", "update status error").unwrap();
+        assert!(tight.score > loose.score);
+    }
+
+    #[test]
+    fn test_finding_nested_requests() {
+        let tree = vec![
+            RequestKind::Nested(Directory {
+                id: "users".into(),
+                name: "users".into(),
+                requests: Arc::new(RwLock::new(vec![RequestKind::Nested(Directory {
+                    id: "accounts".into(),
+                    name: "accounts".into(),
+                    requests: Arc::new(RwLock::new(vec![make_request(
+                        "create account",
+                        RequestMethod::Post,
+                        "/users/accounts",
+                    )])),
+                    description: None,
+                })])),
+                description: None,
+            }),
+            make_request("health", RequestMethod::Get, "/health"),
+        ];
+
+        assert_eq!(find_requests(&tree, "").len(), 2);
+
+        let matches = find_requests(&tree, "post acc");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].path, Some("users / accounts".into()));
+
+        let matches = find_requests(&tree, "/health");
+        assert_eq!(matches[0].request.read().unwrap().id, "health");
+        assert!(find_requests(&tree, "delete").is_empty());
+    }
+
+    #[test]
+    fn test_filtering_keeps_ancestors() {
+        let tree = vec![
+            RequestKind::Nested(Directory {
+                id: "users".into(),
+                name: "users".into(),
+                requests: Arc::new(RwLock::new(vec![
+                    make_request("list", RequestMethod::Get, "/users"),
+                    make_request("create", RequestMethod::Post, "/users"),
+                ])),
+                description: None,
+            }),
+            RequestKind::Nested(Directory {
+                id: "empty".into(),
+                name: "empty".into(),
+                requests: Arc::default(),
+                description: None,
+            }),
+            make_request("health", RequestMethod::Get, "/health"),
+        ];
+
+        let visible = filter_tree(&tree, "post");
+        assert_eq!(visible, HashSet::from(["users".into(), "create".into()]));
+
+        let visible = filter_tree(&tree, "get");
+        assert_eq!(visible.len(), 3);
+        assert!(!visible.contains("empty"));
+
+        assert_eq!(substring_ranges("List Users", "us"), vec![5..7]);
+        assert_eq!(substring_ranges("aaaa", "AA"), vec![0..2, 2..4]);
+        assert!(substring_ranges("list", "").is_empty());
+    }
+}
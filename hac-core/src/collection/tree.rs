@@ -0,0 +1,393 @@
+//! requests of a collection form a tree, where directories can hold requests as
+//! well as other directories, as deep as needed. Every helper here works on any
+//! depth of the tree, so callers don't have to care where an item lives
+
+use crate::collection::types::{Directory, Request, RequestKind};
+
+use std::sync::{Arc, RwLock};
+
+/// finds an item by its id anywhere on the tree
+pub fn find_entry(tree: &[RequestKind], id: &str) -> Option<RequestKind> {
+    tree.iter().find_map(|item| match item {
+        _ if item.get_id().eq(id) => Some(item.clone()),
+        RequestKind::Nested(dir) => find_entry(&dir.requests.read().unwrap(), id),
+        RequestKind::Single(_) => None,
+    })
+}
+
+/// runs `f` on the item with the given id, wherever it lives on the tree, this
+/// is how items that are not behind a lock, like directories, are changed
+pub fn update_entry<F, R>(tree: &mut [RequestKind], id: &str, f: F) -> Option<R>
+where
+    F: FnOnce(&mut RequestKind) -> R,
+{
+    fn inner<R>(
+        tree: &mut [RequestKind],
+        id: &str,
+        f: &mut Option<impl FnOnce(&mut RequestKind) -> R>,
+    ) -> Option<R> {
+        for item in tree.iter_mut() {
+            if item.get_id().eq(id) {
+                return f.take().map(|f| f(item));
+            }
+            if let RequestKind::Nested(dir) = item {
+                if let Some(result) = inner(&mut dir.requests.write().unwrap(), id, f) {
+                    return Some(result);
+                }
+            }
+        }
+        None
+    }
+
+    inner(tree, id, &mut Some(f))
+}
+
+/// id of the directory holding the item with the given id, `None` when the item
+/// is on the root of the tree, or when it doesn't exist at all
+pub fn parent_of(tree: &[RequestKind], id: &str) -> Option<String> {
+    tree.iter().find_map(|item| match item {
+        RequestKind::Nested(dir) => {
+            let children = dir.requests.read().unwrap();
+            match children.iter().any(|child| child.get_id().eq(id)) {
+                true => Some(dir.id.clone()),
+                false => parent_of(&children, id),
+            }
+        }
+        RequestKind::Single(_) => None,
+    })
+}
+
+/// ids of every directory holding the item with the given id, from its parent
+/// all the way up to a directory on the root
+pub fn ancestors_of(tree: &[RequestKind], id: &str) -> Vec<String> {
+    let mut ancestors = vec![];
+    let mut current = id.to_string();
+    while let Some(parent) = parent_of(tree, &current) {
+        ancestors.push(parent.clone());
+        current = parent;
+    }
+    ancestors
+}
+
+/// every directory on the tree, in the order they are displayed, along with how
+/// deep they are, starting from 0 for directories on the root
+pub fn directories(tree: &[RequestKind]) -> Vec<(Directory, usize)> {
+    fn inner(tree: &[RequestKind], depth: usize, directories: &mut Vec<(Directory, usize)>) {
+        for item in tree {
+            if let RequestKind::Nested(dir) = item {
+                directories.push((dir.clone(), depth));
+                inner(&dir.requests.read().unwrap(), depth + 1, directories);
+            }
+        }
+    }
+
+    let mut result = vec![];
+    inner(tree, 0, &mut result);
+    result
+}
+
+/// names of every directory from the root down to the directory with the given
+/// id, including itself, like `users / accounts`
+pub fn directory_path(tree: &[RequestKind], id: &str) -> Option<String> {
+    fn inner(tree: &[RequestKind], id: &str, path: &mut Vec<String>) -> bool {
+        for item in tree {
+            if let RequestKind::Nested(dir) = item {
+                path.push(dir.name.clone());
+                if dir.id.eq(id) || inner(&dir.requests.read().unwrap(), id, path) {
+                    return true;
+                }
+                path.pop();
+            }
+        }
+        false
+    }
+
+    let mut path = vec![];
+    inner(tree, id, &mut path).then(|| path.join(" / "))
+}
+
+/// removes the item with the given id from anywhere on the tree, returning it
+pub fn remove_entry(tree: &mut Vec<RequestKind>, id: &str) -> Option<RequestKind> {
+    if let Some(idx) = tree.iter().position(|item| item.get_id().eq(id)) {
+        return Some(tree.remove(idx));
+    }
+
+    tree.iter().find_map(|item| match item {
+        RequestKind::Nested(dir) => remove_entry(&mut dir.requests.write().unwrap(), id),
+        RequestKind::Single(_) => None,
+    })
+}
+
+/// inserts `entry` as the last item of the directory with id `parent`, or on the
+/// root of the tree when there is no parent. When the parent cannot be found
+/// the entry is given back, so it doesn't get lost
+pub fn insert_entry(
+    tree: &mut Vec<RequestKind>,
+    parent: Option<&str>,
+    entry: RequestKind,
+) -> Result<(), RequestKind> {
+    let Some(parent) = parent else {
+        tree.push(entry);
+        return Ok(());
+    };
+
+    let mut entry = Some(entry);
+    update_entry(tree, parent, |item| {
+        if let RequestKind::Nested(dir) = item {
+            dir.requests.write().unwrap().push(entry.take().unwrap());
+        }
+    });
+
+    match entry {
+        Some(entry) => Err(entry),
+        None => Ok(()),
+    }
+}
+
+/// inserts `entry` right after the item with id `sibling`, on whatever directory
+/// it lives. When the sibling cannot be found the entry is given back
+pub fn insert_after(
+    tree: &mut Vec<RequestKind>,
+    sibling: &str,
+    entry: RequestKind,
+) -> Result<(), RequestKind> {
+    if let Some(idx) = tree.iter().position(|item| item.get_id().eq(sibling)) {
+        tree.insert(idx + 1, entry);
+        return Ok(());
+    }
+
+    let mut entry = Some(entry);
+    for item in tree.iter() {
+        if let RequestKind::Nested(dir) = item {
+            match insert_after(
+                &mut dir.requests.write().unwrap(),
+                sibling,
+                entry.take().unwrap(),
+            ) {
+                Ok(()) => return Ok(()),
+                Err(back) => entry = Some(back),
+            }
+        }
+    }
+
+    Err(entry.unwrap())
+}
+
+/// moves the item with the given id `offset` positions among its siblings, an
+/// item never leaves its directory this way. Returns whether the item moved
+pub fn move_sibling(tree: &mut Vec<RequestKind>, id: &str, offset: isize) -> bool {
+    if let Some(idx) = tree.iter().position(|item| item.get_id().eq(id)) {
+        let Some(target) = idx.checked_add_signed(offset).filter(|&t| t < tree.len()) else {
+            return false;
+        };
+        let item = tree.remove(idx);
+        tree.insert(target, item);
+        return true;
+    }
+
+    tree.iter().any(|item| match item {
+        RequestKind::Nested(dir) => move_sibling(&mut dir.requests.write().unwrap(), id, offset),
+        RequestKind::Single(_) => false,
+    })
+}
+
+/// whether the item with id `id` is `ancestor` itself, or anywhere inside of it
+pub fn is_within(tree: &[RequestKind], ancestor: &str, id: &str) -> bool {
+    match find_entry(tree, ancestor) {
+        _ if ancestor.eq(id) => true,
+        Some(RequestKind::Nested(dir)) => find_entry(&dir.requests.read().unwrap(), id).is_some(),
+        _ => false,
+    }
+}
+
+/// points a request to the directory now holding it. Directories don't store
+/// their parent, and their children keep pointing to them, so they are left as is
+pub fn set_parent(entry: &RequestKind, parent: Option<&str>) {
+    if let RequestKind::Single(request) = entry {
+        request.write().unwrap().parent = parent.map(String::from);
+    }
+}
+
+/// copies `entry` and everything inside of it, giving every copied item a new
+/// id from `new_id`. Requests are shared behind locks, so cloning a
+/// `RequestKind` would give us the very same requests, which is not a copy
+pub fn deep_copy(
+    entry: &RequestKind,
+    parent: Option<&str>,
+    new_id: &mut impl FnMut() -> String,
+) -> RequestKind {
+    match entry {
+        RequestKind::Single(request) => {
+            let mut request = request.read().unwrap().clone();
+            request.id = new_id();
+            request.parent = parent.map(String::from);
+            RequestKind::Single(Arc::new(RwLock::new(request)))
+        }
+        RequestKind::Nested(dir) => {
+            let id = new_id();
+            let requests = dir
+                .requests
+                .read()
+                .unwrap()
+                .iter()
+                .map(|child| deep_copy(child, Some(&id), new_id))
+                .collect();
+
+            RequestKind::Nested(Directory {
+                id,
+                name: dir.name.clone(),
+                requests: Arc::new(RwLock::new(requests)),
+                description: dir.description.clone(),
+            })
+        }
+    }
+}
+
+/// puts `request` in place of the request with the same id, wherever it is
+pub fn replace_request(tree: &mut [RequestKind], request: &Arc<RwLock<Request>>) {
+    let id = request.read().unwrap().id.clone();
+    update_entry(tree, &id, |item| {
+        if let RequestKind::Single(inner) = item {
+            *inner = request.clone();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::types::RequestMethod;
+
+    fn make_request(id: &str) -> RequestKind {
+        RequestKind::Single(Arc::new(RwLock::new(Request {
+            id: id.to_string(),
+            method: RequestMethod::Get,
+            name: id.to_string(),
+            uri: String::default(),
+            headers: None,
+            auth_method: None,
+            parent: None,
+            body: None,
+            body_type: None,
+            description: None,
+        })))
+    }
+
+    fn make_dir(id: &str, requests: Vec<RequestKind>) -> RequestKind {
+        RequestKind::Nested(Directory {
+            id: id.to_string(),
+            name: id.to_string(),
+            requests: Arc::new(RwLock::new(requests)),
+            description: None,
+        })
+    }
+
+    /// users -> accounts -> { create, admin }, with a `health` request at the root
+    fn make_tree() -> Vec<RequestKind> {
+        vec![
+            make_dir(
+                "users",
+                vec![make_dir(
+                    "accounts",
+                    vec![make_request("create"), make_dir("admin", vec![])],
+                )],
+            ),
+            make_request("health"),
+        ]
+    }
+
+    #[test]
+    fn test_finding_nested_items() {
+        let tree = make_tree();
+
+        assert!(find_entry(&tree, "create").is_some());
+        assert!(find_entry(&tree, "missing").is_none());
+        assert_eq!(parent_of(&tree, "create"), Some("accounts".into()));
+        assert_eq!(parent_of(&tree, "accounts"), Some("users".into()));
+        assert_eq!(parent_of(&tree, "health"), None);
+        assert_eq!(ancestors_of(&tree, "create"), vec!["accounts", "users"]);
+        assert!(ancestors_of(&tree, "health").is_empty());
+        assert_eq!(
+            directory_path(&tree, "admin"),
+            Some("users / accounts / admin".into())
+        );
+
+        let directories = directories(&tree)
+            .into_iter()
+            .map(|(dir, depth)| (dir.id, depth))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            directories,
+            vec![
+                ("users".into(), 0),
+                ("accounts".into(), 1),
+                ("admin".into(), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_moving_nested_items() {
+        let mut tree = make_tree();
+
+        let create = remove_entry(&mut tree, "create").unwrap();
+        assert!(find_entry(&tree, "create").is_none());
+        insert_entry(&mut tree, Some("admin"), create).unwrap();
+        assert_eq!(parent_of(&tree, "create"), Some("admin".into()));
+
+        let health = remove_entry(&mut tree, "health").unwrap();
+        assert!(insert_entry(&mut tree, Some("missing"), health).is_err());
+
+        update_entry(&mut tree, "admin", |item| {
+            if let RequestKind::Nested(dir) = item {
+                dir.name = "administrators".into();
+            }
+        });
+        assert_eq!(
+            directory_path(&tree, "admin"),
+            Some("users / accounts / administrators".into())
+        );
+    }
+
+    #[test]
+    fn test_reordering_and_copying_items() {
+        let mut tree = make_tree();
+
+        assert!(move_sibling(&mut tree, "admin", -1));
+        assert!(!move_sibling(&mut tree, "admin", -1));
+        let RequestKind::Nested(accounts) = find_entry(&tree, "accounts").unwrap() else {
+            unreachable!()
+        };
+        let ids = accounts
+            .requests
+            .read()
+            .unwrap()
+            .iter()
+            .map(|item| item.get_id())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["admin", "create"]);
+
+        assert!(is_within(&tree, "users", "create"));
+        assert!(is_within(&tree, "admin", "admin"));
+        assert!(!is_within(&tree, "admin", "users"));
+
+        let mut next_id = 0;
+        let copy = deep_copy(
+            &RequestKind::Nested(accounts.clone()),
+            Some("users"),
+            &mut || {
+                next_id += 1;
+                format!("copy-{next_id}")
+            },
+        );
+        insert_after(&mut tree, "accounts", copy).unwrap();
+        assert_eq!(parent_of(&tree, "copy-1"), Some("users".into()));
+        let RequestKind::Single(create) = find_entry(&tree, "copy-3").unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(create.read().unwrap().parent, Some("copy-1".into()));
+        assert_eq!(create.read().unwrap().name, "create");
+        // the original is left untouched
+        assert!(find_entry(&tree, "create").is_some());
+    }
+}
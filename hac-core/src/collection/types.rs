@@ -1,27 +1,41 @@
+use std::collections::BTreeMap;
 use std::hash::Hash;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
+use hac_config::CollectionLayout;
 use serde::{Deserialize, Serialize};
 
 /// a collection is represented as a file on the file system and holds every
 /// request and metadata
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Collection {
+    /// version of the collection format, collections from older versions are
+    /// upgraded when loaded, see `migrations`
+    pub version: u32,
     /// basic information about the collection such as name and description
     pub info: Info,
     /// maybe a vector of `RequestKind` that are part of the collection
     pub requests: Option<Arc<RwLock<Vec<RequestKind>>>>,
+    /// variables available to every request of the collection, `{{name}}` on
+    /// the uri, headers or body of a request is replaced by the value of `name`
+    /// when the request is sent, see `environment::expand_request`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub environment: BTreeMap<String, String>,
     /// path is a virtual field used only during runtime to know where to
     /// sync the file, this will be the absolute path to the file on the
     /// users computer
     #[serde(skip)]
     pub path: PathBuf,
+    /// layout the collection is stored with, when using the directory layout,
+    /// `path` points to the collection directory instead of to a file
+    #[serde(skip)]
+    pub layout: CollectionLayout,
 }
 
 /// we store requests on a collection and on directories as a enum that could
 /// be either an request or a directory. This enables us to have nested
-/// directories, although we don't support that now and might not ever support.
+/// directories, as deep as needed, see `tree` for helpers that work on any depth.
 ///
 /// Single means its a request
 /// Nested means its a directory
@@ -64,12 +78,32 @@ impl RequestKind {
 /// represents name/value of a header, and wether it is enabled or not.
 ///
 /// disabled headers should not be sent on requests
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct HeaderMap {
     pub pair: (String, String),
     pub enabled: bool,
 }
 
+/// headers whose values describe the request, rather than who is sending it,
+/// everything else may hold a token or an api key
+const SHAREABLE_HEADERS: [&str; 7] = [
+    "accept",
+    "accept-encoding",
+    "accept-language",
+    "cache-control",
+    "content-language",
+    "content-type",
+    "user-agent",
+];
+
+impl HeaderMap {
+    /// whether the value of this header can be written to files meant to be
+    /// shared, like generated docs, without leaking credentials
+    pub fn is_shareable(&self) -> bool {
+        SHAREABLE_HEADERS.contains(&self.pair.0.to_lowercase().as_str())
+    }
+}
+
 /// set of methods we currently support on HTTP requests
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "UPPERCASE")]
@@ -153,7 +187,7 @@ impl RequestMethod {
 /// This is how we store a request on the system, basically this stores all
 /// needed information about a request to be able to perform any actions we
 /// allow.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Request {
     /// we store an uuid on each request to be able to easily identify them
     /// as identifying by name is
@@ -181,6 +215,10 @@ pub struct Request {
     /// the type of the body to be used, like `application/json` or any other
     /// accepted body type
     pub body_type: Option<BodyType>,
+    /// what the request is for, written in Markdown, this is what `hac docs`
+    /// shows for the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -209,6 +247,9 @@ pub struct Directory {
     pub name: String,
     /// vector of requests that are children of this directory
     pub requests: Arc<RwLock<Vec<RequestKind>>>,
+    /// what the requests of the directory have in common, written in Markdown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// basic information about a colleciton
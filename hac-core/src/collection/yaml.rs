@@ -0,0 +1,170 @@
+use serde_json::{Map, Value};
+use serde_yaml_ng::Value as Yaml;
+
+/// parses a yaml document into a json value, anchors and merge keys are
+/// resolved, and keys that are not strings, like the status codes of an
+/// OpenAPI spec, become strings, as json has no other kind of key
+pub fn parse(text: &str) -> anyhow::Result<Value> {
+    let mut yaml: Yaml = serde_yaml_ng::from_str(text)?;
+    yaml.apply_merge()?;
+    to_json(yaml)
+}
+
+fn to_json(yaml: Yaml) -> anyhow::Result<Value> {
+    let value = match yaml {
+        Yaml::Null => Value::Null,
+        Yaml::Bool(bool) => Value::Bool(bool),
+        Yaml::Number(number) => serde_json::to_value(number)?,
+        Yaml::String(string) => Value::String(string),
+        Yaml::Sequence(items) => Value::Array(
+            items
+                .into_iter()
+                .map(to_json)
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        Yaml::Mapping(mapping) => {
+            let mut object = Map::new();
+            for (key, value) in mapping {
+                object.insert(key_to_string(key)?, to_json(value)?);
+            }
+            Value::Object(object)
+        }
+        // tags carry no meaning for us, only the value they are on
+        Yaml::Tagged(tagged) => to_json(tagged.value)?,
+    };
+
+    Ok(value)
+}
+
+fn key_to_string(key: Yaml) -> anyhow::Result<String> {
+    match key {
+        Yaml::String(key) => Ok(key),
+        Yaml::Number(key) => Ok(key.to_string()),
+        Yaml::Bool(key) => Ok(key.to_string()),
+        Yaml::Null => Ok(String::from("null")),
+        key => anyhow::bail!("unsupported key on yaml mapping: {key:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parsing_block_collections() {
+        let yaml = r#"
+# a comment
+openapi: 3.0.3
+info:
+  title: "Users: API"   # trailing comment
+  version: '1.0'
+servers:
+  - url: https://api.com/v1
+    description: production
+tags:
+- name: users
+paths:
+  /users/{id}:
+    get:
+      tags: [users, "admin"]
+      parameters:
+        - name: id
+          in: path
+          required: true
+      responses:
+        200:
+          description: a user
+          content: {}
+empty:
+"#;
+        assert_eq!(
+            parse(yaml).unwrap(),
+            json!({
+                "openapi": "3.0.3",
+                "info": { "title": "Users: API", "version": "1.0" },
+                "servers": [{ "url": "https://api.com/v1", "description": "production" }],
+                "tags": [{ "name": "users" }],
+                "paths": {
+                    "/users/{id}": {
+                        "get": {
+                            "tags": ["users", "admin"],
+                            "parameters": [{ "name": "id", "in": "path", "required": true }],
+                            "responses": { "200": { "description": "a user", "content": {} } }
+                        }
+                    }
+                },
+                "empty": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_parsing_scalars() {
+        let yaml = r#"
+literal: |
+  line one
+    indented
+
+  line three
+folded: >-
+  joined
+  together
+
+  paragraph
+plain: a plain
+  multiline scalar
+numbers: [1, -2.5, 1e3, 1.0.0]
+escapes: "tab\there \"quoted\" é"
+single: 'it''s # not a comment'
+flow: { a: 1, b: [x, { c: ~ }],
+  d: true }
+nested:
+  - - a
+    - b
+  -
+    key: value
+"#;
+        assert_eq!(
+            parse(yaml).unwrap(),
+            json!({
+                "literal": "line one\n  indented\n\nline three\n",
+                "folded": "joined together\nparagraph",
+                "plain": "a plain multiline scalar",
+                "numbers": [1, -2.5, 1000.0, "1.0.0"],
+                "escapes": "tab\there \"quoted\" é",
+                "single": "it's # not a comment",
+                "flow": { "a": 1, "b": ["x", { "c": null }], "d": true },
+                "nested": [["a", "b"], { "key": "value" }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolving_anchors_and_aliases() {
+        let yaml = r#"
+defaults: &defaults
+  type: string
+  nullable: true
+name:
+  <<: *defaults
+  type: integer
+tags: !custom [a, b]
+"#;
+        assert_eq!(
+            parse(yaml).unwrap(),
+            json!({
+                "defaults": { "type": "string", "nullable": true },
+                "name": { "type": "integer", "nullable": true },
+                "tags": ["a", "b"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_reporting_errors() {
+        assert!(parse("a: 1\n   b: 2\n").is_err());
+        assert!(parse("a: *missing").is_err());
+        assert!(parse("a: [1, 2").is_err());
+    }
+}
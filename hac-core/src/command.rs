@@ -1,9 +1,21 @@
 use crate::collection::Collection;
 
+use std::path::PathBuf;
+
 #[derive(Debug)]
 pub enum Command {
     Quit,
     SelectCollection(Collection),
     Error(String),
     CreateCollection(Collection),
+    /// suspends the application to edit the given file on the user's `$VISUAL`
+    /// or `$EDITOR`, sending `ExternalEditorClosed` back when the editor exits
+    OpenExternalEditor(PathBuf),
+    ExternalEditorClosed(PathBuf),
+    /// sets the system clipboard to the given text, through the terminal
+    CopyToClipboard(String),
+    /// the collection with the given hash was written to disk
+    CollectionSynced(u64),
+    /// writing the collection with the given hash failed, with the reason why
+    CollectionSyncFailed(u64, String),
 }
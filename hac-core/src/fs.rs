@@ -1,5 +1,7 @@
+pub mod backup;
 pub mod error;
 #[allow(clippy::module_inception)]
 mod fs;
+pub mod watcher;
 
 pub use fs::*;
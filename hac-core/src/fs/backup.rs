@@ -0,0 +1,156 @@
+use crate::collection::layout::read_collection_dir;
+use crate::collection::Collection;
+use crate::fs::write_atomic;
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hac_config::CollectionLayout;
+
+/// how many backups of each collection we keep around, older ones are removed
+const BACKUPS_TO_KEEP: usize = 10;
+/// collections are synced every few seconds while being edited, so we only take
+/// a new backup when the newest one is older than this, otherwise every backup
+/// would be from the last minute
+const BACKUP_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// copies what is currently on disk for `collection` into its backups directory,
+/// before it gets overwritten. Backups are always stored as a single file, no
+/// matter the layout of the collection, so restoring one is just a matter of
+/// copying it into the collections directory
+pub fn backup_collection(collection: &Collection, backups_dir: &Path) -> anyhow::Result<()> {
+    backup_collection_at(collection, backups_dir, SystemTime::now())
+}
+
+fn backup_collection_at(
+    collection: &Collection,
+    backups_dir: &Path,
+    now: SystemTime,
+) -> anyhow::Result<()> {
+    if !collection.path.exists() {
+        return Ok(());
+    }
+
+    let backups_dir = collection_backups_dir(&collection.path, backups_dir);
+    let now = now.duration_since(UNIX_EPOCH)?.as_millis();
+    let mut backups = list_backups(&backups_dir);
+
+    let is_recent = backups
+        .last()
+        .and_then(|(timestamp, _)| now.checked_sub(*timestamp))
+        .is_some_and(|elapsed| elapsed.lt(&BACKUP_INTERVAL.as_millis()));
+    if is_recent {
+        return Ok(());
+    }
+
+    let content = match collection.layout {
+        CollectionLayout::File => std::fs::read_to_string(&collection.path)?,
+        CollectionLayout::Directory => {
+            serde_json::to_string(&read_collection_dir(&collection.path)?)?
+        }
+    };
+
+    std::fs::create_dir_all(&backups_dir)?;
+    let backup_path = backups_dir.join(format!("{now}.json"));
+    write_atomic(&backup_path, content.as_bytes())?;
+    backups.push((now, backup_path));
+
+    let excess = backups.len().saturating_sub(BACKUPS_TO_KEEP);
+    for (_, path) in backups.into_iter().take(excess) {
+        std::fs::remove_file(path)?;
+    }
+
+    Ok(())
+}
+
+/// every collection has its own backups directory, named after the collection
+/// file or directory, along with a hash of its full path, as collections from
+/// different workspaces may share the same name
+fn collection_backups_dir(collection_path: &Path, backups_dir: &Path) -> PathBuf {
+    let name = collection_path
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let hash = fnv1a(collection_path.to_string_lossy().as_bytes());
+
+    backups_dir.join(format!("{name}-{hash:016x}"))
+}
+
+/// 64 bit FNV-1a, the hash has to be the same on every build of hac, or the
+/// backups of a collection would be left behind on a different directory,
+/// which rules out the hashers on the standard library
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// backups sorted from oldest to newest, backups are named after the timestamp
+/// in milliseconds they were taken at
+fn list_backups(backups_dir: &Path) -> Vec<(u128, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(backups_dir) else {
+        return vec![];
+    };
+
+    let mut backups = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<u128>().ok())
+                .map(|timestamp| (timestamp, path))
+        })
+        .collect::<Vec<_>>();
+    backups.sort();
+    backups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::types::Info;
+    use crate::collection::COLLECTION_VERSION;
+
+    #[test]
+    fn test_backups_are_rolled() {
+        let dir = tempfile::tempdir().unwrap();
+        let backups_dir = dir.path().join("backups");
+        let collection = Collection {
+            version: COLLECTION_VERSION,
+            info: Info {
+                name: "any".into(),
+                description: None,
+            },
+            requests: None,
+            environment: Default::default(),
+            path: dir.path().join("any.json"),
+            layout: CollectionLayout::File,
+        };
+
+        // nothing to backup before the collection is on disk
+        backup_collection_at(&collection, &backups_dir, SystemTime::now()).unwrap();
+        assert!(!backups_dir.exists());
+
+        let start = SystemTime::now();
+        for i in 0..BACKUPS_TO_KEEP + 2 {
+            std::fs::write(&collection.path, format!("version {i}")).unwrap();
+            let now = start + BACKUP_INTERVAL * i as u32;
+            backup_collection_at(&collection, &backups_dir, now).unwrap();
+            // backups taken too close to the previous one are skipped
+            backup_collection_at(&collection, &backups_dir, now + Duration::from_secs(1)).unwrap();
+        }
+
+        let backups = list_backups(&collection_backups_dir(&collection.path, &backups_dir));
+        assert_eq!(backups.len(), BACKUPS_TO_KEEP);
+        let oldest = std::fs::read_to_string(&backups[0].1).unwrap();
+        assert_eq!(oldest, "version 2");
+    }
+
+    #[test]
+    fn test_backups_dir_is_stable() {
+        let dir = collection_backups_dir(Path::new("/work/api.json"), Path::new("backups"));
+
+        assert_eq!(dir, Path::new("backups").join("api-6a85f55f6e355fb0"));
+    }
+}
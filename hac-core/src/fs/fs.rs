@@ -1,7 +1,13 @@
-use crate::collection::{collection::create_from_form, Collection};
+use crate::collection::collection::{collection_path, create_from_form};
+use crate::collection::layout::{self, COLLECTION_FILE};
+use crate::collection::Collection;
+use crate::fs::backup::backup_collection;
 use crate::fs::error::FsError;
 
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use hac_config::CollectionLayout;
 
 #[tracing::instrument(err, skip_all)]
 pub async fn delete_collection<P>(path: P) -> anyhow::Result<(), FsError>
@@ -9,9 +15,13 @@ where
     P: AsRef<Path>,
 {
     let path = path.as_ref();
-    tokio::fs::remove_file(path)
-        .await
-        .map_err(|_| FsError::IOError(format!("failed to delete collection: {:?}", path)))?;
+    let result = match path.is_dir() {
+        // we only remove whole directories when they are a collection
+        true if path.join(COLLECTION_FILE).is_file() => tokio::fs::remove_dir_all(path).await,
+        true => Err(std::io::Error::other("not a collection directory")),
+        false => tokio::fs::remove_file(path).await,
+    };
+    result.map_err(|_| FsError::IOError(format!("failed to delete collection: {:?}", path)))?;
 
     tracing::debug!("sucessfully deleted collection: {:?}", path);
     Ok(())
@@ -21,44 +31,168 @@ where
 pub async fn create_collection(
     name: String,
     description: String,
+    collections_dir: PathBuf,
+    collection_layout: CollectionLayout,
+    dry_run: bool,
+) -> anyhow::Result<Collection, FsError> {
+    let collection = create_from_form(name, description, collections_dir, collection_layout);
+    save_new_collection(collection, dry_run).await
+}
+
+/// stores a collection that was imported from somewhere else, like an OpenAPI
+/// spec, on the collections directory
+#[tracing::instrument(err, skip(collection))]
+pub async fn import_collection(
+    mut collection: Collection,
+    collections_dir: PathBuf,
+    collection_layout: CollectionLayout,
     dry_run: bool,
 ) -> anyhow::Result<Collection, FsError> {
-    let collection = create_from_form(name, description);
+    collection.path = collection_path(&collection.info.name, collections_dir, collection_layout);
+    collection.layout = collection_layout;
+    save_new_collection(collection, dry_run).await
+}
 
+async fn save_new_collection(
+    collection: Collection,
+    dry_run: bool,
+) -> anyhow::Result<Collection, FsError> {
     if collection.path.exists() {
         return Err(FsError::CollectionAlreadyExists(
             collection.path.to_string_lossy().to_string(),
         ));
     }
 
-    let serialized_collection = serde_json::to_string(&collection)
-        .map_err(|e| FsError::SerializationError(e.to_string()))?;
-
     // if we are on a dry_run, we skip syncing
     if !dry_run {
-        tokio::fs::write(&collection.path, serialized_collection)
-            .await
-            .map_err(|e| FsError::IOError(format!("failed to write collection: {:?}", e)))?;
+        sync_collection(collection.clone()).await?;
     }
 
     tracing::debug!("successfully created new collection: {:?}", collection.path);
     Ok(collection)
 }
 
+/// writes the collection to disk, backing up what was there before. Writes are
+/// atomic, so a crash while syncing never leaves a collection half written
 pub async fn sync_collection(collection: Collection) -> anyhow::Result<(), FsError> {
-    let collection_str = serde_json::to_string(&collection)
-        .map_err(|e| FsError::SerializationError(e.to_string()))?;
+    let path = collection.path.clone();
+    let content = match collection.layout {
+        CollectionLayout::File => Some(
+            serde_json::to_string(&collection)
+                .map_err(|e| FsError::SerializationError(e.to_string()))?,
+        ),
+        CollectionLayout::Directory => None,
+    };
 
-    tokio::fs::write(&collection.path, collection_str)
-        .await
-        .map_err(|_| {
-            FsError::IOError(format!(
-                "failed to synchronize collection {:?}",
-                collection.path
-            ))
-        })?;
+    tokio::task::spawn_blocking(move || {
+        // a failed backup shouldn't prevent the user from saving their changes
+        if let Err(e) = backup_collection(&collection, &hac_config::get_backups_dir()) {
+            tracing::error!("failed to backup collection {:?}: {e:?}", collection.path);
+        }
+
+        match content {
+            Some(content) => Ok(write_atomic(&collection.path, content.as_bytes())?),
+            None => layout::write_collection_dir(&collection),
+        }
+    })
+    .await
+    .map_err(|_| FsError::Unknown)?
+    .map_err(|e| FsError::IOError(format!("failed to synchronize collection {:?}: {e}", path)))?;
 
-    tracing::debug!("synchronization of collection: {:?}", collection.path);
+    tracing::debug!("synchronization of collection: {:?}", path);
 
     Ok(())
 }
+
+/// writes `content` to a temporary file next to `path` and renames it over `path`,
+/// renames are atomic, so `path` has either its old or its new content, even if
+/// we crash in the middle of writing
+pub fn write_atomic<P>(path: P, content: &[u8]) -> std::io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::other(format!("{path:?} is not a file")))?;
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+
+    let result = std::fs::File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(content)?;
+            file.sync_all()
+        })
+        .and_then(|_| std::fs::rename(&temp_path, path));
+
+    if result.is_err() {
+        _ = std::fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic_replaces_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("collection.json");
+
+        write_atomic(&path, b"old").unwrap();
+        write_atomic(&path, b"new").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_imported_collections_stay_inside_of_the_collections_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let collections_dir = dir.path().join("cols");
+        std::fs::create_dir(&collections_dir).unwrap();
+
+        for title in ["../escaped", "Pets / v2"] {
+            let spec = format!(r#"{{ "openapi": "3.0.0", "info": {{ "title": "{title}" }} }}"#);
+            let collection = crate::collection::openapi::import_openapi(&spec).unwrap();
+            let collection = import_collection(
+                collection,
+                collections_dir.clone(),
+                CollectionLayout::File,
+                false,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(collection.path.parent(), Some(collections_dir.as_path()));
+            assert!(collection.path.is_file());
+        }
+        assert!(!dir.path().join("escaped.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_importing_har_files_titled_after_a_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let har = r#"{
+            "log": {
+                "pages": [{ "title": "https://shop.com/cart?id=1" }],
+                "entries": [{ "request": { "method": "GET", "url": "https://shop.com/cart" } }]
+            }
+        }"#;
+
+        let collection = crate::collection::har::import_har(har, false).unwrap();
+        let collection = import_collection(
+            collection,
+            dir.path().to_path_buf(),
+            CollectionLayout::File,
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(collection.path, dir.path().join("shop_com.json"));
+        let loaded = crate::collection::collection::load_collection(&collection.path).unwrap();
+        assert_eq!(loaded.info.name, "shop.com");
+    }
+}
@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// size and modification time of every file of a collection, any change on a
+/// collection file changes at least one of those
+type Fingerprint = Vec<(PathBuf, Option<SystemTime>, u64)>;
+
+/// watches a collection for changes made outside of hac, like a `git pull` or
+/// an external editor. This polls the file system, which is more than enough
+/// for the amount of files on a collection, and works the same everywhere.
+///
+/// the watcher doesn't know about who made the change, so our own writes are
+/// reported too, and it is up to the caller to compare the content on disk to
+/// what it last wrote
+#[derive(Debug)]
+pub struct CollectionWatcher {
+    path: PathBuf,
+    fingerprint: Fingerprint,
+}
+
+impl CollectionWatcher {
+    pub fn new<P>(path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let fingerprint = fingerprint(&path);
+        CollectionWatcher { path, fingerprint }
+    }
+
+    /// whether anything changed since the last time we polled
+    pub fn poll(&mut self) -> bool {
+        let fingerprint = fingerprint(&self.path);
+        if fingerprint.eq(&self.fingerprint) {
+            return false;
+        }
+
+        self.fingerprint = fingerprint;
+        true
+    }
+}
+
+fn fingerprint(path: &Path) -> Fingerprint {
+    let mut fingerprint = vec![];
+    collect_fingerprint(path, &mut fingerprint);
+    fingerprint.sort();
+    fingerprint
+}
+
+fn collect_fingerprint(path: &Path, fingerprint: &mut Fingerprint) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_file() {
+        fingerprint.push((path.to_path_buf(), metadata.modified().ok(), metadata.len()));
+        return;
+    }
+
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return;
+    };
+
+    // hidden files are ignored, which includes the temporary files we write
+    // before renaming them into place
+    entries
+        .flatten()
+        .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+        .for_each(|entry| collect_fingerprint(&entry.path(), fingerprint));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_are_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        let collection_dir = dir.path().join("collection");
+        std::fs::create_dir(&collection_dir).unwrap();
+        std::fs::write(collection_dir.join("collection.json"), "{}").unwrap();
+
+        let mut watcher = CollectionWatcher::new(&collection_dir);
+        assert!(!watcher.poll());
+
+        std::fs::write(collection_dir.join(".temp.tmp"), "ignored").unwrap();
+        assert!(!watcher.poll());
+
+        std::fs::create_dir(collection_dir.join("nested")).unwrap();
+        std::fs::write(collection_dir.join("nested").join("request.json"), "{}").unwrap();
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+
+        std::fs::write(collection_dir.join("collection.json"), "{ }").unwrap();
+        assert!(watcher.poll());
+    }
+}
@@ -2,5 +2,7 @@ pub mod request_client;
 pub mod request_manager;
 pub mod request_strategies;
 pub mod response_decoders;
+pub mod response_diff;
+pub mod response_filter;
 
 pub use request_manager::handle_request;
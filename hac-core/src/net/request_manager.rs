@@ -1,9 +1,11 @@
+use crate::collection::environment;
 use crate::collection::types::{BodyType, Request};
 use crate::net::request_strategies::{http_strategy::HttpResponse, RequestStrategy};
 use crate::text_object::{Readonly, TextObject};
 
+use std::collections::BTreeMap;
 use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use reqwest::header::{HeaderMap, HeaderValue};
 use tokio::sync::mpsc::UnboundedSender;
@@ -20,6 +22,17 @@ pub struct Response {
     pub size: Option<u64>,
     pub is_error: bool,
     pub cause: Option<String>,
+    /// the request this is a response to, as it was sent. Set by `handle_request`
+    /// once the response arrives
+    pub sent: Option<SentRequest>,
+}
+
+/// a request as it went out, with the variables of the environment expanded,
+/// later edits to the request don't change what was sent
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentRequest {
+    pub request: Request,
+    pub started_at: SystemTime,
 }
 
 pub struct RequestManager;
@@ -57,11 +70,23 @@ impl From<&str> for ContentType {
     }
 }
 
+/// sends the request with the variables of the collection environment expanded,
+/// the response is sent through `response_tx` once it arrives, along with the id
+/// of its request, as many requests may be in flight at once
 #[tracing::instrument(skip_all)]
-pub fn handle_request(request: &Arc<RwLock<Request>>, response_tx: UnboundedSender<Response>) {
-    let request = request.read().unwrap().clone();
+pub fn handle_request(
+    request: &Arc<RwLock<Request>>,
+    environment: &BTreeMap<String, String>,
+    response_tx: UnboundedSender<(String, Response)>,
+) {
+    let request = environment::expand_request(&request.read().unwrap(), environment);
     tokio::spawn(async move {
-        let response = match request.body_type.as_ref() {
+        let request_id = request.id.clone();
+        let sent = SentRequest {
+            request: request.clone(),
+            started_at: SystemTime::now(),
+        };
+        let mut response = match request.body_type.as_ref() {
             // if we dont have a body type, this is a GET request, so we use HTTP strategy
             None => RequestManager::handle(HttpResponse, request).await,
             Some(body_type) => match body_type {
@@ -69,8 +94,9 @@ pub fn handle_request(request: &Arc<RwLock<Request>>, response_tx: UnboundedSend
             },
         };
 
+        response.sent = Some(sent);
         response_tx
-            .send(response)
+            .send((request_id, response))
             .is_err()
             .then(|| std::process::abort());
     });
@@ -39,6 +39,7 @@ impl HttpResponse {
                 status: None,
                 headers: None,
                 duration: now.elapsed(),
+                sent: None,
             },
         }
     }
@@ -66,6 +67,7 @@ impl HttpResponse {
                 status: None,
                 headers: None,
                 duration: now.elapsed(),
+                sent: None,
             },
         }
     }
@@ -93,6 +95,7 @@ impl HttpResponse {
                 status: None,
                 headers: None,
                 duration: now.elapsed(),
+                sent: None,
             },
         }
     }
@@ -120,6 +123,7 @@ impl HttpResponse {
                 status: None,
                 headers: None,
                 duration: now.elapsed(),
+                sent: None,
             },
         }
     }
@@ -147,6 +151,7 @@ impl HttpResponse {
                 status: None,
                 headers: None,
                 duration: now.elapsed(),
+                sent: None,
             },
         }
     }
@@ -41,6 +41,7 @@ impl ResponseDecoder for JsonDecoder {
             body_size: Some(body_size),
             cause: None,
             is_error: false,
+            sent: None,
         }
     }
 }
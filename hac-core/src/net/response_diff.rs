@@ -0,0 +1,408 @@
+use crate::net::request_manager::Response;
+
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+/// line diffs compare every line of one body with every line of the other, so
+/// past this many comparisons we give up and show one body replacing the other
+const MAX_LINE_COMPARISONS: usize = 4_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Added(String),
+    Removed(String),
+    Changed { before: String, after: String },
+}
+
+/// a change on something that can be addressed, like a header by its name, or
+/// a JSON value by its path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyedChange {
+    pub key: String,
+    pub change: Change,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineChange {
+    Same(String),
+    Added(String),
+    Removed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BodyDiff {
+    /// both bodies are JSON, so they are compared value by value, paths use the
+    /// same syntax as response filters, like `.data[0].name`
+    Json(Vec<KeyedChange>),
+    /// every line of both bodies, in order, marked as kept, added or removed
+    Lines(Vec<LineChange>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseDiff {
+    pub status: Option<Change>,
+    pub headers: Vec<KeyedChange>,
+    pub body: BodyDiff,
+}
+
+impl ResponseDiff {
+    pub fn is_empty(&self) -> bool {
+        let body_is_same = match &self.body {
+            BodyDiff::Json(changes) => changes.is_empty(),
+            BodyDiff::Lines(lines) => lines.iter().all(|l| matches!(l, LineChange::Same(_))),
+        };
+        self.status.is_none() && self.headers.is_empty() && body_is_same
+    }
+}
+
+/// everything that changed from `before` to `after`, JSON bodies are compared
+/// structurally, any other body is compared line by line
+pub fn diff_responses(before: &Response, after: &Response) -> ResponseDiff {
+    let status = |response: &Response| {
+        response
+            .status
+            .map(|status| status.to_string())
+            .unwrap_or_else(|| String::from("no status"))
+    };
+    let status = match (status(before), status(after)) {
+        (before, after) if before.eq(&after) => None,
+        (before, after) => Some(Change::Changed { before, after }),
+    };
+
+    let headers = diff_headers(before.headers.as_ref(), after.headers.as_ref());
+
+    let json = |response: &Response| {
+        response
+            .body
+            .as_ref()
+            .and_then(|body| serde_json::from_str::<Value>(body).ok())
+    };
+    let body = match (json(before), json(after)) {
+        (Some(before), Some(after)) => BodyDiff::Json(diff_json(&before, &after)),
+        _ => BodyDiff::Lines(diff_lines(&body_text(before), &body_text(after))),
+    };
+
+    ResponseDiff {
+        status,
+        headers,
+        body,
+    }
+}
+
+fn body_text(response: &Response) -> String {
+    match (response.pretty_body.as_ref(), response.body.as_ref()) {
+        (Some(pretty_body), _) => pretty_body.to_string(),
+        (None, Some(body)) => body.clone(),
+        (None, None) => String::default(),
+    }
+}
+
+fn header_values(headers: &HeaderMap, name: &str) -> String {
+    headers
+        .get_all(name)
+        .iter()
+        .map(|value| value.to_str().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// headers are compared by name, headers with multiple values are compared as
+/// a single comma separated value
+pub fn diff_headers(before: Option<&HeaderMap>, after: Option<&HeaderMap>) -> Vec<KeyedChange> {
+    let empty = HeaderMap::new();
+    let before = before.unwrap_or(&empty);
+    let after = after.unwrap_or(&empty);
+    let mut changes = vec![];
+
+    for name in before.keys() {
+        let before_value = header_values(before, name.as_str());
+        let change = match after.contains_key(name) {
+            false => Change::Removed(before_value),
+            true => match header_values(after, name.as_str()) {
+                after_value if after_value.eq(&before_value) => continue,
+                after_value => Change::Changed {
+                    before: before_value,
+                    after: after_value,
+                },
+            },
+        };
+        changes.push(KeyedChange {
+            key: name.to_string(),
+            change,
+        });
+    }
+
+    for name in after.keys().filter(|name| !before.contains_key(*name)) {
+        changes.push(KeyedChange {
+            key: name.to_string(),
+            change: Change::Added(header_values(after, name.as_str())),
+        });
+    }
+
+    changes
+}
+
+fn key_path(path: &str, key: &str) -> String {
+    let is_identifier = !key.is_empty()
+        && !key.starts_with(|c: char| c.is_ascii_digit())
+        && key.chars().all(|c| c.is_alphanumeric() || c.eq(&'_'));
+    match is_identifier {
+        true => format!("{path}.{key}"),
+        false => format!("{path}[{}]", Value::String(key.to_string())),
+    }
+}
+
+/// every value that was added, removed or changed from `before` to `after`, by
+/// path. Objects and arrays are walked into, so a changed field is reported on
+/// its own instead of as a change of the whole object holding it
+pub fn diff_json(before: &Value, after: &Value) -> Vec<KeyedChange> {
+    fn inner(before: &Value, after: &Value, path: String, changes: &mut Vec<KeyedChange>) {
+        match (before, after) {
+            (Value::Object(before), Value::Object(after)) => {
+                for (key, before_value) in before {
+                    let path = key_path(&path, key);
+                    match after.get(key) {
+                        Some(after_value) => inner(before_value, after_value, path, changes),
+                        None => changes.push(KeyedChange {
+                            key: path,
+                            change: Change::Removed(before_value.to_string()),
+                        }),
+                    }
+                }
+                for (key, after_value) in after.iter().filter(|(k, _)| !before.contains_key(*k)) {
+                    changes.push(KeyedChange {
+                        key: key_path(&path, key),
+                        change: Change::Added(after_value.to_string()),
+                    });
+                }
+            }
+            (Value::Array(before), Value::Array(after)) => {
+                for idx in 0..usize::max(before.len(), after.len()) {
+                    let path = format!("{path}[{idx}]");
+                    match (before.get(idx), after.get(idx)) {
+                        (Some(before), Some(after)) => inner(before, after, path, changes),
+                        (Some(before), None) => changes.push(KeyedChange {
+                            key: path,
+                            change: Change::Removed(before.to_string()),
+                        }),
+                        (None, Some(after)) => changes.push(KeyedChange {
+                            key: path,
+                            change: Change::Added(after.to_string()),
+                        }),
+                        (None, None) => unreachable!("index is within one of the arrays"),
+                    }
+                }
+            }
+            (before, after) if before.ne(after) => changes.push(KeyedChange {
+                // the root path is `.`, just like the identity filter
+                key: match path.is_empty() {
+                    true => String::from("."),
+                    false => path,
+                },
+                change: Change::Changed {
+                    before: before.to_string(),
+                    after: after.to_string(),
+                },
+            }),
+            _ => {}
+        }
+    }
+
+    let mut changes = vec![];
+    inner(before, after, String::default(), &mut changes);
+    changes
+}
+
+/// every line of both texts, in order, keeping the longest run of lines they
+/// have in common and marking everything else as added or removed
+pub fn diff_lines(before: &str, after: &str) -> Vec<LineChange> {
+    let before = before.lines().collect::<Vec<_>>();
+    let after = after.lines().collect::<Vec<_>>();
+
+    // lines at the start and end that didn't change don't have to be compared,
+    // which is most of the lines when comparing two versions of a body
+    let prefix = before
+        .iter()
+        .zip(after.iter())
+        .take_while(|(a, b)| a.eq(b))
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(a, b)| a.eq(b))
+        .count();
+    let before_mid = &before[prefix..before.len() - suffix];
+    let after_mid = &after[prefix..after.len() - suffix];
+
+    let mut changes = before[..prefix]
+        .iter()
+        .map(|line| LineChange::Same(line.to_string()))
+        .collect::<Vec<_>>();
+
+    if before_mid.len().saturating_mul(after_mid.len()) > MAX_LINE_COMPARISONS {
+        changes.extend(
+            before_mid
+                .iter()
+                .map(|l| LineChange::Removed(l.to_string())),
+        );
+        changes.extend(after_mid.iter().map(|l| LineChange::Added(l.to_string())));
+    } else {
+        changes.extend(diff_common_subsequence(before_mid, after_mid));
+    }
+
+    changes.extend(
+        before[before.len() - suffix..]
+            .iter()
+            .map(|line| LineChange::Same(line.to_string())),
+    );
+    changes
+}
+
+fn diff_common_subsequence(before: &[&str], after: &[&str]) -> Vec<LineChange> {
+    // lengths[i][j] is the length of the longest common subsequence between
+    // before[i..] and after[j..]
+    let mut lengths = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lengths[i][j] = match before[i].eq(after[j]) {
+                true => lengths[i + 1][j + 1] + 1,
+                false => usize::max(lengths[i + 1][j], lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let mut changes = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() && j < after.len() {
+        if before[i].eq(after[j]) {
+            changes.push(LineChange::Same(before[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            changes.push(LineChange::Removed(before[i].to_string()));
+            i += 1;
+        } else {
+            changes.push(LineChange::Added(after[j].to_string()));
+            j += 1;
+        }
+    }
+    changes.extend(
+        before[i..]
+            .iter()
+            .map(|l| LineChange::Removed(l.to_string())),
+    );
+    changes.extend(after[j..].iter().map(|l| LineChange::Added(l.to_string())));
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use std::time::Duration;
+
+    fn make_response(status: u16, body: &str, headers: &[(&'static str, &str)]) -> Response {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in headers {
+            header_map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        Response {
+            body: Some(body.to_string()),
+            pretty_body: None,
+            headers: Some(header_map),
+            duration: Duration::from_millis(10),
+            status: Some(reqwest::StatusCode::from_u16(status).unwrap()),
+            headers_size: None,
+            body_size: None,
+            size: None,
+            is_error: false,
+            cause: None,
+            sent: None,
+        }
+    }
+
+    #[test]
+    fn test_json_bodies_are_diffed_by_path() {
+        let before = serde_json::json!({
+            "id": 1,
+            "user": { "name": "john", "roles": ["admin", "dev"] },
+            "content-type": "a",
+        });
+        let after = serde_json::json!({
+            "id": 1,
+            "user": { "name": "jane", "roles": ["admin"], "age": 30 },
+        });
+
+        assert_eq!(
+            diff_json(&before, &after),
+            vec![
+                KeyedChange {
+                    key: ".user.name".into(),
+                    change: Change::Changed {
+                        before: "\"john\"".into(),
+                        after: "\"jane\"".into()
+                    },
+                },
+                KeyedChange {
+                    key: ".user.roles[1]".into(),
+                    change: Change::Removed("\"dev\"".into()),
+                },
+                KeyedChange {
+                    key: ".user.age".into(),
+                    change: Change::Added("30".into()),
+                },
+                KeyedChange {
+                    key: "[\"content-type\"]".into(),
+                    change: Change::Removed("\"a\"".into()),
+                },
+            ]
+        );
+
+        let changes = diff_json(&serde_json::json!(1), &serde_json::json!("1"));
+        assert_eq!(changes[0].key, ".");
+        assert!(diff_json(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn test_text_bodies_are_diffed_by_line() {
+        let changes = diff_lines("a\nb\nc\nd", "a\nc\nx\nd");
+        assert_eq!(
+            changes,
+            vec![
+                LineChange::Same("a".into()),
+                LineChange::Removed("b".into()),
+                LineChange::Same("c".into()),
+                LineChange::Added("x".into()),
+                LineChange::Same("d".into()),
+            ]
+        );
+        assert!(diff_lines("", "").is_empty());
+    }
+
+    #[test]
+    fn test_status_and_headers_are_compared() {
+        let before = make_response(200, "<p>hi</p>", &[("etag", "1"), ("x-old", "yes")]);
+        let after = make_response(404, "<p>hi</p>", &[("etag", "2"), ("x-new", "yes")]);
+
+        let diff = diff_responses(&before, &after);
+        assert_eq!(
+            diff.status,
+            Some(Change::Changed {
+                before: "200 OK".into(),
+                after: "404 Not Found".into()
+            })
+        );
+        assert_eq!(
+            diff.headers
+                .iter()
+                .map(|h| h.key.as_str())
+                .collect::<Vec<_>>(),
+            vec!["etag", "x-old", "x-new"]
+        );
+        assert!(matches!(diff.body, BodyDiff::Lines(_)));
+        assert!(!diff.is_empty());
+
+        assert!(diff_responses(&before, &before).is_empty());
+    }
+}
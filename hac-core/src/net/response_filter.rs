@@ -0,0 +1,305 @@
+use serde_json::Value;
+
+/// a single step of a filter expression, filters are a chain of segments that
+/// are applied from left to right over a stream of values
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// `.key` or `["key"]`, yields the value under the key of an object
+    Key(String),
+    /// `[n]`, yields the nth element of an array, negative indexes count from
+    /// the end of the array
+    Index(i64),
+    /// `[]`, `[*]` or `.*`, yields every child of an array or object
+    Wildcard,
+    /// `..key` or `..[n]`, applies the inner segment to the value and every
+    /// descendant of it, silently skipping values where it doesn't apply
+    Recursive(Box<Segment>),
+}
+
+/// applies a jq or JSONPath-like expression to a JSON body, returning the result
+/// pretty printed so it can be highlighted just like a regular body.
+///
+/// the supported syntax is a subset shared by both jq and JSONPath:
+/// - `.` or `$` is the identity, returning the whole body
+/// - `.key`, `["key"]` or `['key']` access a key of an object
+/// - `[0]` or `[-1]` access an element of an array
+/// - `[]`, `[*]` or `.*` iterate over every child of an array or object
+/// - `..key` searches for `key` recursively
+/// - `|` pipes the result of an expression into another
+///
+/// when the expression yields more than one value, all the values are wrapped
+/// into an array
+pub fn apply_filter(body: &str, expr: &str) -> anyhow::Result<String> {
+    let body: Value = serde_json::from_str(body)
+        .map_err(|_| anyhow::anyhow!("filters can only be applied to JSON bodies"))?;
+
+    let mut values = vec![body];
+    for stage in expr.split('|') {
+        let segments = parse_stage(stage.trim())?;
+        for segment in segments.iter() {
+            values = apply_segment(values, segment)?;
+        }
+    }
+
+    let result = match values.len() {
+        1 => values.pop().expect("we checked the length above"),
+        _ => Value::Array(values),
+    };
+
+    Ok(serde_json::to_string_pretty(&result)?)
+}
+
+fn parse_stage(stage: &str) -> anyhow::Result<Vec<Segment>> {
+    let chars = stage.chars().collect::<Vec<_>>();
+    let mut segments = vec![];
+    let mut pos = 0;
+
+    match chars.first() {
+        Some('$') => pos += 1,
+        Some('.') | Some('[') => {}
+        Some(c) => anyhow::bail!("unexpected '{c}', filters must start with '.' or '$'"),
+        None => anyhow::bail!("empty filter expression"),
+    }
+
+    while pos < chars.len() {
+        match chars[pos] {
+            '.' if chars.get(pos + 1).is_some_and(|c| c.eq(&'.')) => {
+                pos += 2;
+                let inner = match chars.get(pos) {
+                    Some('[') => parse_bracket(&chars, &mut pos)?,
+                    Some('*') => {
+                        pos += 1;
+                        Segment::Wildcard
+                    }
+                    Some(_) => Segment::Key(parse_identifier(&chars, &mut pos)?),
+                    None => anyhow::bail!("expected a key after '..'"),
+                };
+                segments.push(Segment::Recursive(Box::new(inner)));
+            }
+            '.' => {
+                pos += 1;
+                match chars.get(pos) {
+                    Some('[') => segments.push(parse_bracket(&chars, &mut pos)?),
+                    Some('*') => {
+                        pos += 1;
+                        segments.push(Segment::Wildcard);
+                    }
+                    Some(_) => segments.push(Segment::Key(parse_identifier(&chars, &mut pos)?)),
+                    // a lone `.` at the end is the identity
+                    None => {}
+                }
+            }
+            '[' => segments.push(parse_bracket(&chars, &mut pos)?),
+            c if c.is_whitespace() => pos += 1,
+            c => anyhow::bail!("unexpected '{c}' at position {pos}"),
+        }
+    }
+
+    Ok(segments)
+}
+
+fn parse_identifier(chars: &[char], pos: &mut usize) -> anyhow::Result<String> {
+    let start = *pos;
+    while chars
+        .get(*pos)
+        .is_some_and(|c| c.is_alphanumeric() || c.eq(&'_') || c.eq(&'-'))
+    {
+        *pos += 1;
+    }
+
+    if start.eq(pos) {
+        anyhow::bail!("expected a key at position {start}");
+    }
+
+    Ok(chars[start..*pos].iter().collect())
+}
+
+fn parse_bracket(chars: &[char], pos: &mut usize) -> anyhow::Result<Segment> {
+    // skipping the opening bracket
+    *pos += 1;
+    let start = *pos;
+
+    let segment = match chars.get(*pos) {
+        Some(']') => Segment::Wildcard,
+        Some('*') => {
+            *pos += 1;
+            Segment::Wildcard
+        }
+        Some(quote @ ('"' | '\'')) => {
+            *pos += 1;
+            let key_start = *pos;
+            while chars.get(*pos).is_some_and(|c| c.ne(quote)) {
+                *pos += 1;
+            }
+            if chars.get(*pos).is_none() {
+                anyhow::bail!("unterminated string starting at position {key_start}");
+            }
+            let key = chars[key_start..*pos].iter().collect::<String>();
+            *pos += 1;
+            Segment::Key(key)
+        }
+        Some(_) => {
+            while chars.get(*pos).is_some_and(|c| c.ne(&']')) {
+                *pos += 1;
+            }
+            let index = chars[start..*pos].iter().collect::<String>();
+            let index = index
+                .trim()
+                .parse::<i64>()
+                .map_err(|_| anyhow::anyhow!("invalid index '{}'", index.trim()))?;
+            Segment::Index(index)
+        }
+        None => anyhow::bail!("unterminated '[' at position {}", start - 1),
+    };
+
+    if chars.get(*pos).is_none_or(|c| c.ne(&']')) {
+        anyhow::bail!("expected ']' at position {}", *pos);
+    }
+    *pos += 1;
+
+    Ok(segment)
+}
+
+fn apply_segment(values: Vec<Value>, segment: &Segment) -> anyhow::Result<Vec<Value>> {
+    let mut result = vec![];
+
+    for value in values {
+        match segment {
+            Segment::Key(key) => match value {
+                Value::Object(mut map) => result.push(map.remove(key).unwrap_or(Value::Null)),
+                Value::Null => result.push(Value::Null),
+                other => anyhow::bail!("cannot index {} with \"{key}\"", type_name(&other)),
+            },
+            Segment::Index(index) => match value {
+                Value::Array(mut items) => {
+                    let len = items.len() as i64;
+                    let index = if index.is_negative() {
+                        len + index
+                    } else {
+                        *index
+                    };
+                    match (0..len).contains(&index) {
+                        true => result.push(items.swap_remove(index as usize)),
+                        false => result.push(Value::Null),
+                    }
+                }
+                Value::Null => result.push(Value::Null),
+                other => anyhow::bail!("cannot index {} with {index}", type_name(&other)),
+            },
+            Segment::Wildcard => match value {
+                Value::Array(items) => result.extend(items),
+                Value::Object(map) => result.extend(map.into_iter().map(|(_, v)| v)),
+                other => anyhow::bail!("cannot iterate over {}", type_name(&other)),
+            },
+            Segment::Recursive(inner) => {
+                let mut descendants = vec![];
+                collect_descendants(value, &mut descendants);
+                for descendant in descendants {
+                    let applies = matches!(
+                        (inner.as_ref(), &descendant),
+                        (Segment::Key(key), Value::Object(map)) if map.contains_key(key)
+                    ) || matches!(
+                        (inner.as_ref(), &descendant),
+                        (Segment::Index(_), Value::Array(_))
+                            | (Segment::Wildcard, Value::Array(_) | Value::Object(_))
+                    );
+
+                    if applies {
+                        result.extend(apply_segment(vec![descendant], inner)?);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// collects the value itself and every nested value inside of it, in document order
+fn collect_descendants(value: Value, descendants: &mut Vec<Value>) {
+    let children = match &value {
+        Value::Array(items) => items.clone(),
+        Value::Object(map) => map.values().cloned().collect(),
+        _ => vec![],
+    };
+
+    descendants.push(value);
+    for child in children {
+        collect_descendants(child, descendants);
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = r#"{
+        "name": "hac",
+        "tags": ["api", "tui", "rust"],
+        "owner": { "name": "wll", "repos": [{ "name": "hac" }, { "name": "dots" }] }
+    }"#;
+
+    #[test]
+    fn test_identity() {
+        let expected = serde_json::to_string_pretty(&serde_json::from_str::<Value>(BODY).unwrap());
+        assert_eq!(apply_filter(BODY, ".").unwrap(), expected.unwrap());
+        assert_eq!(
+            apply_filter(BODY, "$").unwrap(),
+            apply_filter(BODY, ".").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_keys_and_indexes() {
+        assert_eq!(apply_filter(BODY, ".name").unwrap(), r#""hac""#);
+        assert_eq!(apply_filter(BODY, ".owner.name").unwrap(), r#""wll""#);
+        assert_eq!(
+            apply_filter(BODY, r#"$["owner"]['name']"#).unwrap(),
+            r#""wll""#
+        );
+        assert_eq!(apply_filter(BODY, ".tags[1]").unwrap(), r#""tui""#);
+        assert_eq!(apply_filter(BODY, ".tags[-1]").unwrap(), r#""rust""#);
+        assert_eq!(apply_filter(BODY, ".tags[10]").unwrap(), "null");
+        assert_eq!(apply_filter(BODY, ".missing").unwrap(), "null");
+    }
+
+    #[test]
+    fn test_iteration_and_pipes() {
+        let expected = serde_json::to_string_pretty(&vec!["hac", "dots"]).unwrap();
+        assert_eq!(apply_filter(BODY, ".owner.repos[].name").unwrap(), expected);
+        assert_eq!(
+            apply_filter(BODY, "$.owner.repos[*].name").unwrap(),
+            expected
+        );
+        assert_eq!(
+            apply_filter(BODY, ".owner.repos | .[] | .name").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_recursive_descent() {
+        let expected = serde_json::to_string_pretty(&vec!["hac", "wll", "hac", "dots"]).unwrap();
+        assert_eq!(apply_filter(BODY, "..name").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_invalid_filters() {
+        assert!(apply_filter(BODY, "name").is_err());
+        assert!(apply_filter(BODY, ".tags[").is_err());
+        assert!(apply_filter(BODY, ".tags[abc]").is_err());
+        assert!(apply_filter(BODY, ".name.first").is_err());
+        assert!(apply_filter("not json", ".").is_err());
+    }
+}
@@ -1,5 +1,8 @@
 pub mod cursor;
+mod history;
 #[allow(clippy::module_inception)]
 mod text_object;
 
-pub use text_object::{Readonly, TextObject, Write};
+pub use text_object::{
+    find_closest_match, Readonly, SearchDirection, SearchMatch, TextObject, Write,
+};
@@ -0,0 +1,91 @@
+use crate::text_object::cursor::Cursor;
+
+use ropey::Rope;
+
+/// a copy of the content of a text object before an edit, along with where the
+/// cursor was, so undoing an edit also brings the cursor back to it.
+///
+/// ropes share most of their structure when cloned, so keeping whole snapshots
+/// around is cheap enough and way simpler than recording each edit operation
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    content: Rope,
+    cursor: Cursor,
+}
+
+/// linear undo history of a text object, every edit pushes a snapshot of the
+/// content before it happened into the undo stack, and any new edit clears the
+/// redo stack.
+///
+/// edits can be grouped, so a whole insert session, or a keymap that performs
+/// multiple edits, is undone at once.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct History {
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    /// `None` when there is no group open, otherwise holds whether the group
+    /// already recorded a snapshot
+    group: Option<bool>,
+}
+
+impl History {
+    /// records the state before an edit, when inside of a group, only the first
+    /// edit of the group is recorded
+    pub fn record(&mut self, content: &Rope, cursor: &Cursor) {
+        if let Some(true) = self.group {
+            return;
+        }
+
+        self.undo_stack.push(Snapshot {
+            content: content.clone(),
+            cursor: cursor.clone(),
+        });
+        self.redo_stack.clear();
+
+        if self.group.is_some() {
+            self.group = Some(true);
+        }
+    }
+
+    pub fn begin_group(&mut self) {
+        if self.group.is_none() {
+            self.group = Some(false);
+        }
+    }
+
+    pub fn end_group(&mut self) {
+        self.group = None;
+    }
+
+    /// returns the snapshot to restore, if any, storing the current state so it
+    /// can be redone later. Snapshots identical to the current content are
+    /// skipped, as they come from edits that didn't change anything
+    pub fn undo(&mut self, content: &Rope, cursor: &Cursor) -> Option<(Rope, Cursor)> {
+        self.end_group();
+        let snapshot = pop_different(&mut self.undo_stack, content)?;
+        self.redo_stack.push(Snapshot {
+            content: content.clone(),
+            cursor: cursor.clone(),
+        });
+        Some((snapshot.content, snapshot.cursor))
+    }
+
+    pub fn redo(&mut self, content: &Rope, cursor: &Cursor) -> Option<(Rope, Cursor)> {
+        self.end_group();
+        let snapshot = pop_different(&mut self.redo_stack, content)?;
+        self.undo_stack.push(Snapshot {
+            content: content.clone(),
+            cursor: cursor.clone(),
+        });
+        Some((snapshot.content, snapshot.cursor))
+    }
+}
+
+fn pop_different(stack: &mut Vec<Snapshot>, content: &Rope) -> Option<Snapshot> {
+    while let Some(snapshot) = stack.pop() {
+        if snapshot.content.ne(content) {
+            return Some(snapshot);
+        }
+    }
+    None
+}
@@ -1,4 +1,5 @@
-use crate::{syntax::highlighter::Highlighter, text_object::cursor::Cursor};
+use crate::syntax::highlighter::Highlighter;
+use crate::text_object::{cursor::Cursor, history::History};
 
 use std::collections::HashMap;
 use std::ops::{Add, Sub};
@@ -30,6 +31,32 @@ impl std::fmt::Display for LineBreak {
     }
 }
 
+/// a single occurrence of a search pattern on a text object, positions are
+/// measured in chars, not bytes, so they can be used directly with a cursor
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch {
+    pub row: usize,
+    pub col: usize,
+    /// char index of the start of the match from the start of the content
+    pub offset: usize,
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchDirection {
+    Forward,
+    Backward,
+}
+
+impl SearchDirection {
+    pub fn reverse(&self) -> Self {
+        match self {
+            SearchDirection::Forward => SearchDirection::Backward,
+            SearchDirection::Backward => SearchDirection::Forward,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Readonly;
 #[derive(Debug, Clone, PartialEq)]
@@ -40,6 +67,7 @@ pub struct TextObject<State = Readonly> {
     content: Rope,
     state: std::marker::PhantomData<State>,
     line_break: LineBreak,
+    history: History,
 }
 
 impl<State> Default for TextObject<State> {
@@ -50,6 +78,7 @@ impl<State> Default for TextObject<State> {
             content: Rope::from_str(&content),
             state: std::marker::PhantomData,
             line_break: LineBreak::Lf,
+            history: History::default(),
         }
     }
 }
@@ -65,6 +94,7 @@ impl TextObject<Readonly> {
             content,
             state: std::marker::PhantomData::<Readonly>,
             line_break,
+            history: History::default(),
         }
     }
 
@@ -73,18 +103,21 @@ impl TextObject<Readonly> {
             content: self.content,
             state: std::marker::PhantomData,
             line_break: self.line_break,
+            history: self.history,
         }
     }
 }
 
 impl TextObject<Write> {
     pub fn insert_char(&mut self, c: char, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
         let line = self.content.line_to_char(cursor.row());
         let col_offset = line + cursor.col();
         self.content.insert_char(col_offset, c);
     }
 
     pub fn insert_newline(&mut self, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
         let line = self.content.line_to_char(cursor.row());
         let col_offset = line + cursor.col();
         self.content
@@ -95,6 +128,7 @@ impl TextObject<Write> {
         if cursor.col().eq(&0) {
             return;
         }
+        self.history.record(&self.content, cursor);
         let line = self.content.line_to_char(cursor.row());
         let col_offset = line + cursor.col();
         self.content
@@ -103,6 +137,7 @@ impl TextObject<Write> {
     }
 
     pub fn erase_previous_char(&mut self, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
         let line = self.content.line_to_char(cursor.row());
         let col_offset = line + cursor.col();
         self.content
@@ -111,6 +146,7 @@ impl TextObject<Write> {
     }
 
     pub fn erase_current_char(&mut self, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
         let line = self.content.line_to_char(cursor.row());
         let col_offset = line + cursor.col();
         self.content.try_remove(col_offset..col_offset.add(1)).ok();
@@ -137,6 +173,7 @@ impl TextObject<Write> {
     }
 
     pub fn erase_until_eol(&mut self, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
         let line = self.content.line_to_char(cursor.row());
         let next_line = self.content.line_to_char(cursor.row().add(1));
         let col_offset = line + cursor.col();
@@ -292,6 +329,10 @@ impl TextObject<Write> {
     }
 
     pub fn delete_line(&mut self, line: usize) {
+        let mut cursor = Cursor::default();
+        cursor.move_to_row(line);
+        self.history.record(&self.content, &cursor);
+
         let start = self.content.line_to_char(line);
         let end = self.content.line_to_char(line.add(1));
         self.content.try_remove(start..end).ok();
@@ -300,10 +341,11 @@ impl TextObject<Write> {
     /// deletes a word forward in one of two ways:
     ///
     /// - if the current character is alphanumeric, then this delete up to the first non
-    /// alphanumeric character
+    ///   alphanumeric character
     /// - if the current character is non alphanumeric, then delete up to the first alphanumeric
-    /// character
+    ///   character
     pub fn delete_word(&mut self, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
         let start_idx = self.content.line_to_char(cursor.row()).add(cursor.col());
         let mut end_idx = start_idx.saturating_sub(1);
 
@@ -330,12 +372,13 @@ impl TextObject<Write> {
     /// deletes a word backwards in one of two ways:
     ///
     /// - if the current character is alphanumeric, then this delete up to the first non
-    /// alphanumeric character
+    ///   alphanumeric character
     /// - if the current character is non alphanumeric, then delete up to the first alphanumeric
-    /// character
+    ///   character
     ///
     /// will always return how many columns to advance the cursor
     pub fn delete_word_backwards(&mut self, cursor: &Cursor) -> usize {
+        self.history.record(&self.content, cursor);
         let start_idx = self.content.line_to_char(cursor.row()).add(cursor.col());
         let mut end_idx = start_idx.saturating_sub(1);
 
@@ -356,19 +399,115 @@ impl TextObject<Write> {
     }
 
     pub fn insert_line_below(&mut self, cursor: &Cursor, tree: Option<&Tree>) {
+        self.history.record(&self.content, cursor);
         let indentation = self.get_scope_aware_indentation(cursor, tree);
         let next_line = self.content.line_to_char(cursor.row().add(1));
-        let line_with_indentation = format!("{}{}", indentation, &self.line_break.to_string());
+        let line_with_indentation = format!("{}{}", indentation, self.line_break);
         self.content.insert(next_line, &line_with_indentation);
     }
 
     pub fn insert_line_above(&mut self, cursor: &Cursor, tree: Option<&Tree>) {
+        self.history.record(&self.content, cursor);
         let indentation = self.get_scope_aware_indentation(cursor, tree);
         let curr_line = self.content.line_to_char(cursor.row());
-        let line_with_indentation = format!("{}{}", indentation, &self.line_break.to_string());
+        let line_with_indentation = format!("{}{}", indentation, self.line_break);
         self.content.insert(curr_line, &line_with_indentation);
     }
 
+    /// deletes every char between two positions, both inclusive
+    pub fn delete_between(&mut self, start: &Cursor, end: &Cursor) {
+        let (start, end) = order_positions(start, end);
+        self.history.record(&self.content, start);
+        let start_idx = self.char_idx(start);
+        let end_idx = self.char_idx(end).add(1).min(self.content.len_chars());
+        self.content.try_remove(start_idx..end_idx).ok();
+    }
+
+    /// deletes every line between two rows, both inclusive. When deleting the
+    /// last lines of the content, the line break before them is also removed so
+    /// we don't leave an empty line behind
+    pub fn delete_lines_between(&mut self, start_row: usize, end_row: usize, cursor: &Cursor) {
+        let (start_row, end_row) = (start_row.min(end_row), start_row.max(end_row));
+        self.history.record(&self.content, cursor);
+
+        let mut start_idx = self.content.line_to_char(start_row);
+        let end_idx = match end_row.add(1).lt(&self.content.len_lines()) {
+            true => self.content.line_to_char(end_row.add(1)),
+            false => {
+                let line_break_len: usize = self.line_break.clone().into();
+                start_idx = start_idx.saturating_sub(line_break_len);
+                self.content.len_chars()
+            }
+        };
+
+        self.content.try_remove(start_idx..end_idx).ok();
+    }
+
+    /// inserts text at the position of the cursor, as is
+    pub fn insert_text(&mut self, text: &str, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
+        let idx = self.char_idx(cursor);
+        self.content.insert(idx, text);
+    }
+
+    /// inserts whole lines before the given row, a line break is added to the end
+    /// of `text` when it doesn't have one. Inserting after the last line appends
+    /// the lines to the content
+    pub fn insert_lines(&mut self, text: &str, row: usize, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
+
+        if row.lt(&self.content.len_lines()) {
+            let idx = self.content.line_to_char(row);
+            match text.ends_with('\n') {
+                true => self.content.insert(idx, text),
+                false => self
+                    .content
+                    .insert(idx, &format!("{}{}", text, self.line_break)),
+            }
+            return;
+        }
+
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        let text = format!("{}{}", self.line_break, text);
+        let len_chars = self.content.len_chars();
+        self.content.insert(len_chars, &text);
+    }
+
+    /// replaces the whole content at once, like when the content was edited
+    /// outside of the application. This is recorded as a single edit, so it
+    /// can be undone
+    pub fn replace_content(&mut self, content: &str, cursor: &Cursor) {
+        self.history.record(&self.content, cursor);
+        self.content = Rope::from_str(content);
+    }
+
+    /// starts grouping edits, every edit until `end_undo_group` is called will be
+    /// undone at once
+    pub fn begin_undo_group(&mut self) {
+        self.history.begin_group();
+    }
+
+    pub fn end_undo_group(&mut self) {
+        self.history.end_group();
+    }
+
+    /// reverts the last edit or group of edits, returning where the cursor was
+    /// before the edit happened
+    pub fn undo(&mut self, cursor: &Cursor) -> Option<Cursor> {
+        let (content, cursor) = self.history.undo(&self.content, cursor)?;
+        self.content = content;
+        Some(cursor)
+    }
+
+    /// reapplies the last undone edit, returning where the cursor was when it
+    /// was undone
+    pub fn redo(&mut self, cursor: &Cursor) -> Option<Cursor> {
+        let (content, cursor) = self.history.redo(&self.content, cursor)?;
+        self.content = content;
+        Some(cursor)
+    }
+
     pub fn find_oposing_token(&mut self, cursor: &Cursor) -> (usize, usize) {
         let start_idx = self.content.line_to_char(cursor.row()).add(cursor.col());
         let mut combinations = HashMap::new();
@@ -463,6 +602,129 @@ impl TextObject<Write> {
     }
 }
 
+impl<State> TextObject<State> {
+    /// finds every occurrence of `pattern` on the content, ordered by position.
+    ///
+    /// searching is smart case, which means that patterns with only lowercase
+    /// characters match regardless of case, while patterns with any uppercase
+    /// character match exactly. Matches never span multiple lines
+    pub fn find_matches(&self, pattern: &str) -> Vec<SearchMatch> {
+        let pattern = pattern.chars().collect::<Vec<_>>();
+        if pattern.is_empty() {
+            return vec![];
+        }
+
+        let ignore_case = !pattern.iter().any(|c| c.is_uppercase());
+        let chars_eq = |a: &char, b: &char| match ignore_case {
+            true => a.to_lowercase().eq(b.to_lowercase()),
+            false => a.eq(b),
+        };
+
+        let mut matches = vec![];
+        for (row, line) in self.content.lines().enumerate() {
+            let line_start = self.content.line_to_char(row);
+            let line = line.chars().filter(|c| !matches!(c, '\n' | '\r'));
+            let line = line.collect::<Vec<_>>();
+
+            if line.len().lt(&pattern.len()) {
+                continue;
+            }
+
+            for col in 0..=line.len().sub(pattern.len()) {
+                let candidate = &line[col..col.add(pattern.len())];
+                if candidate
+                    .iter()
+                    .zip(pattern.iter())
+                    .all(|(a, b)| chars_eq(a, b))
+                {
+                    matches.push(SearchMatch {
+                        row,
+                        col,
+                        offset: line_start.add(col),
+                        len: pattern.len(),
+                    });
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+impl<State> TextObject<State> {
+    /// returns every char between two positions, both inclusive
+    pub fn get_text_between(&self, start: &Cursor, end: &Cursor) -> String {
+        let (start, end) = order_positions(start, end);
+        let start_idx = self.char_idx(start);
+        let end_idx = self.char_idx(end).add(1).min(self.content.len_chars());
+        self.content
+            .get_slice(start_idx..end_idx)
+            .map(|slice| slice.to_string())
+            .unwrap_or_default()
+    }
+
+    /// returns every line between two rows, both inclusive, the result always
+    /// ends with a line break
+    pub fn get_lines_between(&self, start_row: usize, end_row: usize) -> String {
+        let (start_row, end_row) = (start_row.min(end_row), start_row.max(end_row));
+        let start_idx = self.content.line_to_char(start_row);
+        let end_idx = match end_row.add(1).lt(&self.content.len_lines()) {
+            true => self.content.line_to_char(end_row.add(1)),
+            false => self.content.len_chars(),
+        };
+
+        let mut lines = self
+            .content
+            .get_slice(start_idx..end_idx)
+            .map(|slice| slice.to_string())
+            .unwrap_or_default();
+
+        if !lines.ends_with('\n') {
+            lines.push_str(&self.line_break.to_string());
+        }
+
+        lines
+    }
+
+    fn char_idx(&self, cursor: &Cursor) -> usize {
+        let row = cursor.row().min(self.content.len_lines().saturating_sub(1));
+        self.content
+            .line_to_char(row)
+            .add(cursor.col())
+            .min(self.content.len_chars())
+    }
+}
+
+fn order_positions<'a>(a: &'a Cursor, b: &'a Cursor) -> (&'a Cursor, &'a Cursor) {
+    match (a.row(), a.col()).le(&(b.row(), b.col())) {
+        true => (a, b),
+        false => (b, a),
+    }
+}
+
+/// finds the closest match to the given position on the given direction,
+/// wrapping around the content when no match is found before reaching an end.
+///
+/// `matches` is expected to be ordered by position, as returned by
+/// `TextObject::find_matches`
+pub fn find_closest_match(
+    matches: &[SearchMatch],
+    (col, row): (usize, usize),
+    direction: SearchDirection,
+) -> Option<&SearchMatch> {
+    match direction {
+        SearchDirection::Forward => matches
+            .iter()
+            .find(|m| (m.row, m.col).gt(&(row, col)))
+            .or(matches.first()),
+        SearchDirection::Backward => matches
+            .iter()
+            .rev()
+            .find(|m| (m.row, m.col).lt(&(row, col)))
+            .or(matches.last()),
+    }
+}
+
 impl<State> std::fmt::Display for TextObject<State> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.content.to_string())
@@ -476,3 +738,122 @@ fn is_opening_token(char: char) -> bool {
 fn is_closing_token(char: char) -> bool {
     matches!(char, ')' | '}' | ']' | '>')
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matches_is_smart_case() {
+        let text = TextObject::from("{\n  \"Name\": \"name\"\n}");
+
+        let matches = text.find_matches("name");
+        assert_eq!(matches.len(), 2);
+        assert_eq!((matches[0].row, matches[0].col), (1, 3));
+        assert_eq!(matches[0].offset, 5);
+        assert_eq!((matches[1].row, matches[1].col), (1, 11));
+
+        let matches = text.find_matches("Name");
+        assert_eq!(matches.len(), 1);
+        assert_eq!((matches[0].row, matches[0].col), (1, 3));
+    }
+
+    #[test]
+    fn test_find_closest_match_wraps_around() {
+        let text = TextObject::from("ab\nab\nab");
+        let matches = text.find_matches("b");
+
+        let next = find_closest_match(&matches, (1, 1), SearchDirection::Forward).unwrap();
+        assert_eq!((next.row, next.col), (2, 1));
+        let next = find_closest_match(&matches, (1, 2), SearchDirection::Forward).unwrap();
+        assert_eq!((next.row, next.col), (0, 1));
+        let prev = find_closest_match(&matches, (1, 1), SearchDirection::Backward).unwrap();
+        assert_eq!((prev.row, prev.col), (0, 1));
+        let prev = find_closest_match(&matches, (0, 0), SearchDirection::Backward).unwrap();
+        assert_eq!((prev.row, prev.col), (2, 1));
+    }
+
+    #[test]
+    fn test_undo_restores_content_and_cursor() {
+        let mut text = TextObject::from("first\nsecond\nthird").with_write();
+        let mut cursor = Cursor::default();
+        cursor.move_to_row(1);
+        cursor.move_to_col(3);
+
+        text.erase_current_char(&cursor);
+        assert_eq!(text.to_string(), "first\nsecnd\nthird");
+
+        let restored = text.undo(&Cursor::default()).unwrap();
+        assert_eq!(text.to_string(), "first\nsecond\nthird");
+        assert_eq!((restored.row(), restored.col()), (1, 3));
+
+        text.delete_line(1);
+        assert_eq!(text.to_string(), "first\nthird");
+
+        let mut undone_at = Cursor::default();
+        undone_at.move_to_row(1);
+        undone_at.move_to_col(2);
+        let restored = text.undo(&undone_at).unwrap();
+        assert_eq!(text.to_string(), "first\nsecond\nthird");
+        assert_eq!((restored.row(), restored.col()), (1, 0));
+
+        // redoing gives back the cursor the undo was made from
+        let redone = text.redo(&restored).unwrap();
+        assert_eq!(text.to_string(), "first\nthird");
+        assert_eq!((redone.row(), redone.col()), (1, 2));
+        assert!(text.redo(&redone).is_none());
+    }
+
+    #[test]
+    fn test_grouped_edits_are_undone_at_once() {
+        let mut text = TextObject::from("").with_write();
+        let mut cursor = Cursor::default();
+
+        text.begin_undo_group();
+        for c in "hac".chars() {
+            text.insert_char(c, &cursor);
+            cursor.move_right(1);
+        }
+        text.end_undo_group();
+        text.insert_char('!', &cursor);
+
+        text.undo(&cursor);
+        assert_eq!(text.to_string(), "hac");
+        let restored = text.undo(&cursor).unwrap();
+        assert_eq!(text.to_string(), "");
+        assert_eq!(restored.col(), 0);
+        assert!(text.undo(&cursor).is_none());
+    }
+
+    #[test]
+    fn test_get_and_delete_between_positions() {
+        let mut text = TextObject::from("first\nsecond\nthird").with_write();
+        let mut start = Cursor::default();
+        start.move_to_col(2);
+        let mut end = Cursor::default();
+        end.move_to_row(1);
+        end.move_to_col(1);
+
+        assert_eq!(text.get_text_between(&end, &start), "rst\nse");
+        text.delete_between(&start, &end);
+        assert_eq!(text.to_string(), "ficond\nthird");
+    }
+
+    #[test]
+    fn test_linewise_get_delete_and_insert() {
+        let mut text = TextObject::from("first\nsecond\nthird").with_write();
+        let cursor = Cursor::default();
+
+        let lines = text.get_lines_between(2, 1);
+        assert_eq!(lines, "second\nthird\n");
+
+        text.delete_lines_between(1, 2, &cursor);
+        assert_eq!(text.to_string(), "first");
+
+        text.insert_lines(&lines, 1, &cursor);
+        assert_eq!(text.to_string(), "first\nsecond\nthird");
+
+        text.insert_lines("zero\n", 0, &cursor);
+        assert_eq!(text.to_string(), "zero\nfirst\nsecond\nthird");
+    }
+}